@@ -0,0 +1,726 @@
+//! Decision whether an identifier may start or stop charging, combining
+//! the Local Authorization List, the Authorization Cache and the
+//! Central System.
+
+use chrono::{DateTime, Utc};
+
+use super::cache::AuthorizationCache;
+use super::local_list::LocalAuthList;
+use crate::messages::authorize::{AuthorizeConf, AuthorizeReq};
+use crate::types::authorization::{IdTagInfo, IdToken};
+use crate::types::enumerations::AuthorizationStatus;
+
+/// Configuration keys driving the authorization of identifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AuthorizationSettings {
+    /// LocalAuthListEnabled: whether the Local Authorization List is
+    /// used.
+    local_auth_list_enabled: bool,
+    /// AuthorizationCacheEnabled: whether the Authorization Cache is
+    /// used.
+    authorization_cache_enabled: bool,
+    /// LocalAuthorizeOffline: whether identifiers are authorized
+    /// locally while offline.
+    local_authorize_offline: bool,
+    /// LocalPreAuthorize: whether locally accepted identifiers start
+    /// charging without waiting for an Authorize.conf while online.
+    local_pre_authorize: bool,
+    /// AllowOfflineTxForUnknownId: whether unknown identifiers may
+    /// charge while offline.
+    allow_offline_tx_for_unknown_id: bool,
+}
+
+impl AuthorizationSettings {
+    /// Starts building settings where every key is false.
+    pub fn builder() -> AuthorizationSettingsBuilder {
+        AuthorizationSettingsBuilder {
+            inner: AuthorizationSettings::default(),
+        }
+    }
+
+    pub fn local_auth_list_enabled(&self) -> bool {
+        self.local_auth_list_enabled
+    }
+
+    pub fn authorization_cache_enabled(&self) -> bool {
+        self.authorization_cache_enabled
+    }
+
+    pub fn local_authorize_offline(&self) -> bool {
+        self.local_authorize_offline
+    }
+
+    pub fn local_pre_authorize(&self) -> bool {
+        self.local_pre_authorize
+    }
+
+    pub fn allow_offline_tx_for_unknown_id(&self) -> bool {
+        self.allow_offline_tx_for_unknown_id
+    }
+}
+
+/// Builder for AuthorizationSettings, created by
+/// [`AuthorizationSettings::builder`].
+#[derive(Debug, Clone)]
+pub struct AuthorizationSettingsBuilder {
+    inner: AuthorizationSettings,
+}
+
+impl AuthorizationSettingsBuilder {
+    pub fn local_auth_list_enabled(mut self, enabled: bool) -> AuthorizationSettingsBuilder {
+        self.inner.local_auth_list_enabled = enabled;
+        self
+    }
+
+    pub fn authorization_cache_enabled(mut self, enabled: bool) -> AuthorizationSettingsBuilder {
+        self.inner.authorization_cache_enabled = enabled;
+        self
+    }
+
+    pub fn local_authorize_offline(mut self, enabled: bool) -> AuthorizationSettingsBuilder {
+        self.inner.local_authorize_offline = enabled;
+        self
+    }
+
+    pub fn local_pre_authorize(mut self, enabled: bool) -> AuthorizationSettingsBuilder {
+        self.inner.local_pre_authorize = enabled;
+        self
+    }
+
+    pub fn allow_offline_tx_for_unknown_id(
+        mut self,
+        enabled: bool,
+    ) -> AuthorizationSettingsBuilder {
+        self.inner.allow_offline_tx_for_unknown_id = enabled;
+        self
+    }
+
+    pub fn build(self) -> AuthorizationSettings {
+        self.inner
+    }
+}
+
+/// Why an authorization decision was taken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecisionReason {
+    /// The identifier is in the Local Authorization List.
+    LocalList,
+    /// The identifier is in the Authorization Cache.
+    Cache,
+    /// The Central System answered an Authorize.req.
+    CentralSystem,
+    /// The identifier is unknown, but AllowOfflineTxForUnknownId lets it
+    /// charge while offline.
+    UnknownOfflineAllowed,
+    /// The Charge Point is offline and could not authorize the
+    /// identifier locally.
+    Offline,
+    /// The identifier is the one that started the transaction.
+    SameIdTag,
+    /// The identifier shares its parent id tag with the one that
+    /// started the transaction.
+    SameParentIdTag,
+}
+
+/// Authorization decision for an identifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decision {
+    status: AuthorizationStatus,
+    reason: DecisionReason,
+}
+
+impl Decision {
+    pub fn new(status: AuthorizationStatus, reason: DecisionReason) -> Self {
+        Decision { status, reason }
+    }
+
+    pub fn status(&self) -> AuthorizationStatus {
+        self.status
+    }
+
+    pub fn reason(&self) -> DecisionReason {
+        self.reason
+    }
+
+    /// Whether the identifier is allowed to charge.
+    pub fn is_accepted(&self) -> bool {
+        self.status == AuthorizationStatus::Accepted
+    }
+}
+
+/// Result of asking the [`Authorizer`] about an identifier.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Authorization {
+    /// The decision was taken locally.
+    Decided(Decision),
+    /// The Central System must be asked with this Authorize.req. Its
+    /// confirmation is then passed to [`Authorizer::on_authorize_conf`]
+    /// or [`Authorizer::on_stop_authorize_conf`].
+    Pending(AuthorizeReq),
+}
+
+/// Decides whether identifiers may start or stop charging.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Authorizer {
+    settings: AuthorizationSettings,
+    local_list: LocalAuthList,
+    cache: AuthorizationCache,
+}
+
+impl Authorizer {
+    pub fn new(
+        settings: AuthorizationSettings,
+        local_list: LocalAuthList,
+        cache: AuthorizationCache,
+    ) -> Self {
+        Authorizer {
+            settings,
+            local_list,
+            cache,
+        }
+    }
+
+    pub fn settings(&self) -> AuthorizationSettings {
+        self.settings
+    }
+
+    /// Applies new values of the configuration keys.
+    pub fn set_settings(&mut self, settings: AuthorizationSettings) {
+        self.settings = settings;
+    }
+
+    pub fn local_list(&self) -> &LocalAuthList {
+        &self.local_list
+    }
+
+    /// Mutable access to the list, to apply SendLocalList.req. Cached
+    /// identifiers that enter the list are removed from the cache.
+    pub fn with_local_list<T>(&mut self, update: impl FnOnce(&mut LocalAuthList) -> T) -> T {
+        let result = update(&mut self.local_list);
+        self.cache.remove_listed(&self.local_list);
+        result
+    }
+
+    pub fn cache(&self) -> &AuthorizationCache {
+        &self.cache
+    }
+
+    pub fn cache_mut(&mut self) -> &mut AuthorizationCache {
+        &mut self.cache
+    }
+
+    /// Decides whether `id_tag` may start charging.
+    ///
+    /// While online the Central System is asked, unless LocalPreAuthorize
+    /// is set and the identifier is accepted by the list or the cache.
+    /// While offline the identifier is looked up locally when
+    /// LocalAuthorizeOffline is set, and identifiers that are neither in
+    /// the list nor in the cache are accepted when
+    /// AllowOfflineTxForUnknownId is set.
+    pub fn authorize(
+        &mut self,
+        id_tag: &IdToken,
+        online: bool,
+        now: DateTime<Utc>,
+    ) -> Authorization {
+        if online {
+            if self.settings.local_pre_authorize {
+                if let Some(decision) = self.lookup(id_tag, now).filter(Decision::is_accepted) {
+                    return Authorization::Decided(decision);
+                }
+            }
+            return Authorization::Pending(AuthorizeReq::new(id_tag.clone()));
+        }
+
+        let decision = match self.lookup(id_tag, now) {
+            Some(decision) if self.settings.local_authorize_offline => decision,
+            None if self.settings.allow_offline_tx_for_unknown_id => Decision::new(
+                AuthorizationStatus::Accepted,
+                DecisionReason::UnknownOfflineAllowed,
+            ),
+            _ => Decision::new(AuthorizationStatus::Invalid, DecisionReason::Offline),
+        };
+        Authorization::Decided(decision)
+    }
+
+    /// Completes [`Authorizer::authorize`] with the Authorize.conf of the
+    /// Central System, caching the received idTagInfo.
+    pub fn on_authorize_conf(&mut self, id_tag: &IdToken, conf: &AuthorizeConf) -> Decision {
+        self.record(id_tag, conf.id_tag_info());
+
+        Decision::new(conf.id_tag_info().status(), DecisionReason::CentralSystem)
+    }
+
+    /// Stores the idTagInfo of a StartTransaction.conf or
+    /// StopTransaction.conf in the cache, when it is enabled.
+    pub fn record(&mut self, id_tag: &IdToken, id_tag_info: &IdTagInfo) {
+        if self.settings.authorization_cache_enabled {
+            self.cache
+                .insert(id_tag.clone(), id_tag_info.clone(), &self.local_list);
+        }
+    }
+
+    /// Decides whether `id_tag` may stop the transaction started by
+    /// `started_by`, whose parent id tag is `started_by_parent`.
+    ///
+    /// The identifier that started the transaction may always stop it.
+    /// Another accepted identifier may stop it when both share the same
+    /// parent id tag, which is looked up locally and otherwise requested
+    /// from the Central System while online.
+    pub fn authorize_stop(
+        &mut self,
+        started_by: &IdToken,
+        started_by_parent: Option<&IdToken>,
+        id_tag: &IdToken,
+        online: bool,
+        now: DateTime<Utc>,
+    ) -> Authorization {
+        if started_by == id_tag {
+            return Authorization::Decided(Decision::new(
+                AuthorizationStatus::Accepted,
+                DecisionReason::SameIdTag,
+            ));
+        }
+
+        if let Some(info) = self.local_info(id_tag, now) {
+            if info.status_at(now) == AuthorizationStatus::Accepted
+                && same_parent(started_by_parent, info.parent_id_tag())
+            {
+                return Authorization::Decided(Decision::new(
+                    AuthorizationStatus::Accepted,
+                    DecisionReason::SameParentIdTag,
+                ));
+            }
+        }
+
+        if online {
+            Authorization::Pending(AuthorizeReq::new(id_tag.clone()))
+        } else {
+            Authorization::Decided(Decision::new(
+                AuthorizationStatus::Invalid,
+                DecisionReason::Offline,
+            ))
+        }
+    }
+
+    /// Completes [`Authorizer::authorize_stop`] with the Authorize.conf
+    /// of the Central System.
+    pub fn on_stop_authorize_conf(
+        &mut self,
+        started_by_parent: Option<&IdToken>,
+        id_tag: &IdToken,
+        conf: &AuthorizeConf,
+    ) -> Decision {
+        let info = conf.id_tag_info();
+        self.record(id_tag, info);
+
+        if info.status() != AuthorizationStatus::Accepted {
+            Decision::new(info.status(), DecisionReason::CentralSystem)
+        } else if same_parent(started_by_parent, info.parent_id_tag()) {
+            Decision::new(
+                AuthorizationStatus::Accepted,
+                DecisionReason::SameParentIdTag,
+            )
+        } else {
+            Decision::new(AuthorizationStatus::Invalid, DecisionReason::CentralSystem)
+        }
+    }
+
+    /// Decision taken from the list or the cache, the list prevailing.
+    fn lookup(&mut self, id_tag: &IdToken, now: DateTime<Utc>) -> Option<Decision> {
+        if self.settings.local_auth_list_enabled {
+            if let Some(info) = self.local_list.get(id_tag) {
+                return Some(Decision::new(
+                    info.status_at(now),
+                    DecisionReason::LocalList,
+                ));
+            }
+        }
+
+        if self.settings.authorization_cache_enabled {
+            if let Some(info) = self.cache.get(id_tag, now) {
+                return Some(Decision::new(info.status(), DecisionReason::Cache));
+            }
+        }
+
+        None
+    }
+
+    /// idTagInfo known locally for `id_tag`.
+    fn local_info(&mut self, id_tag: &IdToken, now: DateTime<Utc>) -> Option<IdTagInfo> {
+        if self.settings.local_auth_list_enabled {
+            if let Some(info) = self.local_list.get(id_tag) {
+                return Some(info.clone());
+            }
+        }
+
+        if self.settings.authorization_cache_enabled {
+            return self.cache.get(id_tag, now);
+        }
+
+        None
+    }
+}
+
+fn same_parent(lhs: Option<&IdToken>, rhs: Option<&IdToken>) -> bool {
+    matches!((lhs, rhs), (Some(lhs), Some(rhs)) if lhs == rhs)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::messages::send_local_list::SendLocalListReq;
+    use crate::types::authorization::AuthorizationData;
+    use crate::types::enumerations::UpdateType;
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, 4, 12, 0, 0).unwrap()
+    }
+
+    fn id_tag(value: &str) -> IdToken {
+        IdToken::try_from(value).unwrap()
+    }
+
+    fn info(status: AuthorizationStatus, parent: Option<&str>) -> IdTagInfo {
+        let mut info = IdTagInfo::builder(status);
+        if let Some(parent) = parent {
+            info = info.parent_id_tag(id_tag(parent));
+        }
+        info.build().unwrap()
+    }
+
+    fn all_enabled() -> AuthorizationSettingsBuilder {
+        AuthorizationSettings::builder()
+            .local_auth_list_enabled(true)
+            .authorization_cache_enabled(true)
+            .local_authorize_offline(true)
+            .local_pre_authorize(true)
+    }
+
+    /// Authorizer whose list accepts LISTED and blocks BLOCKED, both
+    /// members of group FLEET.
+    fn authorizer(settings: AuthorizationSettings) -> Authorizer {
+        let mut list = LocalAuthList::new(10, 10);
+        list.update(
+            &SendLocalListReq::builder(1, UpdateType::Full)
+                .local_authorization_list(vec![
+                    AuthorizationData::builder(id_tag("LISTED"))
+                        .id_tag_info(info(AuthorizationStatus::Accepted, Some("FLEET")))
+                        .build()
+                        .unwrap(),
+                    AuthorizationData::builder(id_tag("BLOCKED"))
+                        .id_tag_info(info(AuthorizationStatus::Blocked, Some("FLEET")))
+                        .build()
+                        .unwrap(),
+                ])
+                .build()
+                .unwrap(),
+        );
+
+        Authorizer::new(settings, list, AuthorizationCache::new(10))
+    }
+
+    fn decided(authorization: Authorization) -> Decision {
+        match authorization {
+            Authorization::Decided(decision) => decision,
+            Authorization::Pending(_) => panic!("expected a decision"),
+        }
+    }
+
+    #[test]
+    fn test_online_asks_central_system() {
+        let mut authorizer = authorizer(all_enabled().local_pre_authorize(false).build());
+
+        let authorization = authorizer.authorize(&id_tag("LISTED"), true, now());
+
+        assert_eq!(
+            authorization,
+            Authorization::Pending(AuthorizeReq::new(id_tag("LISTED")))
+        );
+    }
+
+    #[test]
+    fn test_online_pre_authorized_by_local_list() {
+        let mut authorizer = authorizer(all_enabled().build());
+
+        let decision = decided(authorizer.authorize(&id_tag("listed"), true, now()));
+
+        assert_eq!(
+            decision,
+            Decision::new(AuthorizationStatus::Accepted, DecisionReason::LocalList)
+        );
+    }
+
+    #[test]
+    fn test_online_pre_authorize_ignores_blocked_identifier() {
+        let mut authorizer = authorizer(all_enabled().build());
+
+        let authorization = authorizer.authorize(&id_tag("BLOCKED"), true, now());
+
+        assert!(matches!(authorization, Authorization::Pending(_)));
+    }
+
+    #[test]
+    fn test_authorize_conf_is_cached() {
+        let mut authorizer = authorizer(all_enabled().build());
+        let conf = AuthorizeConf::new(info(AuthorizationStatus::Accepted, None));
+
+        let decision = authorizer.on_authorize_conf(&id_tag("VISITOR"), &conf);
+        let offline = decided(authorizer.authorize(&id_tag("VISITOR"), false, now()));
+
+        assert_eq!(
+            decision,
+            Decision::new(AuthorizationStatus::Accepted, DecisionReason::CentralSystem)
+        );
+        assert_eq!(
+            offline,
+            Decision::new(AuthorizationStatus::Accepted, DecisionReason::Cache)
+        );
+    }
+
+    #[test]
+    fn test_authorize_conf_is_not_cached_when_disabled() {
+        let mut authorizer = authorizer(all_enabled().authorization_cache_enabled(false).build());
+        let conf = AuthorizeConf::new(info(AuthorizationStatus::Accepted, None));
+
+        authorizer.on_authorize_conf(&id_tag("VISITOR"), &conf);
+
+        assert!(authorizer.cache().is_empty());
+    }
+
+    #[test]
+    fn test_offline_local_list_prevails() {
+        let mut authorizer = authorizer(all_enabled().build());
+
+        let decision = decided(authorizer.authorize(&id_tag("BLOCKED"), false, now()));
+
+        assert_eq!(
+            decision,
+            Decision::new(AuthorizationStatus::Blocked, DecisionReason::LocalList)
+        );
+    }
+
+    #[test]
+    fn test_offline_expired_list_entry() {
+        let mut list = LocalAuthList::new(10, 10);
+        list.update(
+            &SendLocalListReq::builder(1, UpdateType::Full)
+                .local_authorization_list(vec![AuthorizationData::builder(id_tag("LISTED"))
+                    .id_tag_info(
+                        IdTagInfo::builder(AuthorizationStatus::Accepted)
+                            .expiry_date(now())
+                            .build()
+                            .unwrap(),
+                    )
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap(),
+        );
+        let mut authorizer =
+            Authorizer::new(all_enabled().build(), list, AuthorizationCache::new(10));
+
+        let decision = decided(authorizer.authorize(&id_tag("LISTED"), false, now()));
+
+        assert_eq!(decision.status(), AuthorizationStatus::Expired);
+    }
+
+    #[test]
+    fn test_offline_without_local_authorization() {
+        let mut authorizer = authorizer(all_enabled().local_authorize_offline(false).build());
+
+        let decision = decided(authorizer.authorize(&id_tag("LISTED"), false, now()));
+
+        assert_eq!(
+            decision,
+            Decision::new(AuthorizationStatus::Invalid, DecisionReason::Offline)
+        );
+    }
+
+    #[test]
+    fn test_offline_unknown_identifier() {
+        let mut rejecting = authorizer(all_enabled().build());
+        let mut allowing = authorizer(all_enabled().allow_offline_tx_for_unknown_id(true).build());
+
+        let rejected = decided(rejecting.authorize(&id_tag("VISITOR"), false, now()));
+        let allowed = decided(allowing.authorize(&id_tag("VISITOR"), false, now()));
+
+        assert_eq!(
+            rejected,
+            Decision::new(AuthorizationStatus::Invalid, DecisionReason::Offline)
+        );
+        assert_eq!(
+            allowed,
+            Decision::new(
+                AuthorizationStatus::Accepted,
+                DecisionReason::UnknownOfflineAllowed
+            )
+        );
+    }
+
+    #[test]
+    fn test_offline_listed_identifier_is_not_unknown() {
+        let mut authorizer = authorizer(
+            all_enabled()
+                .local_authorize_offline(false)
+                .allow_offline_tx_for_unknown_id(true)
+                .build(),
+        );
+
+        let blocked = decided(authorizer.authorize(&id_tag("BLOCKED"), false, now()));
+        let unknown = decided(authorizer.authorize(&id_tag("VISITOR"), false, now()));
+
+        assert_eq!(
+            blocked,
+            Decision::new(AuthorizationStatus::Invalid, DecisionReason::Offline)
+        );
+        assert_eq!(unknown.reason(), DecisionReason::UnknownOfflineAllowed);
+    }
+
+    #[test]
+    fn test_disabled_local_list_is_ignored() {
+        let mut authorizer = authorizer(
+            all_enabled()
+                .local_auth_list_enabled(false)
+                .allow_offline_tx_for_unknown_id(true)
+                .build(),
+        );
+
+        let decision = decided(authorizer.authorize(&id_tag("BLOCKED"), false, now()));
+
+        assert_eq!(decision.reason(), DecisionReason::UnknownOfflineAllowed);
+    }
+
+    #[test]
+    fn test_list_update_evicts_cached_identifiers() {
+        let mut authorizer = authorizer(all_enabled().build());
+        let conf = AuthorizeConf::new(info(AuthorizationStatus::Accepted, None));
+        authorizer.on_authorize_conf(&id_tag("VISITOR"), &conf);
+
+        authorizer.with_local_list(|list| {
+            list.update(
+                &SendLocalListReq::builder(2, UpdateType::Differential)
+                    .local_authorization_list(vec![AuthorizationData::builder(id_tag("VISITOR"))
+                        .id_tag_info(info(AuthorizationStatus::Blocked, None))
+                        .build()
+                        .unwrap()])
+                    .build()
+                    .unwrap(),
+            )
+        });
+
+        assert!(authorizer.cache().is_empty());
+    }
+
+    #[test]
+    fn test_stop_by_same_identifier() {
+        let mut authorizer = authorizer(AuthorizationSettings::default());
+
+        let decision = decided(authorizer.authorize_stop(
+            &id_tag("VISITOR"),
+            None,
+            &id_tag("visitor"),
+            false,
+            now(),
+        ));
+
+        assert_eq!(decision.reason(), DecisionReason::SameIdTag);
+        assert!(decision.is_accepted());
+    }
+
+    #[test]
+    fn test_stop_by_same_parent_from_local_list() {
+        let mut authorizer = authorizer(all_enabled().build());
+
+        let decision = decided(authorizer.authorize_stop(
+            &id_tag("DRIVER"),
+            Some(&id_tag("FLEET")),
+            &id_tag("LISTED"),
+            false,
+            now(),
+        ));
+
+        assert_eq!(
+            decision,
+            Decision::new(
+                AuthorizationStatus::Accepted,
+                DecisionReason::SameParentIdTag
+            )
+        );
+    }
+
+    #[test]
+    fn test_stop_by_blocked_identifier_of_same_parent() {
+        let mut authorizer = authorizer(all_enabled().build());
+
+        let offline = decided(authorizer.authorize_stop(
+            &id_tag("LISTED"),
+            Some(&id_tag("FLEET")),
+            &id_tag("BLOCKED"),
+            false,
+            now(),
+        ));
+        let online = authorizer.on_stop_authorize_conf(
+            Some(&id_tag("FLEET")),
+            &id_tag("STOLEN"),
+            &AuthorizeConf::new(info(AuthorizationStatus::Expired, Some("FLEET"))),
+        );
+
+        assert!(!offline.is_accepted());
+        assert_eq!(
+            online,
+            Decision::new(AuthorizationStatus::Expired, DecisionReason::CentralSystem)
+        );
+    }
+
+    #[test]
+    fn test_stop_by_other_group_offline() {
+        let mut authorizer = authorizer(all_enabled().build());
+
+        let decision = decided(authorizer.authorize_stop(
+            &id_tag("VISITOR"),
+            Some(&id_tag("GUESTS")),
+            &id_tag("LISTED"),
+            false,
+            now(),
+        ));
+
+        assert_eq!(
+            decision,
+            Decision::new(AuthorizationStatus::Invalid, DecisionReason::Offline)
+        );
+    }
+
+    #[test]
+    fn test_stop_by_same_parent_from_central_system() {
+        let mut authorizer = authorizer(all_enabled().build());
+
+        let authorization = authorizer.authorize_stop(
+            &id_tag("VISITOR"),
+            Some(&id_tag("GUESTS")),
+            &id_tag("FRIEND"),
+            true,
+            now(),
+        );
+        let same_group = authorizer.on_stop_authorize_conf(
+            Some(&id_tag("GUESTS")),
+            &id_tag("FRIEND"),
+            &AuthorizeConf::new(info(AuthorizationStatus::Accepted, Some("guests"))),
+        );
+        let other_group = authorizer.on_stop_authorize_conf(
+            Some(&id_tag("GUESTS")),
+            &id_tag("STRANGER"),
+            &AuthorizeConf::new(info(AuthorizationStatus::Accepted, Some("FLEET"))),
+        );
+
+        assert!(matches!(authorization, Authorization::Pending(_)));
+        assert_eq!(same_group.reason(), DecisionReason::SameParentIdTag);
+        assert!(same_group.is_accepted());
+        assert_eq!(
+            other_group,
+            Decision::new(AuthorizationStatus::Invalid, DecisionReason::CentralSystem)
+        );
+    }
+}
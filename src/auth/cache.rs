@@ -0,0 +1,279 @@
+//! Authorization Cache, remembering the identifiers the Central System
+//! recently authorized.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+use super::local_list::LocalAuthList;
+use crate::types::authorization::{IdTagInfo, IdToken};
+use crate::types::enumerations::ClearCacheStatus;
+
+/// Authorization Cache of a Charge Point.
+///
+/// The cache stores the idTagInfo of the latest Authorize.conf,
+/// StartTransaction.conf or StopTransaction.conf received for each
+/// identifier. Identifiers are compared case-insensitively and, once
+/// full, the least recently used entry is evicted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthorizationCache {
+    /// Maximum number of identifiers in the cache.
+    capacity: usize,
+    entries: HashMap<IdToken, CacheEntry>,
+    /// Counter ordering the uses of the entries.
+    clock: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct CacheEntry {
+    id_tag_info: IdTagInfo,
+    /// Value of the clock when the entry was last stored or read.
+    last_used: u64,
+}
+
+impl AuthorizationCache {
+    /// Builds an empty cache holding at most `capacity` identifiers.
+    pub fn new(capacity: usize) -> Self {
+        AuthorizationCache {
+            capacity,
+            entries: HashMap::new(),
+            clock: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Authorization of `id_tag` at `now`, if it is cached.
+    ///
+    /// An entry whose expiry date has passed is changed to Expired in
+    /// the cache.
+    pub fn get(&mut self, id_tag: &IdToken, now: DateTime<Utc>) -> Option<IdTagInfo> {
+        let last_used = self.tick();
+        let entry = self.entries.get_mut(id_tag)?;
+        if entry.id_tag_info.is_expired(now) {
+            entry.id_tag_info.expire();
+        }
+        entry.last_used = last_used;
+
+        Some(entry.id_tag_info.clone())
+    }
+
+    /// Stores the idTagInfo received for `id_tag`.
+    ///
+    /// Identifiers of the Local Authorization List are never cached,
+    /// the list being authoritative for them. Returns whether the
+    /// entry was stored.
+    pub fn insert(
+        &mut self,
+        id_tag: IdToken,
+        id_tag_info: IdTagInfo,
+        local_list: &LocalAuthList,
+    ) -> bool {
+        if self.capacity == 0 || local_list.contains(&id_tag) {
+            return false;
+        }
+
+        if !self.entries.contains_key(&id_tag) && self.entries.len() >= self.capacity {
+            self.evict();
+        }
+
+        let last_used = self.tick();
+        self.entries.insert(
+            id_tag,
+            CacheEntry {
+                id_tag_info,
+                last_used,
+            },
+        );
+
+        true
+    }
+
+    /// Removes `id_tag` from the cache.
+    pub fn remove(&mut self, id_tag: &IdToken) -> Option<IdTagInfo> {
+        self.entries.remove(id_tag).map(|entry| entry.id_tag_info)
+    }
+
+    /// Removes the identifiers that are now part of the Local
+    /// Authorization List, to be called after the list was updated.
+    pub fn remove_listed(&mut self, local_list: &LocalAuthList) {
+        self.entries
+            .retain(|id_tag, _| !local_list.contains(id_tag));
+    }
+
+    /// Clears the cache on receipt of a ClearCache.req.
+    pub fn clear(&mut self) -> ClearCacheStatus {
+        self.entries.clear();
+
+        ClearCacheStatus::Accepted
+    }
+
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+
+    /// Removes the least recently used entry.
+    fn evict(&mut self) {
+        let oldest = self
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(id_tag, _)| id_tag.clone());
+
+        if let Some(id_tag) = oldest {
+            self.entries.remove(&id_tag);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::messages::send_local_list::SendLocalListReq;
+    use crate::types::authorization::AuthorizationData;
+    use crate::types::enumerations::{AuthorizationStatus, UpdateType};
+    use chrono::{Duration, TimeZone};
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, 4, 12, 0, 0).unwrap()
+    }
+
+    fn id_tag(value: &str) -> IdToken {
+        IdToken::try_from(value).unwrap()
+    }
+
+    fn accepted() -> IdTagInfo {
+        IdTagInfo::builder(AuthorizationStatus::Accepted)
+            .build()
+            .unwrap()
+    }
+
+    fn empty_list() -> LocalAuthList {
+        LocalAuthList::new(10, 10)
+    }
+
+    #[test]
+    fn test_insert_and_get() {
+        let mut cache = AuthorizationCache::new(10);
+
+        assert!(cache.insert(id_tag("B4A63CDF"), accepted(), &empty_list()));
+
+        assert_eq!(cache.get(&id_tag("b4a63cdf"), now()), Some(accepted()));
+        assert_eq!(cache.get(&id_tag("C3B52DEF"), now()), None);
+    }
+
+    #[test]
+    fn test_insert_replaces_entry() {
+        let mut cache = AuthorizationCache::new(10);
+        cache.insert(id_tag("B4A63CDF"), accepted(), &empty_list());
+        let blocked = IdTagInfo::builder(AuthorizationStatus::Blocked)
+            .build()
+            .unwrap();
+
+        cache.insert(id_tag("B4A63CDF"), blocked.clone(), &empty_list());
+
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.get(&id_tag("B4A63CDF"), now()), Some(blocked));
+    }
+
+    #[test]
+    fn test_expired_entry() {
+        let mut cache = AuthorizationCache::new(10);
+        let info = IdTagInfo::builder(AuthorizationStatus::Accepted)
+            .expiry_date(now())
+            .build()
+            .unwrap();
+        cache.insert(id_tag("B4A63CDF"), info, &empty_list());
+
+        let before = cache.get(&id_tag("B4A63CDF"), now() - Duration::hours(1));
+        let after = cache.get(&id_tag("B4A63CDF"), now());
+        let later = cache.get(&id_tag("B4A63CDF"), now() - Duration::hours(1));
+
+        assert_eq!(before.unwrap().status(), AuthorizationStatus::Accepted);
+        assert_eq!(after.unwrap().status(), AuthorizationStatus::Expired);
+        assert_eq!(later.unwrap().status(), AuthorizationStatus::Expired);
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let mut cache = AuthorizationCache::new(2);
+        cache.insert(id_tag("B4A63CDF"), accepted(), &empty_list());
+        cache.insert(id_tag("C3B52DEF"), accepted(), &empty_list());
+        cache.get(&id_tag("B4A63CDF"), now());
+
+        cache.insert(id_tag("D2C41EF0"), accepted(), &empty_list());
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&id_tag("B4A63CDF"), now()).is_some());
+        assert!(cache.get(&id_tag("C3B52DEF"), now()).is_none());
+        assert!(cache.get(&id_tag("D2C41EF0"), now()).is_some());
+    }
+
+    #[test]
+    fn test_does_not_cache_listed_identifiers() {
+        let mut list = empty_list();
+        list.update(
+            &SendLocalListReq::builder(1, UpdateType::Full)
+                .local_authorization_list(vec![AuthorizationData::builder(id_tag("B4A63CDF"))
+                    .id_tag_info(accepted())
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap(),
+        );
+        let mut cache = AuthorizationCache::new(10);
+
+        assert!(!cache.insert(id_tag("b4a63cdf"), accepted(), &list));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_remove_listed() {
+        let mut cache = AuthorizationCache::new(10);
+        cache.insert(id_tag("B4A63CDF"), accepted(), &empty_list());
+        cache.insert(id_tag("C3B52DEF"), accepted(), &empty_list());
+        let mut list = empty_list();
+        list.update(
+            &SendLocalListReq::builder(1, UpdateType::Full)
+                .local_authorization_list(vec![AuthorizationData::builder(id_tag("B4A63CDF"))
+                    .id_tag_info(accepted())
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap(),
+        );
+
+        cache.remove_listed(&list);
+
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(&id_tag("C3B52DEF"), now()).is_some());
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut cache = AuthorizationCache::new(10);
+        cache.insert(id_tag("B4A63CDF"), accepted(), &empty_list());
+
+        assert_eq!(cache.clear(), ClearCacheStatus::Accepted);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_zero_capacity() {
+        let mut cache = AuthorizationCache::new(0);
+
+        assert!(!cache.insert(id_tag("B4A63CDF"), accepted(), &empty_list()));
+    }
+}
@@ -0,0 +1,280 @@
+//! Local Authorization List, managed by the Central System through
+//! SendLocalList.req and GetLocalListVersion.req.
+
+use std::collections::HashMap;
+
+use crate::messages::send_local_list::SendLocalListReq;
+use crate::types::authorization::{IdTagInfo, IdToken};
+use crate::types::enumerations::{UpdateStatus, UpdateType};
+
+/// Local Authorization List of a Charge Point.
+///
+/// Updates are applied atomically: a rejected update leaves both the
+/// entries and the version of the list unchanged.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalAuthList {
+    /// Version of the list, 0 when no list was ever received.
+    version: usize,
+    /// Authorization of each identifier, compared case-insensitively.
+    entries: HashMap<IdToken, IdTagInfo>,
+    /// Maximum number of identifiers in the list, as reported by the
+    /// LocalAuthListMaxLength configuration key.
+    max_length: usize,
+    /// Maximum number of identifiers in a single SendLocalList.req, as
+    /// reported by the SendLocalListMaxLength configuration key.
+    send_max_length: usize,
+}
+
+impl LocalAuthList {
+    /// Builds an empty list holding at most `max_length` identifiers,
+    /// accepting updates of at most `send_max_length` identifiers.
+    pub fn new(max_length: usize, send_max_length: usize) -> Self {
+        LocalAuthList {
+            version: 0,
+            entries: HashMap::new(),
+            max_length,
+            send_max_length,
+        }
+    }
+
+    /// Version of the list, as reported in GetLocalListVersion.conf.
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    pub fn max_length(&self) -> usize {
+        self.max_length
+    }
+
+    pub fn send_max_length(&self) -> usize {
+        self.send_max_length
+    }
+
+    /// Authorization of `id_tag`, if it is in the list.
+    pub fn get(&self, id_tag: &IdToken) -> Option<&IdTagInfo> {
+        self.entries.get(id_tag)
+    }
+
+    pub fn contains(&self, id_tag: &IdToken) -> bool {
+        self.entries.contains_key(id_tag)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Identifiers of the list with their authorization.
+    pub fn iter(&self) -> impl Iterator<Item = (&IdToken, &IdTagInfo)> {
+        self.entries.iter()
+    }
+
+    /// Applies a SendLocalList.req.
+    ///
+    /// A full update replaces the list. A differential update adds or
+    /// updates the entries carrying an idTagInfo and removes the others,
+    /// and is answered with VersionMismatch unless its version is
+    /// greater than the current one. The update fails when it carries
+    /// more than `send_max_length` entries or would grow the list past
+    /// `max_length`.
+    pub fn update(&mut self, request: &SendLocalListReq) -> UpdateStatus {
+        let changes = request.local_authorization_list();
+        if changes.len() > self.send_max_length {
+            return UpdateStatus::Failed;
+        }
+
+        let mut entries = match request.update_type() {
+            UpdateType::Full => HashMap::new(),
+            UpdateType::Differential => {
+                if request.list_version() <= self.version {
+                    return UpdateStatus::VersionMismatch;
+                }
+                self.entries.clone()
+            }
+        };
+
+        for data in changes {
+            match data.id_tag_info() {
+                Some(id_tag_info) => {
+                    entries.insert(data.id_tag().clone(), id_tag_info.clone());
+                }
+                None => {
+                    entries.remove(data.id_tag());
+                }
+            }
+        }
+
+        if entries.len() > self.max_length {
+            return UpdateStatus::Failed;
+        }
+
+        self.entries = entries;
+        self.version = request.list_version();
+
+        UpdateStatus::Accepted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::authorization::AuthorizationData;
+    use crate::types::enumerations::AuthorizationStatus;
+
+    fn id_tag(value: &str) -> IdToken {
+        IdToken::try_from(value).unwrap()
+    }
+
+    fn entry(value: &str, status: Option<AuthorizationStatus>) -> AuthorizationData {
+        let mut data = AuthorizationData::builder(id_tag(value));
+        if let Some(status) = status {
+            data = data.id_tag_info(IdTagInfo::builder(status).build().unwrap());
+        }
+        data.build().unwrap()
+    }
+
+    fn request(
+        version: usize,
+        update_type: UpdateType,
+        entries: Vec<AuthorizationData>,
+    ) -> SendLocalListReq {
+        SendLocalListReq::builder(version, update_type)
+            .local_authorization_list(entries)
+            .build()
+            .unwrap()
+    }
+
+    fn status(list: &LocalAuthList, value: &str) -> Option<AuthorizationStatus> {
+        list.get(&id_tag(value)).map(IdTagInfo::status)
+    }
+
+    fn populated() -> LocalAuthList {
+        let mut list = LocalAuthList::new(3, 2);
+        list.update(&request(
+            1,
+            UpdateType::Full,
+            vec![
+                entry("B4A63CDF", Some(AuthorizationStatus::Accepted)),
+                entry("C3B52DEF", Some(AuthorizationStatus::Blocked)),
+            ],
+        ));
+        list
+    }
+
+    #[test]
+    fn test_new_list_is_empty() {
+        let list = LocalAuthList::new(10, 5);
+
+        assert_eq!(list.version(), 0);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_full_update() {
+        let mut list = populated();
+
+        let result = list.update(&request(
+            1,
+            UpdateType::Full,
+            vec![entry("D2C41EF0", Some(AuthorizationStatus::Accepted))],
+        ));
+
+        assert_eq!(result, UpdateStatus::Accepted);
+        assert_eq!(list.version(), 1);
+        assert_eq!(list.len(), 1);
+        assert_eq!(status(&list, "B4A63CDF"), None);
+        assert_eq!(
+            status(&list, "D2C41EF0"),
+            Some(AuthorizationStatus::Accepted)
+        );
+    }
+
+    #[test]
+    fn test_full_update_with_empty_list_clears_entries() {
+        let mut list = populated();
+
+        let result = list.update(&request(4, UpdateType::Full, Vec::new()));
+
+        assert_eq!(result, UpdateStatus::Accepted);
+        assert_eq!(list.version(), 4);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_differential_update() {
+        let mut list = populated();
+
+        let result = list.update(&request(
+            2,
+            UpdateType::Differential,
+            vec![
+                entry("b4a63cdf", None),
+                entry("C3B52DEF", Some(AuthorizationStatus::Accepted)),
+            ],
+        ));
+
+        assert_eq!(result, UpdateStatus::Accepted);
+        assert_eq!(list.version(), 2);
+        assert_eq!(status(&list, "B4A63CDF"), None);
+        assert_eq!(
+            status(&list, "C3B52DEF"),
+            Some(AuthorizationStatus::Accepted)
+        );
+    }
+
+    #[test]
+    fn test_differential_update_with_old_version() {
+        let mut list = populated();
+
+        let result = list.update(&request(
+            1,
+            UpdateType::Differential,
+            vec![entry("D2C41EF0", Some(AuthorizationStatus::Accepted))],
+        ));
+
+        assert_eq!(result, UpdateStatus::VersionMismatch);
+        assert_eq!(list, populated());
+    }
+
+    #[test]
+    fn test_update_larger_than_send_max_length() {
+        let mut list = populated();
+
+        let result = list.update(&request(
+            2,
+            UpdateType::Full,
+            vec![
+                entry("B4A63CDF", Some(AuthorizationStatus::Accepted)),
+                entry("C3B52DEF", Some(AuthorizationStatus::Accepted)),
+                entry("D2C41EF0", Some(AuthorizationStatus::Accepted)),
+            ],
+        ));
+
+        assert_eq!(result, UpdateStatus::Failed);
+        assert_eq!(list, populated());
+    }
+
+    #[test]
+    fn test_update_exceeding_max_length() {
+        let mut list = populated();
+        list.update(&request(
+            2,
+            UpdateType::Differential,
+            vec![entry("D2C41EF0", Some(AuthorizationStatus::Accepted))],
+        ));
+
+        let result = list.update(&request(
+            3,
+            UpdateType::Differential,
+            vec![entry("E1D30F01", Some(AuthorizationStatus::Accepted))],
+        ));
+
+        assert_eq!(result, UpdateStatus::Failed);
+        assert_eq!(list.version(), 2);
+        assert_eq!(list.len(), 3);
+    }
+}
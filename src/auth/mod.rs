@@ -0,0 +1,6 @@
+//! Authorization of identifiers by the Charge Point, using the Local
+//! Authorization List and the Authorization Cache.
+
+pub mod authorizer;
+pub mod cache;
+pub mod local_list;
@@ -0,0 +1,414 @@
+//! Standard configuration keys, as listed in chapter 9 of the OCPP 1.6
+//! specification.
+
+use std::str::FromStr;
+
+use crate::error::OcppError;
+use crate::types::enumerations::{wire_enum, Measurand};
+use crate::types::utils::CiString500Type;
+
+wire_enum! {
+    /// Feature profile a configuration key belongs to, also used as the
+    /// values of SupportedFeatureProfiles.
+    pub enum FeatureProfile {
+        /// Basic Charge Point functionality.
+        Core => "Core",
+        /// Support for firmware update management and diagnostic log
+        /// file download.
+        FirmwareManagement => "FirmwareManagement",
+        /// Features to manage the local authorization list in Charge
+        /// Points.
+        LocalAuthListManagement => "LocalAuthListManagement",
+        /// Support for reservation of a Charge Point.
+        Reservation => "Reservation",
+        /// Support for basic Smart Charging.
+        SmartCharging => "SmartCharging",
+        /// Support for remote triggering of Charge Point initiated
+        /// messages.
+        RemoteTrigger => "RemoteTrigger",
+    }
+}
+
+wire_enum! {
+    /// Unit accepted in charging schedules, as listed by
+    /// ChargingScheduleAllowedChargingRateUnit.
+    pub enum ChargingRateUnitName {
+        /// Charging schedules in Amperes.
+        Current => "Current",
+        /// Charging schedules in Watts.
+        Power => "Power",
+    }
+}
+
+wire_enum! {
+    /// Wiring of the phases of a connector, as listed by
+    /// ConnectorPhaseRotation.
+    pub enum PhaseRotation {
+        /// Not applicable, e.g. for a DC connector.
+        NotApplicable => "NotApplicable",
+        /// Unknown wiring.
+        Unknown => "Unknown",
+        /// Standard Reference Phasing.
+        Rst => "RST",
+        /// Reversed Reference Phasing.
+        Rts => "RTS",
+        /// Reversed 240 degree rotation.
+        Srt => "SRT",
+        /// Standard 120 degree rotation.
+        Str => "STR",
+        /// Standard 240 degree rotation.
+        Trs => "TRS",
+        /// Reversed 120 degree rotation.
+        Tsr => "TSR",
+    }
+}
+
+/// Whether the Central System may change a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Access {
+    /// The key is reported by GetConfiguration.req only.
+    ReadOnly,
+    /// The key may also be set by ChangeConfiguration.req.
+    ReadWrite,
+}
+
+/// Kind of element of a comma separated list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ListItem {
+    /// A [`Measurand`].
+    Measurand,
+    /// A [`FeatureProfile`].
+    FeatureProfile,
+    /// A [`ChargingRateUnitName`].
+    ChargingRateUnit,
+    /// A connector id and its [`PhaseRotation`], such as `1.RST`.
+    PhaseRotation,
+}
+
+impl ListItem {
+    fn is_valid(&self, item: &str) -> bool {
+        match self {
+            ListItem::Measurand => Measurand::from_str(item).is_ok(),
+            ListItem::FeatureProfile => FeatureProfile::from_str(item).is_ok(),
+            ListItem::ChargingRateUnit => ChargingRateUnitName::from_str(item).is_ok(),
+            ListItem::PhaseRotation => item.split_once('.').is_some_and(|(connector, rotation)| {
+                connector.parse::<usize>().is_ok() && PhaseRotation::from_str(rotation).is_ok()
+            }),
+        }
+    }
+}
+
+/// Type of the value of a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValueType {
+    /// `true` or `false`.
+    Boolean,
+    /// A non-negative integer.
+    Integer,
+    /// A comma separated list of items.
+    List(ListItem),
+    /// Any text fitting a CiString500Type, as reported in a
+    /// GetConfiguration.conf PDU.
+    Text,
+}
+
+impl ValueType {
+    /// Checks `value` and returns its canonical form: booleans in
+    /// lower case, integers without leading zeros and list items
+    /// without surrounding spaces. Text is kept as is.
+    pub fn normalize(&self, value: &str) -> Option<String> {
+        let trimmed = value.trim();
+        match self {
+            ValueType::Boolean => {
+                if trimmed.eq_ignore_ascii_case("true") {
+                    Some(String::from("true"))
+                } else if trimmed.eq_ignore_ascii_case("false") {
+                    Some(String::from("false"))
+                } else {
+                    None
+                }
+            }
+            ValueType::Integer => trimmed.parse::<u32>().ok().map(|value| value.to_string()),
+            ValueType::List(item) => {
+                if trimmed.is_empty() {
+                    return Some(String::new());
+                }
+                let items = trimmed.split(',').map(str::trim).collect::<Vec<_>>();
+                if items.iter().all(|value| item.is_valid(value)) {
+                    Some(items.join(","))
+                } else {
+                    None
+                }
+            }
+            ValueType::Text => CiString500Type::try_from(value)
+                .ok()
+                .map(|value| value.as_str().to_string()),
+        }
+    }
+}
+
+/// Definition of a standard or vendor-specific configuration key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyDefinition {
+    name: &'static str,
+    value_type: ValueType,
+    access: Access,
+    /// Feature profile of a standard key, None for vendor-specific
+    /// keys.
+    profile: Option<FeatureProfile>,
+    /// Whether a Charge Point supporting the profile must support the
+    /// key.
+    required: bool,
+    /// Key bounding the number of items of a list.
+    max_length_key: Option<&'static str>,
+}
+
+impl KeyDefinition {
+    /// Defines a vendor-specific key, to be registered with
+    /// [`ConfigurationStore::with_vendor_key`](super::store::ConfigurationStore::with_vendor_key).
+    pub const fn vendor(name: &'static str, value_type: ValueType, access: Access) -> Self {
+        KeyDefinition {
+            name,
+            value_type,
+            access,
+            profile: None,
+            required: false,
+            max_length_key: None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn value_type(&self) -> ValueType {
+        self.value_type
+    }
+
+    pub fn access(&self) -> Access {
+        self.access
+    }
+
+    pub fn profile(&self) -> Option<FeatureProfile> {
+        self.profile
+    }
+
+    pub fn required(&self) -> bool {
+        self.required
+    }
+
+    /// Key holding the maximum number of items of this list, such as
+    /// MeterValuesSampledDataMaxLength for MeterValuesSampledData.
+    pub fn max_length_key(&self) -> Option<&'static str> {
+        self.max_length_key
+    }
+
+    /// Checks `value` against the type of the key, returning its
+    /// canonical form.
+    pub fn normalize(&self, value: &str) -> Result<String, OcppError> {
+        self.value_type.normalize(value).ok_or_else(|| {
+            OcppError::PropertyConstraintViolation(format!(
+                "{}: invalid value \"{}\"",
+                self.name, value
+            ))
+        })
+    }
+}
+
+/// Declares the table of standard keys.
+macro_rules! standard_keys {
+    ($(
+        $name:literal: $value_type:expr, $access:ident, $profile:ident,
+        $required:literal $(, $max_length_key:literal)?;
+    )+) => {
+        const STANDARD_KEYS: &[KeyDefinition] = &[$(
+            KeyDefinition {
+                name: $name,
+                value_type: $value_type,
+                access: Access::$access,
+                profile: Some(FeatureProfile::$profile),
+                required: $required,
+                max_length_key: standard_keys!(@max_length $($max_length_key)?),
+            },
+        )+];
+    };
+    (@max_length) => { None };
+    (@max_length $key:literal) => { Some($key) };
+}
+
+use ValueType::{Boolean, Integer, List};
+
+standard_keys! {
+    "AllowOfflineTxForUnknownId": Boolean, ReadWrite, Core, false;
+    "AuthorizationCacheEnabled": Boolean, ReadWrite, Core, false;
+    "AuthorizeRemoteTxRequests": Boolean, ReadWrite, Core, true;
+    "BlinkRepeat": Integer, ReadWrite, Core, false;
+    "ClockAlignedDataInterval": Integer, ReadWrite, Core, true;
+    "ConnectionTimeOut": Integer, ReadWrite, Core, true;
+    "ConnectorPhaseRotation": List(ListItem::PhaseRotation), ReadWrite, Core, true,
+        "ConnectorPhaseRotationMaxLength";
+    "ConnectorPhaseRotationMaxLength": Integer, ReadOnly, Core, false;
+    "GetConfigurationMaxKeys": Integer, ReadOnly, Core, true;
+    "HeartbeatInterval": Integer, ReadWrite, Core, true;
+    "LightIntensity": Integer, ReadWrite, Core, false;
+    "LocalAuthorizeOffline": Boolean, ReadWrite, Core, true;
+    "LocalPreAuthorize": Boolean, ReadWrite, Core, true;
+    "MaxEnergyOnInvalidId": Integer, ReadWrite, Core, false;
+    "MeterValuesAlignedData": List(ListItem::Measurand), ReadWrite, Core, true,
+        "MeterValuesAlignedDataMaxLength";
+    "MeterValuesAlignedDataMaxLength": Integer, ReadOnly, Core, false;
+    "MeterValuesSampledData": List(ListItem::Measurand), ReadWrite, Core, true,
+        "MeterValuesSampledDataMaxLength";
+    "MeterValuesSampledDataMaxLength": Integer, ReadOnly, Core, false;
+    "MeterValueSampleInterval": Integer, ReadWrite, Core, true;
+    "MinimumStatusDuration": Integer, ReadWrite, Core, false;
+    "NumberOfConnectors": Integer, ReadOnly, Core, true;
+    "ResetRetries": Integer, ReadWrite, Core, true;
+    "StopTransactionOnEVSideDisconnect": Boolean, ReadWrite, Core, true;
+    "StopTransactionOnInvalidId": Boolean, ReadWrite, Core, true;
+    "StopTxnAlignedData": List(ListItem::Measurand), ReadWrite, Core, true,
+        "StopTxnAlignedDataMaxLength";
+    "StopTxnAlignedDataMaxLength": Integer, ReadOnly, Core, false;
+    "StopTxnSampledData": List(ListItem::Measurand), ReadWrite, Core, true,
+        "StopTxnSampledDataMaxLength";
+    "StopTxnSampledDataMaxLength": Integer, ReadOnly, Core, false;
+    "SupportedFeatureProfiles": List(ListItem::FeatureProfile), ReadOnly, Core, true,
+        "SupportedFeatureProfilesMaxLength";
+    "SupportedFeatureProfilesMaxLength": Integer, ReadOnly, Core, false;
+    "TransactionMessageAttempts": Integer, ReadWrite, Core, true;
+    "TransactionMessageRetryInterval": Integer, ReadWrite, Core, true;
+    "UnlockConnectorOnEVSideDisconnect": Boolean, ReadWrite, Core, true;
+    "WebSocketPingInterval": Integer, ReadWrite, Core, false;
+    "LocalAuthListEnabled": Boolean, ReadWrite, LocalAuthListManagement, true;
+    "LocalAuthListMaxLength": Integer, ReadOnly, LocalAuthListManagement, true;
+    "SendLocalListMaxLength": Integer, ReadOnly, LocalAuthListManagement, true;
+    "ReserveConnectorZeroSupported": Boolean, ReadOnly, Reservation, false;
+    "ChargeProfileMaxStackLevel": Integer, ReadOnly, SmartCharging, true;
+    "ChargingScheduleAllowedChargingRateUnit": List(ListItem::ChargingRateUnit), ReadOnly,
+        SmartCharging, true;
+    "ChargingScheduleMaxPeriods": Integer, ReadOnly, SmartCharging, true;
+    "ConnectorSwitch3to1PhaseSupported": Boolean, ReadOnly, SmartCharging, false;
+    "MaxChargingProfilesInstalled": Integer, ReadOnly, SmartCharging, true;
+}
+
+/// Every standard key, in the order of the specification.
+pub fn standard_keys() -> &'static [KeyDefinition] {
+    STANDARD_KEYS
+}
+
+/// Definition of the standard key `name`, compared case-insensitively.
+pub fn standard_key(name: &str) -> Option<&'static KeyDefinition> {
+    STANDARD_KEYS
+        .iter()
+        .find(|definition| definition.name.eq_ignore_ascii_case(name))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_standard_key_is_case_insensitive() {
+        let definition = standard_key("heartbeatinterval").unwrap();
+
+        assert_eq!(definition.name(), "HeartbeatInterval");
+        assert_eq!(definition.value_type(), ValueType::Integer);
+        assert_eq!(definition.access(), Access::ReadWrite);
+        assert_eq!(definition.profile(), Some(FeatureProfile::Core));
+        assert!(definition.required());
+    }
+
+    #[test]
+    fn test_unknown_key() {
+        assert_eq!(standard_key("HeartbeatPeriod"), None);
+    }
+
+    #[test]
+    fn test_max_length_keys_are_standard() {
+        for definition in standard_keys() {
+            if let Some(max_length_key) = definition.max_length_key() {
+                assert_eq!(
+                    standard_key(max_length_key).map(KeyDefinition::value_type),
+                    Some(ValueType::Integer)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_normalize_boolean() {
+        assert_eq!(
+            ValueType::Boolean.normalize("TRUE"),
+            Some(String::from("true"))
+        );
+        assert_eq!(ValueType::Boolean.normalize("1"), None);
+    }
+
+    #[test]
+    fn test_normalize_integer() {
+        assert_eq!(
+            ValueType::Integer.normalize("0300"),
+            Some(String::from("300"))
+        );
+        assert_eq!(ValueType::Integer.normalize("-1"), None);
+        assert_eq!(ValueType::Integer.normalize("1.5"), None);
+    }
+
+    #[test]
+    fn test_normalize_measurand_list() {
+        let value_type = ValueType::List(ListItem::Measurand);
+
+        assert_eq!(
+            value_type.normalize("Energy.Active.Import.Register, Power.Active.Import"),
+            Some(String::from(
+                "Energy.Active.Import.Register,Power.Active.Import"
+            ))
+        );
+        assert_eq!(value_type.normalize(""), Some(String::new()));
+        assert_eq!(value_type.normalize("Energy.Active.Import"), None);
+    }
+
+    #[test]
+    fn test_normalize_phase_rotation_list() {
+        let value_type = ValueType::List(ListItem::PhaseRotation);
+
+        assert_eq!(
+            value_type.normalize("0.RST,1.RST,2.RTS"),
+            Some(String::from("0.RST,1.RST,2.RTS"))
+        );
+        assert_eq!(value_type.normalize("RST"), None);
+        assert_eq!(value_type.normalize("1.XYZ"), None);
+    }
+
+    #[test]
+    fn test_invalid_value_is_property_constraint_violation() {
+        let err = standard_key("HeartbeatInterval")
+            .unwrap()
+            .normalize("often")
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            OcppError::PropertyConstraintViolation(String::from(
+                "HeartbeatInterval: invalid value \"often\""
+            ))
+        );
+    }
+
+    #[test]
+    fn test_normalize_text() {
+        assert_eq!(
+            ValueType::Text.normalize(" vendor value "),
+            Some(String::from(" vendor value "))
+        );
+        assert_eq!(
+            ValueType::Text
+                .normalize(&"x".repeat(500))
+                .map(|value| value.len()),
+            Some(500)
+        );
+        assert_eq!(ValueType::Text.normalize(&"x".repeat(501)), None);
+        assert_eq!(ValueType::Text.normalize("Caf\u{e9}"), None);
+    }
+}
@@ -0,0 +1,6 @@
+//! Configuration keys of a Charge Point, as described in chapter 9 of
+//! the OCPP 1.6 specification.
+
+pub mod keys;
+pub mod storage;
+pub mod store;
@@ -0,0 +1,267 @@
+//! Persistence of the configuration keys, so that values set by
+//! ChangeConfiguration.req survive a reboot of the Charge Point.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::error::OcppError;
+use crate::messages::change_configuration::ChangeConfigurationReq;
+use crate::persistence::{file_error, write_atomically};
+use crate::types::configuration::KeyValue;
+use crate::types::enumerations::ConfigurationStatus;
+
+use super::store::ConfigurationStore;
+
+/// Storage of the configuration keys.
+pub trait ConfigurationStorage {
+    /// Loads the saved keys, which are empty when nothing was saved yet.
+    fn load(&self) -> Result<Vec<KeyValue>, OcppError>;
+
+    /// Replaces the saved keys with `configuration`.
+    fn save(&mut self, configuration: &[KeyValue]) -> Result<(), OcppError>;
+}
+
+/// Storage keeping the keys in memory, for tests and Charge Points
+/// without persistent storage.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MemoryStorage {
+    configuration: Vec<KeyValue>,
+}
+
+impl MemoryStorage {
+    pub fn new() -> Self {
+        MemoryStorage::default()
+    }
+}
+
+impl ConfigurationStorage for MemoryStorage {
+    fn load(&self) -> Result<Vec<KeyValue>, OcppError> {
+        Ok(self.configuration.clone())
+    }
+
+    fn save(&mut self, configuration: &[KeyValue]) -> Result<(), OcppError> {
+        self.configuration = configuration.to_vec();
+
+        Ok(())
+    }
+}
+
+/// Storage keeping the keys in a JSON file, which is replaced as a
+/// whole on each save so that an interrupted save leaves the previous
+/// configuration intact.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileStorage {
+    path: PathBuf,
+}
+
+impl FileStorage {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        FileStorage { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl ConfigurationStorage for FileStorage {
+    fn load(&self) -> Result<Vec<KeyValue>, OcppError> {
+        let contents = match fs::read(&self.path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(file_error(&self.path, err)),
+        };
+
+        serde_json::from_slice(&contents).map_err(|err| file_error(&self.path, err))
+    }
+
+    fn save(&mut self, configuration: &[KeyValue]) -> Result<(), OcppError> {
+        let contents = serde_json::to_vec_pretty(configuration)
+            .map_err(|err| OcppError::InternalError(err.to_string()))?;
+
+        write_atomically(&self.path, &contents)
+    }
+}
+
+/// Configuration store whose changes are saved to a storage.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PersistentConfiguration<S> {
+    store: ConfigurationStore,
+    storage: S,
+}
+
+impl<S: ConfigurationStorage> PersistentConfiguration<S> {
+    /// Restores the values saved in `storage` into `store`, which holds
+    /// the supported keys with their initial values.
+    pub fn open(mut store: ConfigurationStore, storage: S) -> Result<Self, OcppError> {
+        store.restore(&storage.load()?);
+
+        Ok(PersistentConfiguration { store, storage })
+    }
+
+    pub fn store(&self) -> &ConfigurationStore {
+        &self.store
+    }
+
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// Sets and saves the value of `key`, as done by the Charge Point
+    /// itself. See [`ConfigurationStore::set`].
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), OcppError> {
+        let previous = self.store.clone();
+        self.store.set(key, value)?;
+
+        self.save().inspect_err(|_| self.store = previous)
+    }
+
+    /// Applies and saves a ChangeConfiguration.req. A change that could
+    /// not be saved is undone and answered with Rejected.
+    pub fn change_configuration(
+        &mut self,
+        request: &ChangeConfigurationReq,
+    ) -> ConfigurationStatus {
+        let previous = self.store.clone();
+        let status = self.store.change_configuration(request);
+        if !matches!(
+            status,
+            ConfigurationStatus::Accepted | ConfigurationStatus::RebootRequired
+        ) {
+            return status;
+        }
+
+        match self.save() {
+            Ok(()) => status,
+            Err(_) => {
+                self.store = previous;
+                ConfigurationStatus::Rejected
+            }
+        }
+    }
+
+    fn save(&mut self) -> Result<(), OcppError> {
+        let configuration = self.store.configuration()?;
+        self.storage.save(&configuration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::error::ErrorCode;
+    use crate::types::utils::{CiString500Type, CiString50Type};
+
+    fn store() -> ConfigurationStore {
+        ConfigurationStore::new()
+            .with_value("HeartbeatInterval", "300")
+            .unwrap()
+            .with_value("NumberOfConnectors", "2")
+            .unwrap()
+    }
+
+    fn request(key: &str, value: &str) -> ChangeConfigurationReq {
+        ChangeConfigurationReq::new(
+            CiString50Type::try_from(key).unwrap(),
+            CiString500Type::try_from(value).unwrap(),
+        )
+    }
+
+    fn test_path(test: &str) -> PathBuf {
+        crate::persistence::test_path(&format!("configuration-{}", test), "configuration.json")
+    }
+
+    struct FailingStorage;
+
+    impl ConfigurationStorage for FailingStorage {
+        fn load(&self) -> Result<Vec<KeyValue>, OcppError> {
+            Ok(Vec::new())
+        }
+
+        fn save(&mut self, _: &[KeyValue]) -> Result<(), OcppError> {
+            Err(OcppError::InternalError(String::from("disk full")))
+        }
+    }
+
+    #[test]
+    fn test_change_survives_reopening() {
+        let mut configuration =
+            PersistentConfiguration::open(store(), MemoryStorage::new()).unwrap();
+
+        let status = configuration.change_configuration(&request("HeartbeatInterval", "900"));
+        let reopened =
+            PersistentConfiguration::open(store(), configuration.storage().clone()).unwrap();
+
+        assert_eq!(status, ConfigurationStatus::Accepted);
+        assert_eq!(reopened.store().get_integer("HeartbeatInterval"), Some(900));
+    }
+
+    #[test]
+    fn test_rejected_change_is_not_saved() {
+        let mut configuration =
+            PersistentConfiguration::open(store(), MemoryStorage::new()).unwrap();
+
+        let status = configuration.change_configuration(&request("NumberOfConnectors", "4"));
+
+        assert_eq!(status, ConfigurationStatus::Rejected);
+        assert!(configuration.storage().load().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_change_not_saved_is_undone() {
+        let mut configuration = PersistentConfiguration::open(store(), FailingStorage).unwrap();
+
+        let status = configuration.change_configuration(&request("HeartbeatInterval", "900"));
+        let err = configuration.set("HeartbeatInterval", "600").unwrap_err();
+
+        assert_eq!(status, ConfigurationStatus::Rejected);
+        assert_eq!(err.code(), ErrorCode::InternalError);
+        assert_eq!(
+            configuration.store().get_integer("HeartbeatInterval"),
+            Some(300)
+        );
+    }
+
+    #[test]
+    fn test_file_storage_round_trip() {
+        let path = test_path("round_trip");
+        let mut storage = FileStorage::new(&path);
+        let configuration = store().configuration().unwrap();
+
+        storage.save(&configuration).unwrap();
+
+        assert_eq!(FileStorage::new(&path).load().unwrap(), configuration);
+        assert!(!crate::persistence::temporary_path(&path).exists());
+    }
+
+    #[test]
+    fn test_file_storage_without_file() {
+        let storage = FileStorage::new(test_path("without_file"));
+
+        assert_eq!(storage.load().unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn test_file_storage_with_corrupted_file() {
+        let path = test_path("corrupted_file");
+        fs::write(&path, "[{\"key\":").unwrap();
+
+        let err = FileStorage::new(&path).load().unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::InternalError);
+    }
+
+    #[test]
+    fn test_file_storage_reopening() {
+        let path = test_path("reopening");
+        let mut configuration =
+            PersistentConfiguration::open(store(), FileStorage::new(&path)).unwrap();
+
+        configuration.change_configuration(&request("HeartbeatInterval", "900"));
+        let reopened = PersistentConfiguration::open(store(), FileStorage::new(&path)).unwrap();
+
+        assert_eq!(reopened.store().get_integer("HeartbeatInterval"), Some(900));
+    }
+}
@@ -0,0 +1,507 @@
+//! Configuration of a Charge Point, read by GetConfiguration.req and
+//! changed by ChangeConfiguration.req.
+
+use crate::error::OcppError;
+use crate::messages::change_configuration::ChangeConfigurationReq;
+use crate::messages::get_configuration::{GetConfigurationConf, GetConfigurationReq};
+use crate::types::configuration::KeyValue;
+use crate::types::enumerations::ConfigurationStatus;
+use crate::types::utils::{CiString500Type, CiString50Type};
+
+use super::keys::{standard_key, Access, KeyDefinition};
+
+/// Values of the configuration keys supported by a Charge Point.
+///
+/// A key is supported once it was given a value with
+/// [`ConfigurationStore::with_value`] or
+/// [`ConfigurationStore::with_vendor_key`]. Keys are compared
+/// case-insensitively and reported in the order they were added.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ConfigurationStore {
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Entry {
+    definition: KeyDefinition,
+    value: String,
+    /// Whether a change only takes effect after a reboot.
+    reboot_required: bool,
+}
+
+impl ConfigurationStore {
+    /// Builds a store supporting no key.
+    pub fn new() -> Self {
+        ConfigurationStore::default()
+    }
+
+    /// Supports `key` with its initial `value`.
+    ///
+    /// Fails with NotSupported when `key` is not a standard key and
+    /// with PropertyConstraintViolation when `value` does not match its
+    /// type.
+    pub fn with_value(mut self, key: &str, value: &str) -> Result<Self, OcppError> {
+        let definition = standard_key(key)
+            .ok_or_else(|| OcppError::NotSupported(format!("{}: unknown key", key)))?;
+        self.support(*definition, value)?;
+
+        Ok(self)
+    }
+
+    /// Supports the vendor-specific key `definition` with its initial
+    /// `value`.
+    ///
+    /// Fails with PropertyConstraintViolation when the name of the key
+    /// is longer than 50 characters, is the name of a standard key, or
+    /// when `value` does not match its type.
+    pub fn with_vendor_key(
+        mut self,
+        definition: KeyDefinition,
+        value: &str,
+    ) -> Result<Self, OcppError> {
+        CiString50Type::try_from(definition.name())?;
+        if standard_key(definition.name()).is_some() {
+            return Err(OcppError::PropertyConstraintViolation(format!(
+                "{}: standard key",
+                definition.name()
+            )));
+        }
+        self.support(definition, value)?;
+
+        Ok(self)
+    }
+
+    /// Makes changes of the supported `key` answered with
+    /// RebootRequired.
+    pub fn with_reboot_required(mut self, key: &str) -> Result<Self, OcppError> {
+        self.entry_mut(key)?.reboot_required = true;
+
+        Ok(self)
+    }
+
+    /// Definitions of the supported keys.
+    pub fn keys(&self) -> impl Iterator<Item = &KeyDefinition> {
+        self.entries.iter().map(|entry| &entry.definition)
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.entry(key).is_some()
+    }
+
+    /// Value of `key`, if it is supported.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entry(key).map(|entry| entry.value.as_str())
+    }
+
+    /// Value of the boolean `key`.
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        self.get(key).and_then(|value| value.parse().ok())
+    }
+
+    /// Value of the integer `key`.
+    pub fn get_integer(&self, key: &str) -> Option<u32> {
+        self.get(key).and_then(|value| value.parse().ok())
+    }
+
+    /// Items of the comma separated list `key`.
+    pub fn get_list(&self, key: &str) -> Option<Vec<&str>> {
+        self.get(key)
+            .map(|value| value.split(',').filter(|item| !item.is_empty()).collect())
+    }
+
+    /// Sets the value of the supported `key`, including read-only ones,
+    /// as done by the Charge Point itself.
+    ///
+    /// Fails with NotSupported when `key` is not supported and with
+    /// PropertyConstraintViolation when `value` does not match its type
+    /// or holds more items than allowed by the matching MaxLength key.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), OcppError> {
+        let definition = self.entry_mut(key)?.definition;
+        let value = definition.normalize(value)?;
+
+        if let Some(max_length) = definition
+            .max_length_key()
+            .and_then(|max_length_key| self.get_integer(max_length_key))
+        {
+            let length = value.split(',').filter(|item| !item.is_empty()).count();
+            if length > max_length as usize {
+                return Err(OcppError::PropertyConstraintViolation(format!(
+                    "{}: {} items, at most {} allowed",
+                    definition.name(),
+                    length,
+                    max_length
+                )));
+            }
+        }
+
+        self.entry_mut(key)?.value = value;
+
+        Ok(())
+    }
+
+    /// Answers a GetConfiguration.req, listing every supported key when
+    /// the request names none. Requested keys that are not supported
+    /// are reported as unknown.
+    pub fn get_configuration(
+        &self,
+        request: &GetConfigurationReq,
+    ) -> Result<GetConfigurationConf, OcppError> {
+        let mut configuration_key = Vec::new();
+        let mut unknown_key = Vec::new();
+
+        match request.key() {
+            Some(keys) => {
+                for key in keys {
+                    match self.entry(key.as_str()) {
+                        Some(entry) => configuration_key.push(entry.key_value()?),
+                        None => unknown_key.push(key.clone()),
+                    }
+                }
+            }
+            None => {
+                for entry in &self.entries {
+                    configuration_key.push(entry.key_value()?);
+                }
+            }
+        }
+
+        let mut conf = GetConfigurationConf::builder();
+        if !configuration_key.is_empty() {
+            conf = conf.configuration_key(configuration_key);
+        }
+        if !unknown_key.is_empty() {
+            conf = conf.unknown_key(unknown_key);
+        }
+        conf.build()
+    }
+
+    /// Applies a ChangeConfiguration.req.
+    ///
+    /// Unsupported keys are answered with NotSupported, read-only keys
+    /// and invalid values with Rejected.
+    pub fn change_configuration(
+        &mut self,
+        request: &ChangeConfigurationReq,
+    ) -> ConfigurationStatus {
+        let key = request.key().as_str();
+        let Some(entry) = self.entry(key) else {
+            return ConfigurationStatus::NotSupported;
+        };
+        if entry.definition.access() == Access::ReadOnly {
+            return ConfigurationStatus::Rejected;
+        }
+        let reboot_required = entry.reboot_required;
+
+        match self.set(key, request.value().as_str()) {
+            Ok(()) if reboot_required => ConfigurationStatus::RebootRequired,
+            Ok(()) => ConfigurationStatus::Accepted,
+            Err(_) => ConfigurationStatus::Rejected,
+        }
+    }
+
+    /// Every supported key with its value, as saved by a
+    /// [`ConfigurationStorage`](super::storage::ConfigurationStorage).
+    pub fn configuration(&self) -> Result<Vec<KeyValue>, OcppError> {
+        self.entries.iter().map(Entry::key_value).collect()
+    }
+
+    /// Restores values previously returned by
+    /// [`ConfigurationStore::configuration`]. Keys that are no longer
+    /// supported, or whose value no longer matches their type, keep
+    /// their initial value.
+    ///
+    /// Read-only keys, such as NumberOfConnectors, keep the value given
+    /// by the firmware, which may have changed since they were saved.
+    pub fn restore(&mut self, configuration: &[KeyValue]) {
+        for key_value in configuration {
+            let key = key_value.key().as_str();
+            let writable = self
+                .entry(key)
+                .is_some_and(|entry| entry.definition.access() == Access::ReadWrite);
+            if !writable {
+                continue;
+            }
+            if let Some(value) = key_value.value() {
+                let _ = self.set(key, value.as_str());
+            }
+        }
+    }
+
+    fn support(&mut self, definition: KeyDefinition, value: &str) -> Result<(), OcppError> {
+        if self.entry(definition.name()).is_none() {
+            self.entries.push(Entry {
+                definition,
+                value: String::new(),
+                reboot_required: false,
+            });
+        }
+
+        self.set(definition.name(), value)
+    }
+
+    fn entry(&self, key: &str) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.definition.name().eq_ignore_ascii_case(key))
+    }
+
+    fn entry_mut(&mut self, key: &str) -> Result<&mut Entry, OcppError> {
+        self.entries
+            .iter_mut()
+            .find(|entry| entry.definition.name().eq_ignore_ascii_case(key))
+            .ok_or_else(|| OcppError::NotSupported(format!("{}: unsupported key", key)))
+    }
+}
+
+impl Entry {
+    fn key_value(&self) -> Result<KeyValue, OcppError> {
+        KeyValue::builder(
+            CiString50Type::try_from(self.definition.name())?,
+            self.definition.access() == Access::ReadOnly,
+        )
+        .value(CiString500Type::try_from(self.value.as_str())?)
+        .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::configuration::keys::ValueType;
+    use crate::error::ErrorCode;
+
+    fn store() -> ConfigurationStore {
+        ConfigurationStore::new()
+            .with_value("HeartbeatInterval", "300")
+            .unwrap()
+            .with_value("NumberOfConnectors", "2")
+            .unwrap()
+            .with_value("MeterValuesSampledDataMaxLength", "2")
+            .unwrap()
+            .with_value("MeterValuesSampledData", "Energy.Active.Import.Register")
+            .unwrap()
+            .with_value("LocalPreAuthorize", "false")
+            .unwrap()
+            .with_value("WebSocketPingInterval", "60")
+            .unwrap()
+            .with_reboot_required("WebSocketPingInterval")
+            .unwrap()
+    }
+
+    fn key(value: &str) -> CiString50Type {
+        CiString50Type::try_from(value).unwrap()
+    }
+
+    fn change(store: &mut ConfigurationStore, name: &str, value: &str) -> ConfigurationStatus {
+        store.change_configuration(&ChangeConfigurationReq::new(
+            key(name),
+            CiString500Type::try_from(value).unwrap(),
+        ))
+    }
+
+    #[test]
+    fn test_typed_values() {
+        let store = store();
+
+        assert_eq!(store.get_integer("heartbeatinterval"), Some(300));
+        assert_eq!(store.get_bool("LocalPreAuthorize"), Some(false));
+        assert_eq!(
+            store.get_list("MeterValuesSampledData"),
+            Some(vec!["Energy.Active.Import.Register"])
+        );
+        assert_eq!(store.get("ConnectionTimeOut"), None);
+    }
+
+    #[test]
+    fn test_with_unknown_key() {
+        let err = ConfigurationStore::new()
+            .with_value("HeartbeatPeriod", "300")
+            .unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::NotSupported);
+    }
+
+    #[test]
+    fn test_with_invalid_value() {
+        let err = ConfigurationStore::new()
+            .with_value("LocalPreAuthorize", "yes")
+            .unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::PropertyConstraintViolation);
+    }
+
+    #[test]
+    fn test_get_all_keys() {
+        let conf = store()
+            .get_configuration(&GetConfigurationReq::builder().build().unwrap())
+            .unwrap();
+
+        let keys = conf.configuration_key().unwrap();
+        assert_eq!(keys.len(), 6);
+        assert_eq!(keys[0].key().as_str(), "HeartbeatInterval");
+        assert_eq!(keys[0].value().map(CiString500Type::as_str), Some("300"));
+        assert!(!keys[0].readonly());
+        assert!(keys[1].readonly());
+        assert_eq!(conf.unknown_key(), None);
+    }
+
+    #[test]
+    fn test_get_requested_keys() {
+        let request = GetConfigurationReq::builder()
+            .key(vec![
+                key("numberofconnectors"),
+                key("ConnectionTimeOut"),
+                key("Vendor"),
+            ])
+            .build()
+            .unwrap();
+
+        let conf = store().get_configuration(&request).unwrap();
+
+        let keys = conf.configuration_key().unwrap();
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0].key().as_str(), "NumberOfConnectors");
+        assert_eq!(
+            conf.unknown_key(),
+            Some(&[key("ConnectionTimeOut"), key("Vendor")][..])
+        );
+    }
+
+    #[test]
+    fn test_change_configuration() {
+        let mut store = store();
+
+        assert_eq!(
+            change(&mut store, "HeartbeatInterval", "900"),
+            ConfigurationStatus::Accepted
+        );
+        assert_eq!(store.get_integer("HeartbeatInterval"), Some(900));
+    }
+
+    #[test]
+    fn test_change_configuration_reboot_required() {
+        let mut store = store();
+
+        assert_eq!(
+            change(&mut store, "WebSocketPingInterval", "30"),
+            ConfigurationStatus::RebootRequired
+        );
+        assert_eq!(store.get_integer("WebSocketPingInterval"), Some(30));
+    }
+
+    #[test]
+    fn test_change_configuration_not_supported() {
+        let mut store = store();
+
+        assert_eq!(
+            change(&mut store, "ConnectionTimeOut", "60"),
+            ConfigurationStatus::NotSupported
+        );
+        assert_eq!(
+            change(&mut store, "Vendor", "60"),
+            ConfigurationStatus::NotSupported
+        );
+    }
+
+    #[test]
+    fn test_change_configuration_read_only() {
+        let mut store = store();
+
+        assert_eq!(
+            change(&mut store, "NumberOfConnectors", "4"),
+            ConfigurationStatus::Rejected
+        );
+        assert_eq!(store.get_integer("NumberOfConnectors"), Some(2));
+    }
+
+    #[test]
+    fn test_change_configuration_invalid_value() {
+        let mut store = store();
+
+        assert_eq!(
+            change(&mut store, "HeartbeatInterval", "-5"),
+            ConfigurationStatus::Rejected
+        );
+        assert_eq!(
+            change(
+                &mut store,
+                "MeterValuesSampledData",
+                "Energy.Active.Import.Register,Power.Active.Import,Voltage"
+            ),
+            ConfigurationStatus::Rejected
+        );
+        assert_eq!(store.get_integer("HeartbeatInterval"), Some(300));
+    }
+
+    #[test]
+    fn test_set_read_only_key_locally() {
+        let mut store = store();
+
+        store.set("NumberOfConnectors", "3").unwrap();
+
+        assert_eq!(store.get_integer("NumberOfConnectors"), Some(3));
+    }
+
+    #[test]
+    fn test_vendor_key() {
+        let mut store = ConfigurationStore::new()
+            .with_vendor_key(
+                KeyDefinition::vendor("AcmeDisplayMessage", ValueType::Text, Access::ReadWrite),
+                " Welcome ",
+            )
+            .unwrap();
+
+        assert_eq!(
+            change(&mut store, "acmedisplaymessage", "Out of order"),
+            ConfigurationStatus::Accepted
+        );
+        assert_eq!(store.get("AcmeDisplayMessage"), Some("Out of order"));
+        assert_eq!(store.keys().next().unwrap().profile(), None);
+    }
+
+    #[test]
+    fn test_vendor_key_cannot_shadow_standard_key() {
+        let err = ConfigurationStore::new()
+            .with_vendor_key(
+                KeyDefinition::vendor("HeartbeatInterval", ValueType::Text, Access::ReadWrite),
+                "300",
+            )
+            .unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::PropertyConstraintViolation);
+    }
+
+    #[test]
+    fn test_restore_configuration() {
+        let mut saved = store();
+        change(&mut saved, "HeartbeatInterval", "900");
+        let mut configuration = saved.configuration().unwrap();
+        configuration.push(
+            KeyValue::builder(key("AcmeRetired"), false)
+                .value(CiString500Type::try_from("1").unwrap())
+                .build()
+                .unwrap(),
+        );
+
+        let mut restored = store();
+        restored.restore(&configuration);
+
+        assert_eq!(restored, saved);
+    }
+
+    #[test]
+    fn test_restore_keeps_read_only_values() {
+        let saved = store().configuration().unwrap();
+
+        let mut updated = ConfigurationStore::new()
+            .with_value("NumberOfConnectors", "4")
+            .unwrap()
+            .with_value("HeartbeatInterval", "600")
+            .unwrap();
+        updated.restore(&saved);
+
+        assert_eq!(updated.get_integer("NumberOfConnectors"), Some(4));
+        assert_eq!(updated.get_integer("HeartbeatInterval"), Some(300));
+    }
+}
@@ -0,0 +1,234 @@
+use std::fmt;
+
+use serde_json::error::Category;
+use serde_json::Map;
+
+use crate::frame::CallError;
+use crate::types::enumerations::wire_enum;
+
+wire_enum! {
+    /// Error code of a CALLERROR frame, as defined by OCPP-J.
+    pub enum ErrorCode {
+        /// Requested Action is not known by receiver.
+        NotImplemented => "NotImplemented",
+        /// Requested Action is recognized but not supported by the receiver.
+        NotSupported => "NotSupported",
+        /// An internal error occurred and the receiver was not able to
+        /// process the requested Action successfully.
+        InternalError => "InternalError",
+        /// Payload for Action is incomplete.
+        ProtocolError => "ProtocolError",
+        /// During the processing of Action a security issue occurred
+        /// preventing receiver from completing the Action successfully.
+        SecurityError => "SecurityError",
+        /// Payload for Action is syntactically incorrect or not conform
+        /// the PDU structure for Action.
+        FormationViolation => "FormationViolation",
+        /// Payload is syntactically correct but at least one field
+        /// contains an invalid value.
+        PropertyConstraintViolation => "PropertyConstraintViolation",
+        /// Payload for Action is syntactically correct but at least one
+        /// of the fields violates occurence constraints.
+        OccurenceConstraintViolation => "OccurenceConstraintViolation",
+        /// Payload for Action is syntactically correct but at least one
+        /// of the fields violates data type constraints (e.g. "somestring": 12).
+        TypeConstraintViolation => "TypeConstraintViolation",
+        /// Any other error not covered by the previous ones.
+        GenericError => "GenericError",
+    }
+}
+
+/// Error returned by decoding and validation, carrying the description
+/// that is sent back to the peer in a CALLERROR frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcppError {
+    NotImplemented(String),
+    NotSupported(String),
+    InternalError(String),
+    ProtocolError(String),
+    SecurityError(String),
+    FormationViolation(String),
+    PropertyConstraintViolation(String),
+    OccurenceConstraintViolation(String),
+    TypeConstraintViolation(String),
+    GenericError(String),
+}
+
+impl OcppError {
+    /// Builds an error from its CALLERROR code and description.
+    pub fn new(code: ErrorCode, description: impl Into<String>) -> OcppError {
+        let description = description.into();
+        match code {
+            ErrorCode::NotImplemented => OcppError::NotImplemented(description),
+            ErrorCode::NotSupported => OcppError::NotSupported(description),
+            ErrorCode::InternalError => OcppError::InternalError(description),
+            ErrorCode::ProtocolError => OcppError::ProtocolError(description),
+            ErrorCode::SecurityError => OcppError::SecurityError(description),
+            ErrorCode::FormationViolation => OcppError::FormationViolation(description),
+            ErrorCode::PropertyConstraintViolation => {
+                OcppError::PropertyConstraintViolation(description)
+            }
+            ErrorCode::OccurenceConstraintViolation => {
+                OcppError::OccurenceConstraintViolation(description)
+            }
+            ErrorCode::TypeConstraintViolation => OcppError::TypeConstraintViolation(description),
+            ErrorCode::GenericError => OcppError::GenericError(description),
+        }
+    }
+
+    /// CALLERROR code of the error.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            OcppError::NotImplemented(_) => ErrorCode::NotImplemented,
+            OcppError::NotSupported(_) => ErrorCode::NotSupported,
+            OcppError::InternalError(_) => ErrorCode::InternalError,
+            OcppError::ProtocolError(_) => ErrorCode::ProtocolError,
+            OcppError::SecurityError(_) => ErrorCode::SecurityError,
+            OcppError::FormationViolation(_) => ErrorCode::FormationViolation,
+            OcppError::PropertyConstraintViolation(_) => ErrorCode::PropertyConstraintViolation,
+            OcppError::OccurenceConstraintViolation(_) => ErrorCode::OccurenceConstraintViolation,
+            OcppError::TypeConstraintViolation(_) => ErrorCode::TypeConstraintViolation,
+            OcppError::GenericError(_) => ErrorCode::GenericError,
+        }
+    }
+
+    /// Human readable description of the error.
+    pub fn description(&self) -> &str {
+        match self {
+            OcppError::NotImplemented(description)
+            | OcppError::NotSupported(description)
+            | OcppError::InternalError(description)
+            | OcppError::ProtocolError(description)
+            | OcppError::SecurityError(description)
+            | OcppError::FormationViolation(description)
+            | OcppError::PropertyConstraintViolation(description)
+            | OcppError::OccurenceConstraintViolation(description)
+            | OcppError::TypeConstraintViolation(description)
+            | OcppError::GenericError(description) => description,
+        }
+    }
+
+    /// Builds the CALLERROR frame answering the CALL with `unique_id`.
+    /// Use [`CallError::with_details`] to attach an error details object.
+    pub fn to_call_error(&self, unique_id: impl Into<String>) -> CallError {
+        CallError::new(unique_id, self.code(), self.description(), Map::new())
+    }
+
+    /// Classifies a payload deserialization failure into the
+    /// matching CALLERROR code.
+    ///
+    /// Payloads are checked against their schema before being
+    /// deserialized, which reports missing, mistyped and unknown
+    /// properties precisely. What remains are values the schema
+    /// accepts but the types refuse, and payloads that are not JSON.
+    pub(crate) fn from_payload_error(action: &str, err: serde_json::Error) -> OcppError {
+        let description = format!("{}: {}", action, err);
+        match err.classify() {
+            Category::Data => OcppError::PropertyConstraintViolation(description),
+            Category::Syntax | Category::Eof | Category::Io => {
+                OcppError::FormationViolation(description)
+            }
+        }
+    }
+}
+
+impl fmt::Display for OcppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.code(), self.description())
+    }
+}
+
+impl std::error::Error for OcppError {}
+
+impl From<&CallError> for OcppError {
+    fn from(error: &CallError) -> OcppError {
+        OcppError::new(error.error_code(), error.error_description())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::frame::Frame;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_error_code_round_trip() {
+        let codes = [
+            ErrorCode::NotImplemented,
+            ErrorCode::NotSupported,
+            ErrorCode::InternalError,
+            ErrorCode::ProtocolError,
+            ErrorCode::SecurityError,
+            ErrorCode::FormationViolation,
+            ErrorCode::PropertyConstraintViolation,
+            ErrorCode::OccurenceConstraintViolation,
+            ErrorCode::TypeConstraintViolation,
+            ErrorCode::GenericError,
+        ];
+
+        for code in codes {
+            let error = OcppError::new(code, "description");
+
+            assert_eq!(error.code(), code);
+            assert_eq!(error.description(), "description");
+            assert_eq!(code.to_string().parse::<ErrorCode>(), Ok(code));
+        }
+    }
+
+    #[test]
+    fn test_to_call_error() {
+        let error = OcppError::NotSupported(String::from("Reservations are not supported"));
+
+        let frame = Frame::from(error.to_call_error("42"));
+
+        assert_eq!(
+            frame.encode(),
+            r#"[4,"42","NotSupported","Reservations are not supported",{}]"#
+        );
+    }
+
+    #[test]
+    fn test_to_call_error_with_details() {
+        let error = OcppError::InternalError(String::from("Database unavailable"));
+        let details = match json!({ "retryAfter": 30 }) {
+            Value::Object(details) => details,
+            _ => unreachable!(),
+        };
+
+        let frame = Frame::from(error.to_call_error("42").with_details(details));
+
+        assert_eq!(
+            frame.encode(),
+            r#"[4,"42","InternalError","Database unavailable",{"retryAfter":30}]"#
+        );
+    }
+
+    #[test]
+    fn test_from_call_error() {
+        let frame = Frame::parse(r#"[4, "42", "SecurityError", "Not allowed", {}]"#).unwrap();
+
+        let error = match &frame {
+            Frame::CallError(error) => OcppError::from(error),
+            _ => panic!("expected a CALLERROR"),
+        };
+
+        assert_eq!(error, OcppError::SecurityError(String::from("Not allowed")));
+    }
+
+    #[test]
+    fn test_from_payload_error() {
+        let data = serde_json::from_str::<u8>("256").unwrap_err();
+        let syntax = serde_json::from_str::<u8>("1 2").unwrap_err();
+
+        assert_eq!(
+            OcppError::from_payload_error("Heartbeat", data).code(),
+            ErrorCode::PropertyConstraintViolation
+        );
+        assert_eq!(
+            OcppError::from_payload_error("Heartbeat", syntax).code(),
+            ErrorCode::FormationViolation
+        );
+    }
+}
@@ -0,0 +1,714 @@
+//! OCPP-J RPC framing.
+//!
+//! Every OCPP-J message is a JSON array whose first element is the
+//! message type id:
+//!
+//! * CALL: `[2, "<uniqueId>", "<Action>", {<payload>}]`
+//! * CALLRESULT: `[3, "<uniqueId>", {<payload>}]`
+//! * CALLERROR: `[4, "<uniqueId>", "<errorCode>", "<errorDescription>", {<errorDetails>}]`
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::error::{ErrorCode, OcppError};
+use crate::messages::Validate;
+use crate::schema;
+
+use crate::messages::authorize::{AuthorizeConf, AuthorizeReq};
+use crate::messages::boot_notification::{BootNotificationConf, BootNotificationReq};
+use crate::messages::cancel_reservation::{CancelReservationConf, CancelReservationReq};
+use crate::messages::change_availability::{ChangeAvailabilityConf, ChangeAvailabilityReq};
+use crate::messages::change_configuration::{ChangeConfigurationConf, ChangeConfigurationReq};
+use crate::messages::clear_cache::{ClearCacheConf, ClearCacheReq};
+use crate::messages::clear_charging_profile::{ClearChargingProfileConf, ClearChargingProfileReq};
+use crate::messages::data_transfer::{DataTransferConf, DataTransferReq};
+use crate::messages::diagnostics_status_notification::{
+    DiagnosticsStatusNotificationConf, DiagnosticsStatusNotificationReq,
+};
+use crate::messages::firmware_status_notification::{
+    FirmwareStatusNotificationConf, FirmwareStatusNotificationReq,
+};
+use crate::messages::get_composite_schedule::{GetCompositeScheduleConf, GetCompositeScheduleReq};
+use crate::messages::get_configuration::{GetConfigurationConf, GetConfigurationReq};
+use crate::messages::get_diagnostics::{GetDiagnosticsConf, GetDiagnosticsReq};
+use crate::messages::get_local_list_version::{GetLocalListVersionConf, GetLocalListVersionReq};
+use crate::messages::heartbeat::{HeartbeatConf, HeartbeatReq};
+use crate::messages::meter_values::{MeterValuesConf, MeterValuesReq};
+use crate::messages::remote_start_transaction::{
+    RemoteStartTransactionConf, RemoteStartTransactionReq,
+};
+use crate::messages::remote_stop_transaction::{
+    RemoteStopTransactionConf, RemoteStopTransactionReq,
+};
+use crate::messages::reserve_now::{ReserveNowConf, ReserveNowReq};
+use crate::messages::reset::{ResetConf, ResetReq};
+use crate::messages::send_local_list::{SendLocalListConf, SendLocalListReq};
+use crate::messages::set_charging_profile::{SetChargingProfileConf, SetChargingProfileReq};
+use crate::messages::start_transaction::{StartTransactionConf, StartTransactionReq};
+use crate::messages::status_notification::{StatusNotificationConf, StatusNotificationReq};
+use crate::messages::stop_transaction::{StopTransactionConf, StopTransactionReq};
+use crate::messages::trigger_message::{TriggerMessageConf, TriggerMessageReq};
+use crate::messages::unlock_connector::{UnlockConnectorConf, UnlockConnectorReq};
+use crate::messages::update_firmware::{UpdateFirmwareConf, UpdateFirmwareReq};
+
+/// Message type id of a CALL frame.
+pub const CALL: u8 = 2;
+/// Message type id of a CALLRESULT frame.
+pub const CALL_RESULT: u8 = 3;
+/// Message type id of a CALLERROR frame.
+pub const CALL_ERROR: u8 = 4;
+
+/// Maximum length of the unique id of a message.
+pub const MAX_UNIQUE_ID_LENGTH: usize = 36;
+
+/// A single OCPP-J RPC frame.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Frame {
+    Call(Call),
+    CallResult(CallResult),
+    CallError(CallError),
+}
+
+impl Frame {
+    /// Parses a raw text frame as received from the WebSocket.
+    ///
+    /// Frames that are not well-formed OCPP-J messages are reported
+    /// as a `FormationViolation`.
+    pub fn parse(text: &str) -> Result<Frame, OcppError> {
+        let value: Value = serde_json::from_str(text)
+            .map_err(|e| OcppError::FormationViolation(format!("invalid JSON: {}", e)))?;
+        let elements = match value {
+            Value::Array(elements) => elements,
+            _ => {
+                return Err(OcppError::FormationViolation(String::from(
+                    "frame is not a JSON array",
+                )))
+            }
+        };
+
+        let message_type = match elements.first() {
+            Some(Value::Number(n)) if n.as_u64() == Some(CALL as u64) => CALL,
+            Some(Value::Number(n)) if n.as_u64() == Some(CALL_RESULT as u64) => CALL_RESULT,
+            Some(Value::Number(n)) if n.as_u64() == Some(CALL_ERROR as u64) => CALL_ERROR,
+            Some(other) => {
+                return Err(OcppError::FormationViolation(format!(
+                    "unknown message type id {}",
+                    other
+                )))
+            }
+            None => return Err(OcppError::FormationViolation(String::from("empty frame"))),
+        };
+
+        let expected = match message_type {
+            CALL => 4,
+            CALL_RESULT => 3,
+            _ => 5,
+        };
+        if elements.len() != expected {
+            return Err(OcppError::FormationViolation(format!(
+                "message type {} requires {} elements, got {}",
+                message_type,
+                expected,
+                elements.len()
+            )));
+        }
+
+        let mut elements = elements.into_iter().skip(1);
+        let unique_id = unique_id(elements.next())?;
+
+        let frame = match message_type {
+            CALL => Frame::Call(Call {
+                unique_id,
+                action: string(elements.next(), "action")?,
+                payload: object(elements.next(), "payload")?,
+            }),
+            CALL_RESULT => Frame::CallResult(CallResult {
+                unique_id,
+                payload: object(elements.next(), "payload")?,
+            }),
+            _ => Frame::CallError(CallError {
+                unique_id,
+                error_code: error_code(elements.next())?,
+                error_description: string(elements.next(), "errorDescription")?,
+                error_details: match object(elements.next(), "errorDetails")? {
+                    Value::Object(details) => details,
+                    _ => unreachable!(),
+                },
+            }),
+        };
+
+        Ok(frame)
+    }
+
+    /// Encodes the frame as the text sent over the WebSocket.
+    pub fn encode(&self) -> String {
+        let value = match self {
+            Frame::Call(call) => Value::Array(vec![
+                CALL.into(),
+                call.unique_id.clone().into(),
+                call.action.clone().into(),
+                call.payload.clone(),
+            ]),
+            Frame::CallResult(result) => Value::Array(vec![
+                CALL_RESULT.into(),
+                result.unique_id.clone().into(),
+                result.payload.clone(),
+            ]),
+            Frame::CallError(error) => Value::Array(vec![
+                CALL_ERROR.into(),
+                error.unique_id.clone().into(),
+                error.error_code.as_str().into(),
+                error.error_description.clone().into(),
+                Value::Object(error.error_details.clone()),
+            ]),
+        };
+
+        value.to_string()
+    }
+
+    /// Unique id of the message, shared by a CALL and its response.
+    pub fn unique_id(&self) -> &str {
+        match self {
+            Frame::Call(call) => &call.unique_id,
+            Frame::CallResult(result) => &result.unique_id,
+            Frame::CallError(error) => &error.unique_id,
+        }
+    }
+}
+
+impl From<Call> for Frame {
+    fn from(call: Call) -> Frame {
+        Frame::Call(call)
+    }
+}
+
+impl From<CallResult> for Frame {
+    fn from(result: CallResult) -> Frame {
+        Frame::CallResult(result)
+    }
+}
+
+impl From<CallError> for Frame {
+    fn from(error: CallError) -> Frame {
+        Frame::CallError(error)
+    }
+}
+
+/// A request sent by either side of the connection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Call {
+    unique_id: String,
+    action: String,
+    payload: Value,
+}
+
+impl Call {
+    /// Builds a CALL frame carrying the given request PDU.
+    pub fn new<R: OcppRequest>(unique_id: impl Into<String>, request: &R) -> Call {
+        Call {
+            unique_id: unique_id.into(),
+            action: R::ACTION.to_string(),
+            payload: to_payload(request),
+        }
+    }
+
+    pub fn unique_id(&self) -> &str {
+        &self.unique_id
+    }
+
+    pub fn action(&self) -> &str {
+        &self.action
+    }
+
+    pub fn payload(&self) -> &Value {
+        &self.payload
+    }
+
+    /// Checks the payload against the schema of the action, decodes it
+    /// into the matching request PDU and checks its constraints.
+    pub fn decode(&self) -> Result<Request, OcppError> {
+        Request::decode(&self.action, self.payload.clone())
+    }
+}
+
+/// A successful response to a [`Call`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallResult {
+    unique_id: String,
+    payload: Value,
+}
+
+impl CallResult {
+    /// Builds a CALLRESULT frame answering the CALL with `unique_id`.
+    pub fn new<T: Serialize>(unique_id: impl Into<String>, response: &T) -> CallResult {
+        CallResult {
+            unique_id: unique_id.into(),
+            payload: to_payload(response),
+        }
+    }
+
+    pub fn unique_id(&self) -> &str {
+        &self.unique_id
+    }
+
+    pub fn payload(&self) -> &Value {
+        &self.payload
+    }
+
+    /// Decodes the payload as the response PDU of request `R`.
+    ///
+    /// A CALLRESULT does not carry its action, so the caller must
+    /// know which request it answers.
+    pub fn decode<R: OcppRequest>(&self) -> Result<R::Response, OcppError> {
+        schema::validate_response(R::ACTION, &self.payload)?;
+        decode_payload(R::ACTION, self.payload.clone())
+    }
+}
+
+/// An error response to a [`Call`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallError {
+    unique_id: String,
+    error_code: ErrorCode,
+    error_description: String,
+    error_details: Map<String, Value>,
+}
+
+impl CallError {
+    pub fn new(
+        unique_id: impl Into<String>,
+        error_code: ErrorCode,
+        error_description: impl Into<String>,
+        error_details: Map<String, Value>,
+    ) -> CallError {
+        CallError {
+            unique_id: unique_id.into(),
+            error_code,
+            error_description: error_description.into(),
+            error_details,
+        }
+    }
+
+    /// Replaces the error details object.
+    pub fn with_details(mut self, error_details: Map<String, Value>) -> CallError {
+        self.error_details = error_details;
+        self
+    }
+
+    pub fn unique_id(&self) -> &str {
+        &self.unique_id
+    }
+
+    pub fn error_code(&self) -> ErrorCode {
+        self.error_code
+    }
+
+    pub fn error_description(&self) -> &str {
+        &self.error_description
+    }
+
+    pub fn error_details(&self) -> &Map<String, Value> {
+        &self.error_details
+    }
+}
+
+/// A request PDU, tied to the action name used in CALL frames
+/// and to the PDU expected in the matching CALLRESULT.
+pub trait OcppRequest: Serialize + DeserializeOwned + Validate {
+    /// Action name, e.g. `"BootNotification"`.
+    const ACTION: &'static str;
+    /// Confirmation PDU answering this request.
+    type Response: Serialize + DeserializeOwned + Validate;
+}
+
+macro_rules! actions {
+    ($($action:ident => $req:ty, $conf:ty;)+) => {
+        $(
+            impl OcppRequest for $req {
+                const ACTION: &'static str = stringify!($action);
+                type Response = $conf;
+            }
+
+            impl From<$req> for Request {
+                fn from(request: $req) -> Request {
+                    Request::$action(request)
+                }
+            }
+        )+
+
+        /// Any OCPP 1.6 request PDU, as carried by a CALL frame.
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Request {
+            $($action($req),)+
+        }
+
+        impl Request {
+            /// Decodes the payload of a CALL with the given action.
+            pub fn decode(action: &str, payload: Value) -> Result<Request, OcppError> {
+                match action {
+                    $(stringify!($action) => {
+                        schema::validate_request(action, &payload)?;
+                        decode_payload(action, payload).map(Request::$action)
+                    })+
+                    _ => Err(OcppError::NotImplemented(format!("unknown action {}", action))),
+                }
+            }
+
+            /// Action name of the request.
+            pub fn action(&self) -> &'static str {
+                match self {
+                    $(Request::$action(_) => stringify!($action),)+
+                }
+            }
+
+            /// Builds a CALL frame carrying this request.
+            pub fn to_call(&self, unique_id: impl Into<String>) -> Call {
+                match self {
+                    $(Request::$action(request) => Call::new(unique_id, request),)+
+                }
+            }
+        }
+    };
+}
+
+actions! {
+    Authorize => AuthorizeReq, AuthorizeConf;
+    BootNotification => BootNotificationReq, BootNotificationConf;
+    CancelReservation => CancelReservationReq, CancelReservationConf;
+    ChangeAvailability => ChangeAvailabilityReq, ChangeAvailabilityConf;
+    ChangeConfiguration => ChangeConfigurationReq, ChangeConfigurationConf;
+    ClearCache => ClearCacheReq, ClearCacheConf;
+    ClearChargingProfile => ClearChargingProfileReq, ClearChargingProfileConf;
+    DataTransfer => DataTransferReq, DataTransferConf;
+    DiagnosticsStatusNotification => DiagnosticsStatusNotificationReq, DiagnosticsStatusNotificationConf;
+    FirmwareStatusNotification => FirmwareStatusNotificationReq, FirmwareStatusNotificationConf;
+    GetCompositeSchedule => GetCompositeScheduleReq, GetCompositeScheduleConf;
+    GetConfiguration => GetConfigurationReq, GetConfigurationConf;
+    GetDiagnostics => GetDiagnosticsReq, GetDiagnosticsConf;
+    GetLocalListVersion => GetLocalListVersionReq, GetLocalListVersionConf;
+    Heartbeat => HeartbeatReq, HeartbeatConf;
+    MeterValues => MeterValuesReq, MeterValuesConf;
+    RemoteStartTransaction => RemoteStartTransactionReq, RemoteStartTransactionConf;
+    RemoteStopTransaction => RemoteStopTransactionReq, RemoteStopTransactionConf;
+    ReserveNow => ReserveNowReq, ReserveNowConf;
+    Reset => ResetReq, ResetConf;
+    SendLocalList => SendLocalListReq, SendLocalListConf;
+    SetChargingProfile => SetChargingProfileReq, SetChargingProfileConf;
+    StartTransaction => StartTransactionReq, StartTransactionConf;
+    StatusNotification => StatusNotificationReq, StatusNotificationConf;
+    StopTransaction => StopTransactionReq, StopTransactionConf;
+    TriggerMessage => TriggerMessageReq, TriggerMessageConf;
+    UnlockConnector => UnlockConnectorReq, UnlockConnectorConf;
+    UpdateFirmware => UpdateFirmwareReq, UpdateFirmwareConf;
+}
+
+fn decode_payload<T: DeserializeOwned + Validate>(
+    action: &str,
+    payload: Value,
+) -> Result<T, OcppError> {
+    let pdu: T =
+        serde_json::from_value(payload).map_err(|e| OcppError::from_payload_error(action, e))?;
+    pdu.validate()?;
+
+    Ok(pdu)
+}
+
+fn to_payload<T: Serialize>(pdu: &T) -> Value {
+    // PDUs only contain strings, numbers, booleans and nested structs,
+    // none of which can fail to serialize.
+    serde_json::to_value(pdu).expect("PDU serialization cannot fail")
+}
+
+fn unique_id(element: Option<Value>) -> Result<String, OcppError> {
+    let id = string(element, "uniqueId")?;
+    if id.len() > MAX_UNIQUE_ID_LENGTH {
+        return Err(OcppError::FormationViolation(format!(
+            "unique id {} is longer than {} characters",
+            id, MAX_UNIQUE_ID_LENGTH
+        )));
+    }
+
+    Ok(id)
+}
+
+fn string(element: Option<Value>, name: &'static str) -> Result<String, OcppError> {
+    match element {
+        Some(Value::String(s)) => Ok(s),
+        _ => Err(OcppError::FormationViolation(format!(
+            "{} is not a string",
+            name
+        ))),
+    }
+}
+
+fn error_code(element: Option<Value>) -> Result<ErrorCode, OcppError> {
+    string(element, "errorCode")?
+        .parse()
+        .map_err(|e| OcppError::FormationViolation(format!("{}", e)))
+}
+
+fn object(element: Option<Value>, name: &'static str) -> Result<Value, OcppError> {
+    match element {
+        Some(object @ Value::Object(_)) => Ok(object),
+        _ => Err(OcppError::FormationViolation(format!(
+            "{} is not an object",
+            name
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_call() {
+        let frame = Frame::parse(
+            r#"[2, "19223201", "BootNotification", {"chargePointVendor": "VendorX", "chargePointModel": "SingleSocketCharger"}]"#,
+        )
+        .unwrap();
+
+        let call = match frame {
+            Frame::Call(call) => call,
+            _ => panic!("expected a CALL"),
+        };
+        assert_eq!(call.unique_id(), "19223201");
+        assert_eq!(call.action(), "BootNotification");
+
+        let request = call.decode().unwrap();
+        assert_eq!(request.action(), "BootNotification");
+        assert!(matches!(request, Request::BootNotification(_)));
+    }
+
+    #[test]
+    fn test_parse_call_result() {
+        let frame = Frame::parse(
+            r#"[3, "19223201", {"currentTime": "2013-02-01T20:53:32.486Z", "interval": 300, "status": "Accepted"}]"#,
+        )
+        .unwrap();
+
+        let result = match frame {
+            Frame::CallResult(result) => result,
+            _ => panic!("expected a CALLRESULT"),
+        };
+        assert_eq!(result.unique_id(), "19223201");
+        assert!(result.decode::<BootNotificationReq>().is_ok());
+    }
+
+    #[test]
+    fn test_parse_call_error() {
+        let frame = Frame::parse(
+            r#"[4, "162376037", "NotSupported", "SetDisplayMessageRequest not implemented", {}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            frame,
+            Frame::CallError(CallError::new(
+                "162376037",
+                ErrorCode::NotSupported,
+                "SetDisplayMessageRequest not implemented",
+                Map::new(),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_encode_round_trip() {
+        let texts = [
+            r#"[2,"1","Heartbeat",{}]"#,
+            r#"[3,"1",{"currentTime":"2013-02-01T20:53:32.486Z"}]"#,
+            r#"[4,"1","GenericError","Something went wrong",{"cause":"unknown"}]"#,
+        ];
+
+        for text in texts {
+            assert_eq!(Frame::parse(text).unwrap().encode(), text);
+        }
+    }
+
+    #[test]
+    fn test_encode_call_from_request() {
+        let request: AuthorizeReq = serde_json::from_value(json!({ "idTag": "ABC123" })).unwrap();
+
+        let call = Call::new("42", &request);
+
+        assert_eq!(
+            Frame::from(call).encode(),
+            r#"[2,"42","Authorize",{"idTag":"ABC123"}]"#
+        );
+    }
+
+    #[test]
+    fn test_encode_call_result_from_response() {
+        let response: AuthorizeConf =
+            serde_json::from_value(json!({ "idTagInfo": { "status": "Accepted" } })).unwrap();
+
+        let result = CallResult::new("42", &response);
+
+        assert_eq!(
+            Frame::from(result).encode(),
+            r#"[3,"42",{"idTagInfo":{"status":"Accepted"}}]"#
+        );
+    }
+
+    fn parse_error_code(text: &str) -> ErrorCode {
+        Frame::parse(text).unwrap_err().code()
+    }
+
+    fn decode_error_code(text: &str) -> ErrorCode {
+        match Frame::parse(text).unwrap() {
+            Frame::Call(call) => match call.decode() {
+                Err(error) => error.code(),
+                Ok(_) => panic!("expected decoding to fail"),
+            },
+            _ => panic!("expected a CALL"),
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_json() {
+        assert_eq!(
+            parse_error_code("[2, \"1\", "),
+            ErrorCode::FormationViolation
+        );
+        assert_eq!(parse_error_code("{}"), ErrorCode::FormationViolation);
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_message_type() {
+        assert_eq!(
+            parse_error_code(r#"[5, "1", {}]"#),
+            ErrorCode::FormationViolation
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_wrong_length() {
+        assert_eq!(
+            parse_error_code(r#"[2, "1", "Heartbeat"]"#),
+            ErrorCode::FormationViolation
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_elements() {
+        assert_eq!(
+            parse_error_code(r#"[2, 1, "Heartbeat", {}]"#),
+            ErrorCode::FormationViolation
+        );
+        assert_eq!(
+            parse_error_code(r#"[2, "1", "Heartbeat", []]"#),
+            ErrorCode::FormationViolation
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_error_code() {
+        assert_eq!(
+            parse_error_code(r#"[4, "1", "Timeout", "", {}]"#),
+            ErrorCode::FormationViolation
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_long_unique_id() {
+        let id = "a".repeat(MAX_UNIQUE_ID_LENGTH + 1);
+        let text = format!(r#"[2, "{}", "Heartbeat", {{}}]"#, id);
+
+        assert_eq!(parse_error_code(&text), ErrorCode::FormationViolation);
+    }
+
+    #[test]
+    fn test_decode_unknown_action() {
+        assert_eq!(
+            decode_error_code(r#"[2, "1", "SetDisplayMessage", {}]"#),
+            ErrorCode::NotImplemented
+        );
+    }
+
+    #[test]
+    fn test_decode_payload_with_wrong_type() {
+        assert_eq!(
+            decode_error_code(r#"[2, "1", "Authorize", {"idTag": 42}]"#),
+            ErrorCode::TypeConstraintViolation
+        );
+    }
+
+    #[test]
+    fn test_decode_payload_without_required_field() {
+        assert_eq!(
+            decode_error_code(r#"[2, "1", "Authorize", {}]"#),
+            ErrorCode::OccurenceConstraintViolation
+        );
+    }
+
+    #[test]
+    fn test_decode_payload_with_invalid_value() {
+        assert_eq!(
+            decode_error_code(
+                r#"[2, "1", "ChangeAvailability", {"connectorId": 0, "type": "Medium"}]"#
+            ),
+            ErrorCode::PropertyConstraintViolation
+        );
+    }
+
+    #[test]
+    fn test_decode_payload_with_too_long_string() {
+        let text = format!(
+            r#"[2, "1", "Authorize", {{"idTag": "{}"}}]"#,
+            "a".repeat(21)
+        );
+
+        assert_eq!(
+            decode_error_code(&text),
+            ErrorCode::PropertyConstraintViolation
+        );
+    }
+
+    #[test]
+    fn test_decode_payload_with_additional_property() {
+        assert_eq!(
+            decode_error_code(r#"[2, "1", "Heartbeat", {"uptime": 3600}]"#),
+            ErrorCode::FormationViolation
+        );
+    }
+
+    #[test]
+    fn test_decode_response_against_schema() {
+        let result = CallResult {
+            unique_id: String::from("1"),
+            payload: json!({ "currentTime": "not a date" }),
+        };
+
+        let error = result.decode::<HeartbeatReq>().unwrap_err();
+
+        assert_eq!(error.code(), ErrorCode::TypeConstraintViolation);
+    }
+
+    #[test]
+    fn test_decode_payload_failing_validation() {
+        assert_eq!(
+            decode_error_code(r#"[2, "1", "MeterValues", {"connectorId": 1, "meterValue": []}]"#),
+            ErrorCode::OccurenceConstraintViolation
+        );
+    }
+
+    #[test]
+    fn test_call_error_for_failed_decoding() {
+        let call = match Frame::parse(r#"[2, "7", "Authorize", {}]"#).unwrap() {
+            Frame::Call(call) => call,
+            _ => panic!("expected a CALL"),
+        };
+
+        let error = match call.decode() {
+            Err(error) => error.to_call_error(call.unique_id()),
+            Ok(_) => panic!("expected decoding to fail"),
+        };
+
+        assert_eq!(error.unique_id(), "7");
+        assert_eq!(error.error_code(), ErrorCode::OccurenceConstraintViolation);
+    }
+}
@@ -1,2 +1,10 @@
-mod messages;
-mod types;
+pub mod auth;
+pub mod configuration;
+pub mod error;
+pub mod frame;
+pub mod messages;
+mod persistence;
+pub mod schema;
+pub mod smart_charging;
+pub mod transport;
+pub mod types;
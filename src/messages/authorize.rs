@@ -1,16 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use crate::messages::Validate;
 use crate::types::authorization::{IdTagInfo, IdToken};
 
 /// Authorize.req PDU sent by the Charge Point to the Central System.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct AuthorizeReq {
     /// This contains the identifier
     /// that needs to be authorized.
     id_tag: IdToken,
 }
 
+impl AuthorizeReq {
+    pub fn new(id_tag: IdToken) -> AuthorizeReq {
+        AuthorizeReq { id_tag }
+    }
+
+    pub fn id_tag(&self) -> &IdToken {
+        &self.id_tag
+    }
+}
+
+impl Validate for AuthorizeReq {}
+
 /// Authorize.conf PDU sent by the Central System
 /// to the Charge Point in response to a Authorize.req PDU.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct AuthorizeConf {
     /// This contains information about authorization status,
     /// expiry and parent id.
     id_tag_info: IdTagInfo,
 }
+
+impl AuthorizeConf {
+    pub fn new(id_tag_info: IdTagInfo) -> AuthorizeConf {
+        AuthorizeConf { id_tag_info }
+    }
+
+    pub fn id_tag_info(&self) -> &IdTagInfo {
+        &self.id_tag_info
+    }
+}
+
+impl Validate for AuthorizeConf {}
@@ -1,44 +1,289 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
+use crate::error::OcppError;
+use crate::messages::Validate;
 use crate::types::enumerations::RegistrationStatus;
 
 use crate::types::utils::{CiString20Type, CiString25Type, CiString50Type};
 
 /// BootNotification.req PDU sent by the Charge Point to the
 /// Central System.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct BootNotificationReq {
     /// This contains a value that identifies the
     /// serial number of the Charge Box inside
     /// the Charge Point.
     /// Deprecated, will be removed in future version.
+    #[serde(skip_serializing_if = "Option::is_none")]
     charge_box_serial_number: Option<CiString25Type>,
     /// This contains a value that identifies the model of the Charge Point.
     charge_point_model: CiString20Type,
     /// This contains a value that identifies the
     /// serial number of the Charge Point.
+    #[serde(skip_serializing_if = "Option::is_none")]
     charge_point_serial_number: Option<CiString25Type>,
     /// This contains a value that identifies
     /// the vendor of the ChargePoint.
     charge_point_vendor: CiString20Type,
     /// This contains the firmware version of the Charge Point.
+    #[serde(skip_serializing_if = "Option::is_none")]
     firmware_version: Option<CiString50Type>,
     /// This contains the ICCID of the modem's SIM card.
+    #[serde(skip_serializing_if = "Option::is_none")]
     iccid: Option<CiString20Type>,
     /// This contains the IMSI of the modem's SIM card.
+    #[serde(skip_serializing_if = "Option::is_none")]
     imsi: Option<CiString20Type>,
     /// This contains the serial number of the main power meter
     /// of the Charge Point.
+    #[serde(skip_serializing_if = "Option::is_none")]
     meter_serial_number: Option<CiString25Type>,
     /// This contains the type of the main power meter
     /// of the Charge Point.
+    #[serde(skip_serializing_if = "Option::is_none")]
     meter_type: Option<CiString25Type>,
 }
 
+impl BootNotificationReq {
+    pub fn builder(
+        charge_point_vendor: CiString20Type,
+        charge_point_model: CiString20Type,
+    ) -> BootNotificationReqBuilder {
+        BootNotificationReqBuilder {
+            inner: BootNotificationReq {
+                charge_point_vendor,
+                charge_point_model,
+                charge_box_serial_number: None,
+                charge_point_serial_number: None,
+                firmware_version: None,
+                iccid: None,
+                imsi: None,
+                meter_serial_number: None,
+                meter_type: None,
+            },
+        }
+    }
+
+    pub fn charge_box_serial_number(&self) -> Option<&CiString25Type> {
+        self.charge_box_serial_number.as_ref()
+    }
+
+    pub fn charge_point_model(&self) -> &CiString20Type {
+        &self.charge_point_model
+    }
+
+    pub fn charge_point_serial_number(&self) -> Option<&CiString25Type> {
+        self.charge_point_serial_number.as_ref()
+    }
+
+    pub fn charge_point_vendor(&self) -> &CiString20Type {
+        &self.charge_point_vendor
+    }
+
+    pub fn firmware_version(&self) -> Option<&CiString50Type> {
+        self.firmware_version.as_ref()
+    }
+
+    pub fn iccid(&self) -> Option<&CiString20Type> {
+        self.iccid.as_ref()
+    }
+
+    pub fn imsi(&self) -> Option<&CiString20Type> {
+        self.imsi.as_ref()
+    }
+
+    pub fn meter_serial_number(&self) -> Option<&CiString25Type> {
+        self.meter_serial_number.as_ref()
+    }
+
+    pub fn meter_type(&self) -> Option<&CiString25Type> {
+        self.meter_type.as_ref()
+    }
+}
+
+/// Builder for BootNotificationReq, created by [`BootNotificationReq::builder`].
+#[derive(Debug, Clone)]
+pub struct BootNotificationReqBuilder {
+    inner: BootNotificationReq,
+}
+
+impl BootNotificationReqBuilder {
+    pub fn charge_box_serial_number(
+        mut self,
+        charge_box_serial_number: CiString25Type,
+    ) -> BootNotificationReqBuilder {
+        self.inner.charge_box_serial_number = Some(charge_box_serial_number);
+        self
+    }
+
+    pub fn charge_point_serial_number(
+        mut self,
+        charge_point_serial_number: CiString25Type,
+    ) -> BootNotificationReqBuilder {
+        self.inner.charge_point_serial_number = Some(charge_point_serial_number);
+        self
+    }
+
+    pub fn firmware_version(
+        mut self,
+        firmware_version: CiString50Type,
+    ) -> BootNotificationReqBuilder {
+        self.inner.firmware_version = Some(firmware_version);
+        self
+    }
+
+    pub fn iccid(mut self, iccid: CiString20Type) -> BootNotificationReqBuilder {
+        self.inner.iccid = Some(iccid);
+        self
+    }
+
+    pub fn imsi(mut self, imsi: CiString20Type) -> BootNotificationReqBuilder {
+        self.inner.imsi = Some(imsi);
+        self
+    }
+
+    pub fn meter_serial_number(
+        mut self,
+        meter_serial_number: CiString25Type,
+    ) -> BootNotificationReqBuilder {
+        self.inner.meter_serial_number = Some(meter_serial_number);
+        self
+    }
+
+    pub fn meter_type(mut self, meter_type: CiString25Type) -> BootNotificationReqBuilder {
+        self.inner.meter_type = Some(meter_type);
+        self
+    }
+
+    /// Finishes the BootNotificationReq, checking its constraints.
+    pub fn build(self) -> Result<BootNotificationReq, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for BootNotificationReq {}
+
 /// BootNotification.conf PDU sent by the Central System
 /// to the Charge Point in response to a BootNotification.req PDU.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct BootNotificationConf {
     /// This contains the Central System's current time.
     current_time: DateTime<Utc>,
     interval: usize,
     status: RegistrationStatus,
 }
+
+impl BootNotificationConf {
+    pub fn new(
+        current_time: DateTime<Utc>,
+        interval: usize,
+        status: RegistrationStatus,
+    ) -> BootNotificationConf {
+        BootNotificationConf {
+            current_time,
+            interval,
+            status,
+        }
+    }
+
+    pub fn current_time(&self) -> DateTime<Utc> {
+        self.current_time
+    }
+
+    pub fn interval(&self) -> usize {
+        self.interval
+    }
+
+    pub fn status(&self) -> RegistrationStatus {
+        self.status
+    }
+}
+
+impl Validate for BootNotificationConf {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_boot_notification_req_round_trip() {
+        let payload = json!({
+            "chargePointVendor": "VendorX",
+            "chargePointModel": "SingleSocketCharger",
+            "chargePointSerialNumber": "cp-01234",
+            "firmwareVersion": "1.0.3",
+            "meterType": "ACME 3000"
+        });
+
+        let req: BootNotificationReq = serde_json::from_value(payload.clone()).unwrap();
+
+        assert_eq!(serde_json::to_value(&req).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_boot_notification_req_omits_absent_fields() {
+        let payload = json!({
+            "chargePointVendor": "VendorX",
+            "chargePointModel": "SingleSocketCharger"
+        });
+
+        let req: BootNotificationReq = serde_json::from_value(payload).unwrap();
+        let serialized = serde_json::to_string(&req).unwrap();
+
+        assert_eq!(
+            serialized,
+            r#"{"chargePointModel":"SingleSocketCharger","chargePointVendor":"VendorX"}"#
+        );
+    }
+
+    #[test]
+    fn test_boot_notification_req_without_required_field() {
+        let payload = json!({ "chargePointVendor": "VendorX" });
+
+        assert!(serde_json::from_value::<BootNotificationReq>(payload).is_err());
+    }
+
+    #[test]
+    fn test_boot_notification_req_builder() {
+        let req = BootNotificationReq::builder(
+            CiString20Type::try_from("VendorX").unwrap(),
+            CiString20Type::try_from("SingleSocketCharger").unwrap(),
+        )
+        .firmware_version(CiString50Type::try_from("1.0.3").unwrap())
+        .build()
+        .unwrap();
+
+        assert_eq!(req.charge_point_vendor().as_str(), "VendorX");
+        assert_eq!(req.charge_point_model().as_str(), "SingleSocketCharger");
+        assert_eq!(req.firmware_version().map(|v| v.as_str()), Some("1.0.3"));
+        assert_eq!(req.meter_type(), None);
+        assert_eq!(
+            serde_json::to_value(&req).unwrap(),
+            json!({
+                "chargePointVendor": "VendorX",
+                "chargePointModel": "SingleSocketCharger",
+                "firmwareVersion": "1.0.3"
+            })
+        );
+    }
+
+    #[test]
+    fn test_boot_notification_conf_round_trip() {
+        let payload = json!({
+            "currentTime": "2013-02-01T20:53:32.486Z",
+            "interval": 300,
+            "status": "Accepted"
+        });
+
+        let conf: BootNotificationConf = serde_json::from_value(payload.clone()).unwrap();
+
+        assert_eq!(serde_json::to_value(&conf).unwrap(), payload);
+    }
+}
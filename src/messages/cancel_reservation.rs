@@ -1,16 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use crate::messages::Validate;
 use crate::types::enumerations::CancelReservationStatus;
 
 /// CancelReservation.req PDU sent by the Central System to the
 /// Charge Point.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct CancelReservationReq {
     /// Id of the reservation to cancel.
     reservation_id: usize,
 }
 
+impl CancelReservationReq {
+    pub fn new(reservation_id: usize) -> CancelReservationReq {
+        CancelReservationReq { reservation_id }
+    }
+
+    pub fn reservation_id(&self) -> usize {
+        self.reservation_id
+    }
+}
+
+impl Validate for CancelReservationReq {}
+
 /// CancelReservation.conf PDU sent by the Charge Point to the
 /// Central System in response to a CancelReservation.req PDU.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct CancelReservationConf {
     /// This indicates the success or failure of the cancelling
     /// of a reservation by Central System.
     status: CancelReservationStatus,
 }
+
+impl CancelReservationConf {
+    pub fn new(status: CancelReservationStatus) -> CancelReservationConf {
+        CancelReservationConf { status }
+    }
+
+    pub fn status(&self) -> CancelReservationStatus {
+        self.status
+    }
+}
+
+impl Validate for CancelReservationConf {}
@@ -1,7 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use crate::messages::Validate;
 use crate::types::enumerations::{AvailabilityStatus, AvailabilityType};
 
 /// ChangeAvailability.req PDU sent by the Central System to the
 /// Charge Point.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ChangeAvailabilityReq {
     /// The id of the connector for which availability
     /// needs to change. Id '0' (zero) is used if the
@@ -10,13 +15,63 @@ pub struct ChangeAvailabilityReq {
     connector_id: usize,
     /// This contains the type of availability change
     /// that the Charge Point should perform.
+    #[serde(rename = "type")]
     availability_type: AvailabilityType,
 }
 
+impl ChangeAvailabilityReq {
+    pub fn new(connector_id: usize, availability_type: AvailabilityType) -> ChangeAvailabilityReq {
+        ChangeAvailabilityReq {
+            connector_id,
+            availability_type,
+        }
+    }
+
+    pub fn connector_id(&self) -> usize {
+        self.connector_id
+    }
+
+    pub fn availability_type(&self) -> AvailabilityType {
+        self.availability_type
+    }
+}
+
+impl Validate for ChangeAvailabilityReq {}
+
 /// ChangeAvailability.conf PDU return by Charge Point to
 /// Central System.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ChangeAvailabilityConf {
     /// This indicates whether the Charge Point is able
     /// to perform the availability change.
     status: AvailabilityStatus,
 }
+
+impl ChangeAvailabilityConf {
+    pub fn new(status: AvailabilityStatus) -> ChangeAvailabilityConf {
+        ChangeAvailabilityConf { status }
+    }
+
+    pub fn status(&self) -> AvailabilityStatus {
+        self.status
+    }
+}
+
+impl Validate for ChangeAvailabilityConf {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_change_availability_req_uses_type_field() {
+        let payload = json!({ "connectorId": 0, "type": "Inoperative" });
+
+        let req: ChangeAvailabilityReq = serde_json::from_value(payload.clone()).unwrap();
+
+        assert_eq!(serde_json::to_value(&req).unwrap(), payload);
+    }
+}
@@ -1,3 +1,53 @@
-pub struct ChangeConfigurationReq {}
+use serde::{Deserialize, Serialize};
 
-pub struct ChangeConfigurationConf {}
+use crate::messages::Validate;
+use crate::types::enumerations::ConfigurationStatus;
+use crate::types::utils::{CiString500Type, CiString50Type};
+
+/// ChangeConfiguration.req PDU sent by the Central System to the
+/// Charge Point.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeConfigurationReq {
+    /// The name of the configuration setting to change.
+    key: CiString50Type,
+    /// The new value as string for the setting.
+    value: CiString500Type,
+}
+
+impl ChangeConfigurationReq {
+    pub fn new(key: CiString50Type, value: CiString500Type) -> ChangeConfigurationReq {
+        ChangeConfigurationReq { key, value }
+    }
+
+    pub fn key(&self) -> &CiString50Type {
+        &self.key
+    }
+
+    pub fn value(&self) -> &CiString500Type {
+        &self.value
+    }
+}
+
+impl Validate for ChangeConfigurationReq {}
+
+/// ChangeConfiguration.conf PDU sent by the Charge Point to the
+/// Central System in response to a ChangeConfiguration.req PDU.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeConfigurationConf {
+    /// Returns whether configuration change has been accepted.
+    status: ConfigurationStatus,
+}
+
+impl ChangeConfigurationConf {
+    pub fn new(status: ConfigurationStatus) -> ChangeConfigurationConf {
+        ChangeConfigurationConf { status }
+    }
+
+    pub fn status(&self) -> ConfigurationStatus {
+        self.status
+    }
+}
+
+impl Validate for ChangeConfigurationConf {}
@@ -1,9 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+use crate::messages::Validate;
 use crate::types::enumerations::ClearCacheStatus;
 
+/// ClearCache.req PDU sent by the Central System to the
+/// Charge Point.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ClearCacheReq {}
+
+impl ClearCacheReq {
+    pub fn new() -> ClearCacheReq {
+        ClearCacheReq {}
+    }
+}
+
+impl Validate for ClearCacheReq {}
+
 /// ClearCache.conf PDU sent by the Charge Point to the
 /// Central System.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ClearCacheConf {
     /// Accepted if the Charge Point has executed
     /// the request, otherwise rejected.
     status: ClearCacheStatus,
 }
+
+impl ClearCacheConf {
+    pub fn new(status: ClearCacheStatus) -> ClearCacheConf {
+        ClearCacheConf { status }
+    }
+
+    pub fn status(&self) -> ClearCacheStatus {
+        self.status
+    }
+}
+
+impl Validate for ClearCacheConf {}
@@ -1,3 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::OcppError;
+use crate::messages::Validate;
 use crate::types::enumerations::{ChargingProfilePurposeType, ClearChargingProfileStatus};
 
 /// ClearChargingProfile.req PDU sent by the Central System
@@ -8,8 +12,11 @@ use crate::types::enumerations::{ChargingProfilePurposeType, ClearChargingProfil
 /// selection of charging profiles that match with the values
 /// of the optional connector_id, stack_level, and
 /// charging_profile_purpose fields.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ClearChargingProfileReq {
     /// The ID of the charging profile to clear.
+    #[serde(skip_serializing_if = "Option::is_none")]
     id: Option<usize>,
     /// Specifies the ID of the connector for which to
     /// clear charging profiles. A connectorId of zero (0)
@@ -19,20 +26,108 @@ pub struct ClearChargingProfileReq {
     /// Absence of this parameter means the clearing applies
     /// to all charging profiles that match the other criteria
     /// in the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
     connector_id: Option<usize>,
     /// Specifies to purpose of the charging profiles that
     /// will be cleared, if they meet the other criteria
     /// in the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
     charging_profile_purpose: Option<ChargingProfilePurposeType>,
     /// Specifies the stackLevel for which charging profiles
     /// will be cleared, if they meet the other criteria in
     /// the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
     stack_level: Option<usize>,
 }
 
+impl ClearChargingProfileReq {
+    /// Without criteria, every charging profile installed on the
+    /// Charge Point is cleared.
+    pub fn builder() -> ClearChargingProfileReqBuilder {
+        ClearChargingProfileReqBuilder {
+            inner: ClearChargingProfileReq {
+                id: None,
+                connector_id: None,
+                charging_profile_purpose: None,
+                stack_level: None,
+            },
+        }
+    }
+
+    pub fn id(&self) -> Option<usize> {
+        self.id
+    }
+
+    pub fn connector_id(&self) -> Option<usize> {
+        self.connector_id
+    }
+
+    pub fn charging_profile_purpose(&self) -> Option<ChargingProfilePurposeType> {
+        self.charging_profile_purpose
+    }
+
+    pub fn stack_level(&self) -> Option<usize> {
+        self.stack_level
+    }
+}
+
+/// Builder for ClearChargingProfileReq, created by [`ClearChargingProfileReq::builder`].
+#[derive(Debug, Clone)]
+pub struct ClearChargingProfileReqBuilder {
+    inner: ClearChargingProfileReq,
+}
+
+impl ClearChargingProfileReqBuilder {
+    pub fn id(mut self, id: usize) -> ClearChargingProfileReqBuilder {
+        self.inner.id = Some(id);
+        self
+    }
+
+    pub fn connector_id(mut self, connector_id: usize) -> ClearChargingProfileReqBuilder {
+        self.inner.connector_id = Some(connector_id);
+        self
+    }
+
+    pub fn charging_profile_purpose(
+        mut self,
+        charging_profile_purpose: ChargingProfilePurposeType,
+    ) -> ClearChargingProfileReqBuilder {
+        self.inner.charging_profile_purpose = Some(charging_profile_purpose);
+        self
+    }
+
+    pub fn stack_level(mut self, stack_level: usize) -> ClearChargingProfileReqBuilder {
+        self.inner.stack_level = Some(stack_level);
+        self
+    }
+
+    /// Finishes the ClearChargingProfileReq, checking its constraints.
+    pub fn build(self) -> Result<ClearChargingProfileReq, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for ClearChargingProfileReq {}
+
 /// ClearChargingProfile.conf PDU sent by the Charge Point to the
 /// Central System in response to a ClearChargingProfile.req PDU.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ClearChargingProfileConf {
     /// Indicates if the Charge Point was able to execute the request.
     status: ClearChargingProfileStatus,
 }
+
+impl ClearChargingProfileConf {
+    pub fn new(status: ClearChargingProfileStatus) -> ClearChargingProfileConf {
+        ClearChargingProfileConf { status }
+    }
+
+    pub fn status(&self) -> ClearChargingProfileStatus {
+        self.status
+    }
+}
+
+impl Validate for ClearChargingProfileConf {}
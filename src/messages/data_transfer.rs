@@ -1,23 +1,123 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::OcppError;
+use crate::messages::Validate;
 use crate::types::utils::{CiString255Type, CiString50Type};
 
 use crate::types::enumerations::DataTransferStatus;
 
 /// DataTransfer.req PDU sent either by the Central System
 /// to the Charge Point or vice versa.
-pub struct DataTransferReq<'a> {
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataTransferReq {
     /// This identifies the Vendor specific implementation.
     vendor_id: CiString255Type,
     /// Additional identification field.
+    #[serde(skip_serializing_if = "Option::is_none")]
     message_id: Option<CiString50Type>,
     /// Data without specified length or format.
-    data: Option<&'a [u8]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<String>,
+}
+
+impl DataTransferReq {
+    pub fn builder(vendor_id: CiString255Type) -> DataTransferReqBuilder {
+        DataTransferReqBuilder {
+            inner: DataTransferReq {
+                vendor_id,
+                message_id: None,
+                data: None,
+            },
+        }
+    }
+
+    pub fn vendor_id(&self) -> &CiString255Type {
+        &self.vendor_id
+    }
+
+    pub fn message_id(&self) -> Option<&CiString50Type> {
+        self.message_id.as_ref()
+    }
+
+    pub fn data(&self) -> Option<&str> {
+        self.data.as_deref()
+    }
+}
+
+/// Builder for DataTransferReq, created by [`DataTransferReq::builder`].
+#[derive(Debug, Clone)]
+pub struct DataTransferReqBuilder {
+    inner: DataTransferReq,
+}
+
+impl DataTransferReqBuilder {
+    pub fn message_id(mut self, message_id: CiString50Type) -> DataTransferReqBuilder {
+        self.inner.message_id = Some(message_id);
+        self
+    }
+
+    pub fn data(mut self, data: String) -> DataTransferReqBuilder {
+        self.inner.data = Some(data);
+        self
+    }
+
+    /// Finishes the DataTransferReq, checking its constraints.
+    pub fn build(self) -> Result<DataTransferReq, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
 }
 
+impl Validate for DataTransferReq {}
+
 /// DataTransfer.conf PDU sent by the Charge Point to the
 /// Central System or vice versa in response to a DataTransfer.req PDU.
-pub struct DataTransferConf<'a> {
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataTransferConf {
     /// This indicates the success or failure of the data transfer.
     status: DataTransferStatus,
     /// Data in response to request.
-    data: Option<&'a [u8]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<String>,
+}
+
+impl DataTransferConf {
+    pub fn builder(status: DataTransferStatus) -> DataTransferConfBuilder {
+        DataTransferConfBuilder {
+            inner: DataTransferConf { status, data: None },
+        }
+    }
+
+    pub fn status(&self) -> DataTransferStatus {
+        self.status
+    }
+
+    pub fn data(&self) -> Option<&str> {
+        self.data.as_deref()
+    }
+}
+
+/// Builder for DataTransferConf, created by [`DataTransferConf::builder`].
+#[derive(Debug, Clone)]
+pub struct DataTransferConfBuilder {
+    inner: DataTransferConf,
+}
+
+impl DataTransferConfBuilder {
+    pub fn data(mut self, data: String) -> DataTransferConfBuilder {
+        self.inner.data = Some(data);
+        self
+    }
+
+    /// Finishes the DataTransferConf, checking its constraints.
+    pub fn build(self) -> Result<DataTransferConf, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
 }
+
+impl Validate for DataTransferConf {}
@@ -1,8 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use crate::messages::Validate;
 use crate::types::enumerations::DiagnosticsStatus;
 
 /// DiagnosticsStatusNotification.req PDU sent by the Charge Point
 /// to the Central System.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct DiagnosticsStatusNotificationReq {
     /// This contains the status of the diagnostics upload.
     status: DiagnosticsStatus,
 }
+
+impl DiagnosticsStatusNotificationReq {
+    pub fn new(status: DiagnosticsStatus) -> DiagnosticsStatusNotificationReq {
+        DiagnosticsStatusNotificationReq { status }
+    }
+
+    pub fn status(&self) -> DiagnosticsStatus {
+        self.status
+    }
+}
+
+impl Validate for DiagnosticsStatusNotificationReq {}
+
+/// DiagnosticsStatusNotification.conf PDU sent by the Central System to the
+/// Charge Point in response to a DiagnosticsStatusNotification.req PDU.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct DiagnosticsStatusNotificationConf {}
+
+impl DiagnosticsStatusNotificationConf {
+    pub fn new() -> DiagnosticsStatusNotificationConf {
+        DiagnosticsStatusNotificationConf {}
+    }
+}
+
+impl Validate for DiagnosticsStatusNotificationConf {}
@@ -1,9 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+use crate::messages::Validate;
 use crate::types::enumerations::FirmwareStatus;
 
 /// FirmwareStatusNotification.req PDU sent by
 /// the Charge Point to the Central System.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct FirmwareStatusNotificationReq {
     /// This contains the progress status of the
     /// firmware installation.
     status: FirmwareStatus,
 }
+
+impl FirmwareStatusNotificationReq {
+    pub fn new(status: FirmwareStatus) -> FirmwareStatusNotificationReq {
+        FirmwareStatusNotificationReq { status }
+    }
+
+    pub fn status(&self) -> FirmwareStatus {
+        self.status
+    }
+}
+
+impl Validate for FirmwareStatusNotificationReq {}
+
+/// FirmwareStatusNotification.conf PDU sent by the Central System to the
+/// Charge Point in response to a FirmwareStatusNotification.req PDU.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct FirmwareStatusNotificationConf {}
+
+impl FirmwareStatusNotificationConf {
+    pub fn new() -> FirmwareStatusNotificationConf {
+        FirmwareStatusNotificationConf {}
+    }
+}
+
+impl Validate for FirmwareStatusNotificationConf {}
@@ -1,11 +1,16 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
+use crate::error::OcppError;
+use crate::messages::Validate;
 use crate::types::enumerations::{ChargingRateUnitType, GetCompositeScheduleStatus};
 
 use crate::types::charging_profile::ChargingSchedule;
 
 /// GetCompositeSchedule.req PDU sent by the Central System
 /// to the Charge Point.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct GetCompositeScheduleReq {
     /// The ID of the Connector for which the schedule is requested.
     /// When ConnectorId = 0, the Charge Point will calculate the
@@ -14,11 +19,63 @@ pub struct GetCompositeScheduleReq {
     /// Time in seconds. Length of requested schedule.
     duration: usize,
     /// Can be used to force a power or current profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
     charging_rate_unit: Option<ChargingRateUnitType>,
 }
 
+impl GetCompositeScheduleReq {
+    pub fn builder(connector_id: usize, duration: usize) -> GetCompositeScheduleReqBuilder {
+        GetCompositeScheduleReqBuilder {
+            inner: GetCompositeScheduleReq {
+                connector_id,
+                duration,
+                charging_rate_unit: None,
+            },
+        }
+    }
+
+    pub fn connector_id(&self) -> usize {
+        self.connector_id
+    }
+
+    pub fn duration(&self) -> usize {
+        self.duration
+    }
+
+    pub fn charging_rate_unit(&self) -> Option<ChargingRateUnitType> {
+        self.charging_rate_unit
+    }
+}
+
+/// Builder for GetCompositeScheduleReq, created by [`GetCompositeScheduleReq::builder`].
+#[derive(Debug, Clone)]
+pub struct GetCompositeScheduleReqBuilder {
+    inner: GetCompositeScheduleReq,
+}
+
+impl GetCompositeScheduleReqBuilder {
+    pub fn charging_rate_unit(
+        mut self,
+        charging_rate_unit: ChargingRateUnitType,
+    ) -> GetCompositeScheduleReqBuilder {
+        self.inner.charging_rate_unit = Some(charging_rate_unit);
+        self
+    }
+
+    /// Finishes the GetCompositeScheduleReq, checking its constraints.
+    pub fn build(self) -> Result<GetCompositeScheduleReq, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for GetCompositeScheduleReq {}
+
 /// GetCompositeSchedule.conf PDU sent by the Charge Point
 /// to the Central System in response to a GetCompositeSchedule.req PDU.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct GetCompositeScheduleConf {
     /// Status of the request.
     /// The Charge Point will indicate if it was able
@@ -26,12 +83,134 @@ pub struct GetCompositeScheduleConf {
     status: GetCompositeScheduleStatus,
     /// The charging schedule contained in this notification
     /// applies to a Connector.
+    #[serde(skip_serializing_if = "Option::is_none")]
     connector_id: Option<usize>,
     /// Time. Periods contained in the charging profile are
     /// relative to this point in time.
+    #[serde(skip_serializing_if = "Option::is_none")]
     schedule_start: Option<DateTime<Utc>>,
     /// Planned Composite Charging Schedule, the energy
     /// consumption over time.
     /// Always relative to ScheduleStart.
+    #[serde(skip_serializing_if = "Option::is_none")]
     charging_schedule: Option<ChargingSchedule>,
 }
+
+impl GetCompositeScheduleConf {
+    pub fn builder(status: GetCompositeScheduleStatus) -> GetCompositeScheduleConfBuilder {
+        GetCompositeScheduleConfBuilder {
+            inner: GetCompositeScheduleConf {
+                status,
+                connector_id: None,
+                schedule_start: None,
+                charging_schedule: None,
+            },
+        }
+    }
+
+    pub fn status(&self) -> GetCompositeScheduleStatus {
+        self.status
+    }
+
+    pub fn connector_id(&self) -> Option<usize> {
+        self.connector_id
+    }
+
+    pub fn schedule_start(&self) -> Option<DateTime<Utc>> {
+        self.schedule_start
+    }
+
+    pub fn charging_schedule(&self) -> Option<&ChargingSchedule> {
+        self.charging_schedule.as_ref()
+    }
+}
+
+/// Builder for GetCompositeScheduleConf, created by [`GetCompositeScheduleConf::builder`].
+#[derive(Debug, Clone)]
+pub struct GetCompositeScheduleConfBuilder {
+    inner: GetCompositeScheduleConf,
+}
+
+impl GetCompositeScheduleConfBuilder {
+    pub fn connector_id(mut self, connector_id: usize) -> GetCompositeScheduleConfBuilder {
+        self.inner.connector_id = Some(connector_id);
+        self
+    }
+
+    pub fn schedule_start(
+        mut self,
+        schedule_start: DateTime<Utc>,
+    ) -> GetCompositeScheduleConfBuilder {
+        self.inner.schedule_start = Some(schedule_start);
+        self
+    }
+
+    pub fn charging_schedule(
+        mut self,
+        charging_schedule: ChargingSchedule,
+    ) -> GetCompositeScheduleConfBuilder {
+        self.inner.charging_schedule = Some(charging_schedule);
+        self
+    }
+
+    /// Finishes the GetCompositeScheduleConf, checking its constraints.
+    pub fn build(self) -> Result<GetCompositeScheduleConf, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for GetCompositeScheduleConf {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_get_composite_schedule_req_round_trip() {
+        let payload = json!({
+            "connectorId": 1,
+            "duration": 3600,
+            "chargingRateUnit": "W"
+        });
+
+        let req: GetCompositeScheduleReq = serde_json::from_value(payload.clone()).unwrap();
+
+        assert_eq!(serde_json::to_value(&req).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_get_composite_schedule_conf_round_trip() {
+        let payload = json!({
+            "status": "Accepted",
+            "connectorId": 1,
+            "scheduleStart": "2013-02-01T15:09:18Z",
+            "chargingSchedule": {
+                "duration": 3600,
+                "startSchedule": "2013-02-01T15:09:18Z",
+                "chargingRateUnit": "A",
+                "chargingSchedulePeriod": [
+                    { "startPeriod": 0, "limit": 32.0, "numberPhases": 3 },
+                    { "startPeriod": 1800, "limit": 16.5 }
+                ],
+                "minChargingRate": 6.0
+            }
+        });
+
+        let conf: GetCompositeScheduleConf = serde_json::from_value(payload.clone()).unwrap();
+
+        assert_eq!(serde_json::to_value(&conf).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_get_composite_schedule_conf_rejected_omits_schedule() {
+        let payload = json!({ "status": "Rejected" });
+
+        let conf: GetCompositeScheduleConf = serde_json::from_value(payload.clone()).unwrap();
+
+        assert_eq!(serde_json::to_value(&conf).unwrap(), payload);
+    }
+}
@@ -1,20 +1,115 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::OcppError;
+use crate::messages::Validate;
 use crate::types::utils::CiString50Type;
 
 use crate::types::configuration::KeyValue;
 
 /// GetConfiguration.req PDU  sent by the Central System
 /// to the Charge Point.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct GetConfigurationReq {
     /// List of keys for which the configuration
     /// value is required.
+    #[serde(skip_serializing_if = "Option::is_none")]
     key: Option<Vec<CiString50Type>>,
 }
 
+impl GetConfigurationReq {
+    /// Without keys, the Charge Point reports its whole configuration.
+    pub fn builder() -> GetConfigurationReqBuilder {
+        GetConfigurationReqBuilder {
+            inner: GetConfigurationReq { key: None },
+        }
+    }
+
+    pub fn key(&self) -> Option<&[CiString50Type]> {
+        self.key.as_deref()
+    }
+}
+
+/// Builder for GetConfigurationReq, created by [`GetConfigurationReq::builder`].
+#[derive(Debug, Clone)]
+pub struct GetConfigurationReqBuilder {
+    inner: GetConfigurationReq,
+}
+
+impl GetConfigurationReqBuilder {
+    pub fn key(mut self, key: Vec<CiString50Type>) -> GetConfigurationReqBuilder {
+        self.inner.key = Some(key);
+        self
+    }
+
+    /// Finishes the GetConfigurationReq, checking its constraints.
+    pub fn build(self) -> Result<GetConfigurationReq, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for GetConfigurationReq {}
+
 /// GetConfiguration.conf PDU sent by Charge Point to the
 /// Central System in response to a GetConfiguration.req.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct GetConfigurationConf {
     /// List of requested or known keys.
+    #[serde(skip_serializing_if = "Option::is_none")]
     configuration_key: Option<Vec<KeyValue>>,
     /// Requested keys that are unknown.
+    #[serde(skip_serializing_if = "Option::is_none")]
     unknown_key: Option<Vec<CiString50Type>>,
 }
+
+impl GetConfigurationConf {
+    pub fn builder() -> GetConfigurationConfBuilder {
+        GetConfigurationConfBuilder {
+            inner: GetConfigurationConf {
+                configuration_key: None,
+                unknown_key: None,
+            },
+        }
+    }
+
+    pub fn configuration_key(&self) -> Option<&[KeyValue]> {
+        self.configuration_key.as_deref()
+    }
+
+    pub fn unknown_key(&self) -> Option<&[CiString50Type]> {
+        self.unknown_key.as_deref()
+    }
+}
+
+/// Builder for GetConfigurationConf, created by [`GetConfigurationConf::builder`].
+#[derive(Debug, Clone)]
+pub struct GetConfigurationConfBuilder {
+    inner: GetConfigurationConf,
+}
+
+impl GetConfigurationConfBuilder {
+    pub fn configuration_key(
+        mut self,
+        configuration_key: Vec<KeyValue>,
+    ) -> GetConfigurationConfBuilder {
+        self.inner.configuration_key = Some(configuration_key);
+        self
+    }
+
+    pub fn unknown_key(mut self, unknown_key: Vec<CiString50Type>) -> GetConfigurationConfBuilder {
+        self.inner.unknown_key = Some(unknown_key);
+        self
+    }
+
+    /// Finishes the GetConfigurationConf, checking its constraints.
+    pub fn build(self) -> Result<GetConfigurationConf, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for GetConfigurationConf {}
@@ -1,3 +1,204 @@
-pub struct GetDiagnosticsReq {}
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
-pub struct GetDiagnosticsConf {}
+use crate::error::OcppError;
+use crate::messages::Validate;
+use crate::types::utils::CiString255Type;
+
+/// GetDiagnostics.req PDU sent by the Central System to the
+/// Charge Point.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDiagnosticsReq {
+    /// This contains the location (directory) where the
+    /// diagnostics file shall be uploaded to.
+    location: String,
+    /// This specifies how many times Charge Point must try to
+    /// upload the diagnostics before giving up. If this field is
+    /// not present, it is left to Charge Point to decide how many
+    /// times it wants to retry.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retries: Option<usize>,
+    /// The interval in seconds after which a retry may be
+    /// attempted. If this field is not present, it is left to
+    /// Charge Point to decide how long to wait between attempts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retry_interval: Option<usize>,
+    /// This contains the date and time of the oldest logging
+    /// information to include in the diagnostics.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_time: Option<DateTime<Utc>>,
+    /// This contains the date and time of the latest logging
+    /// information to include in the diagnostics.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop_time: Option<DateTime<Utc>>,
+}
+
+impl GetDiagnosticsReq {
+    pub fn builder(location: String) -> GetDiagnosticsReqBuilder {
+        GetDiagnosticsReqBuilder {
+            inner: GetDiagnosticsReq {
+                location,
+                retries: None,
+                retry_interval: None,
+                start_time: None,
+                stop_time: None,
+            },
+        }
+    }
+
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    pub fn retries(&self) -> Option<usize> {
+        self.retries
+    }
+
+    pub fn retry_interval(&self) -> Option<usize> {
+        self.retry_interval
+    }
+
+    pub fn start_time(&self) -> Option<DateTime<Utc>> {
+        self.start_time
+    }
+
+    pub fn stop_time(&self) -> Option<DateTime<Utc>> {
+        self.stop_time
+    }
+}
+
+/// Builder for GetDiagnosticsReq, created by [`GetDiagnosticsReq::builder`].
+#[derive(Debug, Clone)]
+pub struct GetDiagnosticsReqBuilder {
+    inner: GetDiagnosticsReq,
+}
+
+impl GetDiagnosticsReqBuilder {
+    pub fn retries(mut self, retries: usize) -> GetDiagnosticsReqBuilder {
+        self.inner.retries = Some(retries);
+        self
+    }
+
+    pub fn retry_interval(mut self, retry_interval: usize) -> GetDiagnosticsReqBuilder {
+        self.inner.retry_interval = Some(retry_interval);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: DateTime<Utc>) -> GetDiagnosticsReqBuilder {
+        self.inner.start_time = Some(start_time);
+        self
+    }
+
+    pub fn stop_time(mut self, stop_time: DateTime<Utc>) -> GetDiagnosticsReqBuilder {
+        self.inner.stop_time = Some(stop_time);
+        self
+    }
+
+    /// Finishes the GetDiagnosticsReq, checking its constraints.
+    pub fn build(self) -> Result<GetDiagnosticsReq, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for GetDiagnosticsReq {
+    fn validate(&self) -> Result<(), OcppError> {
+        if let (Some(start_time), Some(stop_time)) = (self.start_time, self.stop_time) {
+            if start_time > stop_time {
+                return Err(OcppError::PropertyConstraintViolation(String::from(
+                    "startTime must not be after stopTime",
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// GetDiagnostics.conf PDU sent by the Charge Point to the
+/// Central System in response to a GetDiagnostics.req PDU.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDiagnosticsConf {
+    /// This contains the name of the file with diagnostic
+    /// information that will be uploaded. This field is not
+    /// present when no diagnostic information is available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file_name: Option<CiString255Type>,
+}
+
+impl GetDiagnosticsConf {
+    /// Without a file name, no diagnostics information is available.
+    pub fn builder() -> GetDiagnosticsConfBuilder {
+        GetDiagnosticsConfBuilder {
+            inner: GetDiagnosticsConf { file_name: None },
+        }
+    }
+
+    pub fn file_name(&self) -> Option<&CiString255Type> {
+        self.file_name.as_ref()
+    }
+}
+
+/// Builder for GetDiagnosticsConf, created by [`GetDiagnosticsConf::builder`].
+#[derive(Debug, Clone)]
+pub struct GetDiagnosticsConfBuilder {
+    inner: GetDiagnosticsConf,
+}
+
+impl GetDiagnosticsConfBuilder {
+    pub fn file_name(mut self, file_name: CiString255Type) -> GetDiagnosticsConfBuilder {
+        self.inner.file_name = Some(file_name);
+        self
+    }
+
+    /// Finishes the GetDiagnosticsConf, checking its constraints.
+    pub fn build(self) -> Result<GetDiagnosticsConf, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for GetDiagnosticsConf {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_get_diagnostics_req_round_trip() {
+        let payload = json!({
+            "location": "ftp://diagnostics.example.com/uploads/",
+            "retries": 3,
+            "retryInterval": 60,
+            "startTime": "2013-02-01T00:00:00Z",
+            "stopTime": "2013-02-02T00:00:00Z"
+        });
+
+        let req: GetDiagnosticsReq = serde_json::from_value(payload.clone()).unwrap();
+
+        assert!(req.validate().is_ok());
+        assert_eq!(serde_json::to_value(&req).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_get_diagnostics_req_with_inverted_interval() {
+        let payload = json!({
+            "location": "ftp://diagnostics.example.com/uploads/",
+            "startTime": "2013-02-02T00:00:00Z",
+            "stopTime": "2013-02-01T00:00:00Z"
+        });
+
+        let req: GetDiagnosticsReq = serde_json::from_value(payload).unwrap();
+
+        assert!(matches!(
+            req.validate(),
+            Err(OcppError::PropertyConstraintViolation(_))
+        ));
+    }
+}
@@ -1,3 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use crate::messages::Validate;
+
+/// GetLocalListVersion.req PDU sent by the Central System to the
+/// Charge Point.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct GetLocalListVersionReq {}
 
-pub struct GetLocalListVersionConf {}
+impl GetLocalListVersionReq {
+    pub fn new() -> GetLocalListVersionReq {
+        GetLocalListVersionReq {}
+    }
+}
+
+impl Validate for GetLocalListVersionReq {}
+
+/// GetLocalListVersion.conf PDU sent by the Charge Point to the
+/// Central System in response to a GetLocalListVersion.req PDU.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetLocalListVersionConf {
+    /// This contains the current version number of the local
+    /// authorization list in the Charge Point. A version number
+    /// of 0 (zero) is used to indicate that the local list is
+    /// empty, and -1 that the Charge Point does not support
+    /// Local Authorization Lists.
+    list_version: isize,
+}
+
+impl GetLocalListVersionConf {
+    pub fn new(list_version: isize) -> GetLocalListVersionConf {
+        GetLocalListVersionConf { list_version }
+    }
+
+    pub fn list_version(&self) -> isize {
+        self.list_version
+    }
+}
+
+impl Validate for GetLocalListVersionConf {}
@@ -1,3 +1,38 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::messages::Validate;
+
+/// Heartbeat.req PDU sent by the Charge Point to the
+/// Central System.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct HeartbeatReq {}
 
-pub struct HeartbeatConf {}
+impl HeartbeatReq {
+    pub fn new() -> HeartbeatReq {
+        HeartbeatReq {}
+    }
+}
+
+impl Validate for HeartbeatReq {}
+
+/// Heartbeat.conf PDU sent by the Central System to the
+/// Charge Point in response to a Heartbeat.req PDU.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeartbeatConf {
+    /// This contains the current time of the Central System.
+    current_time: DateTime<Utc>,
+}
+
+impl HeartbeatConf {
+    pub fn new(current_time: DateTime<Utc>) -> HeartbeatConf {
+        HeartbeatConf { current_time }
+    }
+
+    pub fn current_time(&self) -> DateTime<Utc> {
+        self.current_time
+    }
+}
+
+impl Validate for HeartbeatConf {}
@@ -1,3 +1,162 @@
-pub struct MeterValuesReq {}
+use serde::{Deserialize, Serialize};
 
+use crate::error::OcppError;
+use crate::messages::Validate;
+use crate::types::meter_values::MeterValues;
+
+/// MeterValues.req PDU sent by the Charge Point to the
+/// Central System.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MeterValuesReq {
+    /// This contains a number (>0) designating a connector of
+    /// the Charge Point. '0' (zero) is used to designate the
+    /// main powermeter.
+    connector_id: usize,
+    /// The transaction to which these meter samples are related.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transaction_id: Option<usize>,
+    /// The sampled meter values with timestamps.
+    meter_value: Vec<MeterValues>,
+}
+
+impl MeterValuesReq {
+    pub fn builder(connector_id: usize, meter_value: Vec<MeterValues>) -> MeterValuesReqBuilder {
+        MeterValuesReqBuilder {
+            inner: MeterValuesReq {
+                connector_id,
+                meter_value,
+                transaction_id: None,
+            },
+        }
+    }
+
+    pub fn connector_id(&self) -> usize {
+        self.connector_id
+    }
+
+    pub fn transaction_id(&self) -> Option<usize> {
+        self.transaction_id
+    }
+
+    pub fn meter_value(&self) -> &[MeterValues] {
+        &self.meter_value
+    }
+}
+
+/// Builder for MeterValuesReq, created by [`MeterValuesReq::builder`].
+#[derive(Debug, Clone)]
+pub struct MeterValuesReqBuilder {
+    inner: MeterValuesReq,
+}
+
+impl MeterValuesReqBuilder {
+    pub fn transaction_id(mut self, transaction_id: usize) -> MeterValuesReqBuilder {
+        self.inner.transaction_id = Some(transaction_id);
+        self
+    }
+
+    /// Finishes the MeterValuesReq, checking its constraints.
+    pub fn build(self) -> Result<MeterValuesReq, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for MeterValuesReq {
+    fn validate(&self) -> Result<(), OcppError> {
+        if self.meter_value.is_empty() {
+            return Err(OcppError::OccurenceConstraintViolation(String::from(
+                "meterValue must contain at least one element",
+            )));
+        }
+
+        for meter_value in &self.meter_value {
+            meter_value.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// MeterValues.conf PDU sent by the Central System to the
+/// Charge Point in response to a MeterValues.req PDU.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct MeterValuesConf {}
+
+impl MeterValuesConf {
+    pub fn new() -> MeterValuesConf {
+        MeterValuesConf {}
+    }
+}
+
+impl Validate for MeterValuesConf {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_meter_values_req_round_trip() {
+        let payload = json!({
+            "connectorId": 1,
+            "transactionId": 1764,
+            "meterValue": [
+                {
+                    "timestamp": "2013-02-01T16:09:18Z",
+                    "sampledValue": [
+                        {
+                            "value": "14.2",
+                            "context": "Sample.Periodic",
+                            "format": "Raw",
+                            "measurand": "Current.Import",
+                            "phase": "L1-N",
+                            "location": "Outlet",
+                            "unit": "A"
+                        }
+                    ]
+                }
+            ]
+        });
+
+        let req: MeterValuesReq = serde_json::from_value(payload.clone()).unwrap();
+
+        assert!(req.validate().is_ok());
+        assert_eq!(serde_json::to_value(&req).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_meter_values_req_without_meter_value() {
+        let payload = json!({ "connectorId": 0, "meterValue": [] });
+
+        let req: MeterValuesReq = serde_json::from_value(payload).unwrap();
+
+        assert!(matches!(
+            req.validate(),
+            Err(OcppError::OccurenceConstraintViolation(_))
+        ));
+    }
+
+    #[test]
+    fn test_meter_values_req_with_non_numeric_raw_value() {
+        let payload = json!({
+            "connectorId": 1,
+            "meterValue": [
+                {
+                    "timestamp": "2013-02-01T16:09:18Z",
+                    "sampledValue": [{ "value": "fourteen" }]
+                }
+            ]
+        });
+
+        let req: MeterValuesReq = serde_json::from_value(payload).unwrap();
+
+        assert!(matches!(
+            req.validate(),
+            Err(OcppError::PropertyConstraintViolation(_))
+        ));
+    }
+}
@@ -26,3 +26,16 @@ pub mod stop_transaction;
 pub mod trigger_message;
 pub mod unlock_connector;
 pub mod update_firmware;
+
+use crate::error::OcppError;
+
+/// Checks the constraints of a PDU that cannot be expressed by its
+/// field types alone, such as cross-field rules and value ranges.
+///
+/// Decoding a frame runs this after deserialization, so that an
+/// invalid PDU is answered with the appropriate CALLERROR.
+pub trait Validate {
+    fn validate(&self) -> Result<(), OcppError> {
+        Ok(())
+    }
+}
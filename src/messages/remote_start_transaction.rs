@@ -1,3 +1,226 @@
-pub struct RemoteStartTransactionReq {}
+use serde::{Deserialize, Serialize};
 
-pub struct RemoteStartTransactionConf {}
+use crate::error::OcppError;
+use crate::messages::Validate;
+use crate::types::authorization::IdToken;
+use crate::types::charging_profile::ChargingProfile;
+use crate::types::enumerations::{ChargingProfilePurposeType, RemoteStartStopStatus};
+
+/// RemoteStartTransaction.req PDU sent to Charge Point by
+/// Central System.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteStartTransactionReq {
+    /// Number of the connector on which to start the transaction.
+    /// connectorId SHALL be > 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    connector_id: Option<usize>,
+    /// The identifier that Charge Point must use to start
+    /// a transaction.
+    id_tag: IdToken,
+    /// Charging Profile to be used by the Charge Point for the
+    /// requested transaction. ChargingProfilePurpose MUST be set
+    /// to TxProfile and the transactionId SHALL NOT be set, as the
+    /// transaction does not exist yet.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    charging_profile: Option<ChargingProfile>,
+}
+
+impl RemoteStartTransactionReq {
+    pub fn builder(id_tag: IdToken) -> RemoteStartTransactionReqBuilder {
+        RemoteStartTransactionReqBuilder {
+            inner: RemoteStartTransactionReq {
+                id_tag,
+                connector_id: None,
+                charging_profile: None,
+            },
+        }
+    }
+
+    pub fn connector_id(&self) -> Option<usize> {
+        self.connector_id
+    }
+
+    pub fn id_tag(&self) -> &IdToken {
+        &self.id_tag
+    }
+
+    pub fn charging_profile(&self) -> Option<&ChargingProfile> {
+        self.charging_profile.as_ref()
+    }
+}
+
+/// Builder for RemoteStartTransactionReq, created by [`RemoteStartTransactionReq::builder`].
+#[derive(Debug, Clone)]
+pub struct RemoteStartTransactionReqBuilder {
+    inner: RemoteStartTransactionReq,
+}
+
+impl RemoteStartTransactionReqBuilder {
+    pub fn connector_id(mut self, connector_id: usize) -> RemoteStartTransactionReqBuilder {
+        self.inner.connector_id = Some(connector_id);
+        self
+    }
+
+    pub fn charging_profile(
+        mut self,
+        charging_profile: ChargingProfile,
+    ) -> RemoteStartTransactionReqBuilder {
+        self.inner.charging_profile = Some(charging_profile);
+        self
+    }
+
+    /// Finishes the RemoteStartTransactionReq, checking its constraints.
+    pub fn build(self) -> Result<RemoteStartTransactionReq, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for RemoteStartTransactionReq {
+    fn validate(&self) -> Result<(), OcppError> {
+        if self.connector_id == Some(0) {
+            return Err(OcppError::PropertyConstraintViolation(String::from(
+                "connectorId of a transaction must be greater than 0",
+            )));
+        }
+
+        if let Some(charging_profile) = &self.charging_profile {
+            if charging_profile.charging_profile_purpose() != ChargingProfilePurposeType::TxProfile
+            {
+                return Err(OcppError::PropertyConstraintViolation(String::from(
+                    "chargingProfilePurpose of a remote start must be TxProfile",
+                )));
+            }
+
+            if charging_profile.transaction_id().is_some() {
+                return Err(OcppError::OccurenceConstraintViolation(String::from(
+                    "transactionId must not be set in the chargingProfile of a remote start",
+                )));
+            }
+
+            charging_profile.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// RemoteStartTransaction.conf PDU sent from Charge Point to
+/// Central System in response to a RemoteStartTransaction.req PDU.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteStartTransactionConf {
+    /// Status indicating whether Charge Point accepts
+    /// the request to start a transaction.
+    status: RemoteStartStopStatus,
+}
+
+impl RemoteStartTransactionConf {
+    pub fn new(status: RemoteStartStopStatus) -> RemoteStartTransactionConf {
+        RemoteStartTransactionConf { status }
+    }
+
+    pub fn status(&self) -> RemoteStartStopStatus {
+        self.status
+    }
+}
+
+impl Validate for RemoteStartTransactionConf {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn charging_profile(purpose: &str) -> Value {
+        json!({
+            "chargingProfileId": 1,
+            "stackLevel": 0,
+            "chargingProfilePurpose": purpose,
+            "chargingProfileKind": "Relative",
+            "chargingSchedule": {
+                "chargingRateUnit": "A",
+                "chargingSchedulePeriod": [{ "startPeriod": 0, "limit": 16.0 }]
+            }
+        })
+    }
+
+    fn validate(payload: Value) -> Result<(), OcppError> {
+        serde_json::from_value::<RemoteStartTransactionReq>(payload)
+            .unwrap()
+            .validate()
+    }
+
+    #[test]
+    fn test_remote_start_transaction_req_round_trip() {
+        let payload = json!({
+            "connectorId": 2,
+            "idTag": "B4A63CDF",
+            "chargingProfile": charging_profile("TxProfile")
+        });
+
+        let req: RemoteStartTransactionReq = serde_json::from_value(payload.clone()).unwrap();
+
+        assert!(req.validate().is_ok());
+        assert_eq!(serde_json::to_value(&req).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_remote_start_transaction_req_builder_checks_constraints() {
+        let id_tag = IdToken::try_from("B4A63CDF").unwrap();
+
+        let valid = RemoteStartTransactionReq::builder(id_tag.clone())
+            .connector_id(1)
+            .build();
+        let invalid = RemoteStartTransactionReq::builder(id_tag)
+            .connector_id(0)
+            .build();
+
+        assert_eq!(valid.unwrap().connector_id(), Some(1));
+        assert!(matches!(
+            invalid,
+            Err(OcppError::PropertyConstraintViolation(_))
+        ));
+    }
+
+    #[test]
+    fn test_remote_start_transaction_req_without_connector() {
+        assert!(validate(json!({ "idTag": "B4A63CDF" })).is_ok());
+    }
+
+    #[test]
+    fn test_remote_start_transaction_req_on_connector_zero() {
+        assert!(matches!(
+            validate(json!({ "connectorId": 0, "idTag": "B4A63CDF" })),
+            Err(OcppError::PropertyConstraintViolation(_))
+        ));
+    }
+
+    #[test]
+    fn test_remote_start_transaction_req_with_default_profile() {
+        let payload = json!({
+            "idTag": "B4A63CDF",
+            "chargingProfile": charging_profile("TxDefaultProfile")
+        });
+
+        assert!(matches!(
+            validate(payload),
+            Err(OcppError::PropertyConstraintViolation(_))
+        ));
+    }
+
+    #[test]
+    fn test_remote_start_transaction_req_with_transaction_id() {
+        let mut profile = charging_profile("TxProfile");
+        profile["transactionId"] = json!(1764);
+        let payload = json!({ "idTag": "B4A63CDF", "chargingProfile": profile });
+
+        assert!(matches!(
+            validate(payload),
+            Err(OcppError::OccurenceConstraintViolation(_))
+        ));
+    }
+}
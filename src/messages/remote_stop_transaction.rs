@@ -1,3 +1,48 @@
-pub struct RemoteStopTransactionReq {}
+use serde::{Deserialize, Serialize};
 
-pub struct RemoteStopTransactionConf {}
+use crate::messages::Validate;
+use crate::types::enumerations::RemoteStartStopStatus;
+
+/// RemoteStopTransaction.req PDU sent to Charge Point by
+/// Central System.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteStopTransactionReq {
+    /// The identifier of the transaction which Charge Point
+    /// is requested to stop.
+    transaction_id: usize,
+}
+
+impl RemoteStopTransactionReq {
+    pub fn new(transaction_id: usize) -> RemoteStopTransactionReq {
+        RemoteStopTransactionReq { transaction_id }
+    }
+
+    pub fn transaction_id(&self) -> usize {
+        self.transaction_id
+    }
+}
+
+impl Validate for RemoteStopTransactionReq {}
+
+/// RemoteStopTransaction.conf PDU sent from Charge Point to
+/// Central System in response to a RemoteStopTransaction.req PDU.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteStopTransactionConf {
+    /// Status indicating whether Charge Point accepts
+    /// the request to stop a transaction.
+    status: RemoteStartStopStatus,
+}
+
+impl RemoteStopTransactionConf {
+    pub fn new(status: RemoteStartStopStatus) -> RemoteStopTransactionConf {
+        RemoteStopTransactionConf { status }
+    }
+
+    pub fn status(&self) -> RemoteStartStopStatus {
+        self.status
+    }
+}
+
+impl Validate for RemoteStopTransactionConf {}
@@ -1,3 +1,111 @@
-pub struct ReserveNowReq {}
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
-pub struct ReserveNowConf {}
+use crate::error::OcppError;
+use crate::messages::Validate;
+use crate::types::authorization::IdToken;
+use crate::types::enumerations::ReservationStatus;
+
+/// ReserveNow.req PDU sent by the Central System to the
+/// Charge Point.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReserveNowReq {
+    /// This contains the id of the connector to be reserved.
+    /// A value of 0 means that the reservation is not for a
+    /// specific connector.
+    connector_id: usize,
+    /// This contains the date and time when the reservation ends.
+    expiry_date: DateTime<Utc>,
+    /// The identifier for which the Charge Point has to
+    /// reserve a connector.
+    id_tag: IdToken,
+    /// The parent idTag.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_id_tag: Option<IdToken>,
+    /// Unique id for this reservation.
+    reservation_id: usize,
+}
+
+impl ReserveNowReq {
+    pub fn builder(
+        connector_id: usize,
+        expiry_date: DateTime<Utc>,
+        id_tag: IdToken,
+        reservation_id: usize,
+    ) -> ReserveNowReqBuilder {
+        ReserveNowReqBuilder {
+            inner: ReserveNowReq {
+                connector_id,
+                expiry_date,
+                id_tag,
+                reservation_id,
+                parent_id_tag: None,
+            },
+        }
+    }
+
+    pub fn connector_id(&self) -> usize {
+        self.connector_id
+    }
+
+    pub fn expiry_date(&self) -> DateTime<Utc> {
+        self.expiry_date
+    }
+
+    pub fn id_tag(&self) -> &IdToken {
+        &self.id_tag
+    }
+
+    pub fn parent_id_tag(&self) -> Option<&IdToken> {
+        self.parent_id_tag.as_ref()
+    }
+
+    pub fn reservation_id(&self) -> usize {
+        self.reservation_id
+    }
+}
+
+/// Builder for ReserveNowReq, created by [`ReserveNowReq::builder`].
+#[derive(Debug, Clone)]
+pub struct ReserveNowReqBuilder {
+    inner: ReserveNowReq,
+}
+
+impl ReserveNowReqBuilder {
+    pub fn parent_id_tag(mut self, parent_id_tag: IdToken) -> ReserveNowReqBuilder {
+        self.inner.parent_id_tag = Some(parent_id_tag);
+        self
+    }
+
+    /// Finishes the ReserveNowReq, checking its constraints.
+    pub fn build(self) -> Result<ReserveNowReq, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for ReserveNowReq {}
+
+/// ReserveNow.conf PDU sent by the Charge Point to the
+/// Central System in response to a ReserveNow.req PDU.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReserveNowConf {
+    /// This indicates the success or failure of the
+    /// reservation.
+    status: ReservationStatus,
+}
+
+impl ReserveNowConf {
+    pub fn new(status: ReservationStatus) -> ReserveNowConf {
+        ReserveNowConf { status }
+    }
+
+    pub fn status(&self) -> ReservationStatus {
+        self.status
+    }
+}
+
+impl Validate for ReserveNowConf {}
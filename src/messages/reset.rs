@@ -1,3 +1,64 @@
-pub struct ResetReq {}
+use serde::{Deserialize, Serialize};
 
-pub struct ResetConf {}
+use crate::messages::Validate;
+use crate::types::enumerations::{ResetStatus, ResetType};
+
+/// Reset.req PDU sent by the Central System to the Charge Point.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResetReq {
+    /// This contains the type of reset that the Charge Point
+    /// should perform.
+    #[serde(rename = "type")]
+    reset_type: ResetType,
+}
+
+impl ResetReq {
+    pub fn new(reset_type: ResetType) -> ResetReq {
+        ResetReq { reset_type }
+    }
+
+    pub fn reset_type(&self) -> ResetType {
+        self.reset_type
+    }
+}
+
+impl Validate for ResetReq {}
+
+/// Reset.conf PDU sent by the Charge Point to the Central System
+/// in response to a Reset.req PDU.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResetConf {
+    /// This indicates whether the Charge Point is able to
+    /// perform the reset.
+    status: ResetStatus,
+}
+
+impl ResetConf {
+    pub fn new(status: ResetStatus) -> ResetConf {
+        ResetConf { status }
+    }
+
+    pub fn status(&self) -> ResetStatus {
+        self.status
+    }
+}
+
+impl Validate for ResetConf {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_reset_req_uses_type_field() {
+        let payload = json!({ "type": "Soft" });
+
+        let req: ResetReq = serde_json::from_value(payload.clone()).unwrap();
+
+        assert_eq!(serde_json::to_value(&req).unwrap(), payload);
+    }
+}
@@ -1,3 +1,163 @@
-pub struct SendLocalListReq {}
+use serde::{Deserialize, Serialize};
 
-pub struct SendLocalListConf {}
+use crate::error::OcppError;
+use crate::messages::Validate;
+use crate::types::authorization::AuthorizationData;
+use crate::types::enumerations::{UpdateStatus, UpdateType};
+
+/// SendLocalList.req PDU sent by the Central System to the
+/// Charge Point.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendLocalListReq {
+    /// In case of a full update this is the version number of
+    /// the full list. In case of a differential update it is the
+    /// version number of the list after the update has been applied.
+    list_version: usize,
+    /// In case of a full update this contains the list of values
+    /// that form the new local authorization list. In case of a
+    /// differential update it contains the changes to be applied
+    /// to the local authorization list in the Charge Point.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    local_authorization_list: Vec<AuthorizationData>,
+    /// This contains the type of update (full or differential)
+    /// of this request.
+    update_type: UpdateType,
+}
+
+impl SendLocalListReq {
+    pub fn builder(list_version: usize, update_type: UpdateType) -> SendLocalListReqBuilder {
+        SendLocalListReqBuilder {
+            inner: SendLocalListReq {
+                list_version,
+                update_type,
+                local_authorization_list: Vec::new(),
+            },
+        }
+    }
+
+    pub fn list_version(&self) -> usize {
+        self.list_version
+    }
+
+    pub fn local_authorization_list(&self) -> &[AuthorizationData] {
+        &self.local_authorization_list
+    }
+
+    pub fn update_type(&self) -> UpdateType {
+        self.update_type
+    }
+}
+
+/// Builder for SendLocalListReq, created by [`SendLocalListReq::builder`].
+#[derive(Debug, Clone)]
+pub struct SendLocalListReqBuilder {
+    inner: SendLocalListReq,
+}
+
+impl SendLocalListReqBuilder {
+    pub fn local_authorization_list(
+        mut self,
+        local_authorization_list: Vec<AuthorizationData>,
+    ) -> SendLocalListReqBuilder {
+        self.inner.local_authorization_list = local_authorization_list;
+        self
+    }
+
+    /// Finishes the SendLocalListReq, checking its constraints.
+    pub fn build(self) -> Result<SendLocalListReq, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for SendLocalListReq {
+    fn validate(&self) -> Result<(), OcppError> {
+        if self.update_type == UpdateType::Full
+            && self
+                .local_authorization_list
+                .iter()
+                .any(|data| data.id_tag_info().is_none())
+        {
+            return Err(OcppError::OccurenceConstraintViolation(String::from(
+                "idTagInfo is required for every entry of a full update",
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// SendLocalList.conf PDU sent by the Charge Point to the
+/// Central System in response to a SendLocalList.req PDU.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendLocalListConf {
+    /// This indicates whether the Charge Point has successfully
+    /// received and applied the update of the local authorization
+    /// list.
+    status: UpdateStatus,
+}
+
+impl SendLocalListConf {
+    pub fn new(status: UpdateStatus) -> SendLocalListConf {
+        SendLocalListConf { status }
+    }
+
+    pub fn status(&self) -> UpdateStatus {
+        self.status
+    }
+}
+
+impl Validate for SendLocalListConf {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_send_local_list_req_round_trip() {
+        let payload = json!({
+            "listVersion": 5,
+            "localAuthorizationList": [
+                { "idTag": "B4A63CDF", "idTagInfo": { "status": "Accepted" } },
+                { "idTag": "C3B52DEF" }
+            ],
+            "updateType": "Differential"
+        });
+
+        let req: SendLocalListReq = serde_json::from_value(payload.clone()).unwrap();
+
+        assert!(req.validate().is_ok());
+        assert_eq!(serde_json::to_value(&req).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_send_local_list_req_without_list() {
+        let payload = json!({ "listVersion": 1, "updateType": "Full" });
+
+        let req: SendLocalListReq = serde_json::from_value(payload.clone()).unwrap();
+
+        assert!(req.validate().is_ok());
+        assert_eq!(serde_json::to_value(&req).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_send_local_list_req_full_update_without_id_tag_info() {
+        let payload = json!({
+            "listVersion": 1,
+            "localAuthorizationList": [{ "idTag": "C3B52DEF" }],
+            "updateType": "Full"
+        });
+
+        let req: SendLocalListReq = serde_json::from_value(payload).unwrap();
+
+        assert!(matches!(
+            req.validate(),
+            Err(OcppError::OccurenceConstraintViolation(_))
+        ));
+    }
+}
@@ -1,3 +1,162 @@
-pub struct SetChargingProfileReq {}
+use serde::{Deserialize, Serialize};
 
-pub struct SetChargingProfileConf {}
+use crate::error::OcppError;
+use crate::messages::Validate;
+use crate::types::charging_profile::ChargingProfile;
+use crate::types::enumerations::ChargingProfileStatus;
+
+/// SetChargingProfile.req PDU sent by the Central System to the
+/// Charge Point.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetChargingProfileReq {
+    /// The connector to which the charging profile applies.
+    /// If connectorId = 0, the message contains an overall
+    /// limit for the Charge Point.
+    connector_id: usize,
+    /// The charging profile to be set at the Charge Point.
+    cs_charging_profiles: ChargingProfile,
+}
+
+impl SetChargingProfileReq {
+    /// Builds the PDU, checking its constraints.
+    pub fn new(
+        connector_id: usize,
+        cs_charging_profiles: ChargingProfile,
+    ) -> Result<SetChargingProfileReq, OcppError> {
+        let pdu = SetChargingProfileReq {
+            connector_id,
+            cs_charging_profiles,
+        };
+        pdu.validate()?;
+
+        Ok(pdu)
+    }
+
+    pub fn connector_id(&self) -> usize {
+        self.connector_id
+    }
+
+    pub fn cs_charging_profiles(&self) -> &ChargingProfile {
+        &self.cs_charging_profiles
+    }
+}
+
+impl Validate for SetChargingProfileReq {
+    fn validate(&self) -> Result<(), OcppError> {
+        self.cs_charging_profiles.validate()
+    }
+}
+
+/// SetChargingProfile.conf PDU sent by the Charge Point to the
+/// Central System in response to a SetChargingProfile.req PDU.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetChargingProfileConf {
+    /// Returns whether the Charge Point has been able to
+    /// process the message successfully. This does not
+    /// guarantee the schedule will be followed to the letter.
+    status: ChargingProfileStatus,
+}
+
+impl SetChargingProfileConf {
+    pub fn new(status: ChargingProfileStatus) -> SetChargingProfileConf {
+        SetChargingProfileConf { status }
+    }
+
+    pub fn status(&self) -> ChargingProfileStatus {
+        self.status
+    }
+}
+
+impl Validate for SetChargingProfileConf {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_set_charging_profile_req_round_trip() {
+        let payload = json!({
+            "connectorId": 0,
+            "csChargingProfiles": {
+                "chargingProfileId": 100,
+                "stackLevel": 0,
+                "chargingProfilePurpose": "ChargePointMaxProfile",
+                "chargingProfileKind": "Recurring",
+                "recurrencyKind": "Daily",
+                "validFrom": "2013-01-01T00:00:00Z",
+                "validTo": "2014-01-01T00:00:00Z",
+                "chargingSchedule": {
+                    "duration": 86400,
+                    "startSchedule": "2013-01-01T00:00:00Z",
+                    "chargingRateUnit": "W",
+                    "chargingSchedulePeriod": [
+                        { "startPeriod": 0, "limit": 11000.0 },
+                        { "startPeriod": 28800, "limit": 6000.0 },
+                        { "startPeriod": 72000, "limit": 11000.0 }
+                    ]
+                }
+            }
+        });
+
+        let req: SetChargingProfileReq = serde_json::from_value(payload.clone()).unwrap();
+
+        assert!(req.validate().is_ok());
+        assert_eq!(serde_json::to_value(&req).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_set_charging_profile_req_with_unordered_periods() {
+        let payload = json!({
+            "connectorId": 1,
+            "csChargingProfiles": {
+                "chargingProfileId": 101,
+                "stackLevel": 1,
+                "chargingProfilePurpose": "TxDefaultProfile",
+                "chargingProfileKind": "Relative",
+                "chargingSchedule": {
+                    "chargingRateUnit": "A",
+                    "chargingSchedulePeriod": [
+                        { "startPeriod": 0, "limit": 16.0 },
+                        { "startPeriod": 3600, "limit": 32.0 },
+                        { "startPeriod": 1800, "limit": 8.0 }
+                    ]
+                }
+            }
+        });
+
+        let req: SetChargingProfileReq = serde_json::from_value(payload).unwrap();
+
+        assert!(matches!(
+            req.validate(),
+            Err(OcppError::PropertyConstraintViolation(_))
+        ));
+    }
+
+    #[test]
+    fn test_set_charging_profile_req_recurring_without_recurrency_kind() {
+        let payload = json!({
+            "connectorId": 0,
+            "csChargingProfiles": {
+                "chargingProfileId": 102,
+                "stackLevel": 0,
+                "chargingProfilePurpose": "ChargePointMaxProfile",
+                "chargingProfileKind": "Recurring",
+                "chargingSchedule": {
+                    "chargingRateUnit": "W",
+                    "chargingSchedulePeriod": [{ "startPeriod": 0, "limit": 11000.0 }]
+                }
+            }
+        });
+
+        let req: SetChargingProfileReq = serde_json::from_value(payload).unwrap();
+
+        assert!(matches!(
+            req.validate(),
+            Err(OcppError::OccurenceConstraintViolation(_))
+        ));
+    }
+}
@@ -1,3 +1,188 @@
-pub struct StartTransactionReq {}
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
-pub struct StartTransactionConf {}
+use crate::error::OcppError;
+use crate::messages::Validate;
+use crate::types::authorization::{IdTagInfo, IdToken};
+
+/// StartTransaction.req PDU sent by the Charge Point to the
+/// Central System.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartTransactionReq {
+    /// This identifies which connector of the Charge Point
+    /// is used. Connector ids start at 1.
+    connector_id: usize,
+    /// This contains the identifier for which a transaction
+    /// has to be started.
+    id_tag: IdToken,
+    /// This contains the meter value in Wh for the connector
+    /// at start of the transaction.
+    meter_start: usize,
+    /// This contains the id of the reservation that terminates
+    /// as a result of this transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reservation_id: Option<usize>,
+    /// This contains the date and time on which the transaction
+    /// is started.
+    timestamp: DateTime<Utc>,
+}
+
+impl StartTransactionReq {
+    pub fn builder(
+        connector_id: usize,
+        id_tag: IdToken,
+        meter_start: usize,
+        timestamp: DateTime<Utc>,
+    ) -> StartTransactionReqBuilder {
+        StartTransactionReqBuilder {
+            inner: StartTransactionReq {
+                connector_id,
+                id_tag,
+                meter_start,
+                timestamp,
+                reservation_id: None,
+            },
+        }
+    }
+
+    pub fn connector_id(&self) -> usize {
+        self.connector_id
+    }
+
+    pub fn id_tag(&self) -> &IdToken {
+        &self.id_tag
+    }
+
+    pub fn meter_start(&self) -> usize {
+        self.meter_start
+    }
+
+    pub fn reservation_id(&self) -> Option<usize> {
+        self.reservation_id
+    }
+
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+}
+
+/// Builder for StartTransactionReq, created by [`StartTransactionReq::builder`].
+#[derive(Debug, Clone)]
+pub struct StartTransactionReqBuilder {
+    inner: StartTransactionReq,
+}
+
+impl StartTransactionReqBuilder {
+    pub fn reservation_id(mut self, reservation_id: usize) -> StartTransactionReqBuilder {
+        self.inner.reservation_id = Some(reservation_id);
+        self
+    }
+
+    /// Finishes the StartTransactionReq, checking its constraints.
+    pub fn build(self) -> Result<StartTransactionReq, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for StartTransactionReq {
+    fn validate(&self) -> Result<(), OcppError> {
+        if self.connector_id == 0 {
+            return Err(OcppError::PropertyConstraintViolation(String::from(
+                "connectorId of a transaction must be greater than 0",
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// StartTransaction.conf PDU sent by the Central System to the
+/// Charge Point in response to a StartTransaction.req PDU.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartTransactionConf {
+    /// This contains information about authorization status,
+    /// expiry and parent id.
+    id_tag_info: IdTagInfo,
+    /// This contains the transaction id supplied by the
+    /// Central System.
+    transaction_id: usize,
+}
+
+impl StartTransactionConf {
+    pub fn new(id_tag_info: IdTagInfo, transaction_id: usize) -> StartTransactionConf {
+        StartTransactionConf {
+            id_tag_info,
+            transaction_id,
+        }
+    }
+
+    pub fn id_tag_info(&self) -> &IdTagInfo {
+        &self.id_tag_info
+    }
+
+    pub fn transaction_id(&self) -> usize {
+        self.transaction_id
+    }
+}
+
+impl Validate for StartTransactionConf {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_start_transaction_req_round_trip() {
+        let payload = json!({
+            "connectorId": 1,
+            "idTag": "B4A63CDF",
+            "meterStart": 1250,
+            "reservationId": 3,
+            "timestamp": "2013-02-01T15:09:18Z"
+        });
+
+        let req: StartTransactionReq = serde_json::from_value(payload.clone()).unwrap();
+
+        assert!(req.validate().is_ok());
+        assert_eq!(serde_json::to_value(&req).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_start_transaction_req_on_connector_zero() {
+        let payload = json!({
+            "connectorId": 0,
+            "idTag": "B4A63CDF",
+            "meterStart": 1250,
+            "timestamp": "2013-02-01T15:09:18Z"
+        });
+
+        let req: StartTransactionReq = serde_json::from_value(payload).unwrap();
+
+        assert!(matches!(
+            req.validate(),
+            Err(OcppError::PropertyConstraintViolation(_))
+        ));
+    }
+
+    #[test]
+    fn test_start_transaction_conf_round_trip() {
+        let payload = json!({
+            "idTagInfo": {
+                "expiryDate": "2013-02-01T16:09:18Z",
+                "parentIdTag": "PARENT",
+                "status": "Accepted"
+            },
+            "transactionId": 1764
+        });
+
+        let conf: StartTransactionConf = serde_json::from_value(payload.clone()).unwrap();
+
+        assert_eq!(serde_json::to_value(&conf).unwrap(), payload);
+    }
+}
@@ -1,3 +1,193 @@
-pub struct StatusNotificationReq {}
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
+use crate::error::OcppError;
+use crate::messages::Validate;
+use crate::types::enumerations::{ChargePointErrorCode, ChargePointStatus};
+use crate::types::utils::{CiString255Type, CiString50Type};
+
+/// StatusNotification.req PDU sent by the Charge Point to the
+/// Central System.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusNotificationReq {
+    /// The id of the connector for which the status is reported.
+    /// Id '0' (zero) is used if the status is for the Charge Point
+    /// main controller.
+    connector_id: usize,
+    /// This contains the error code reported by the Charge Point.
+    error_code: ChargePointErrorCode,
+    /// Additional free format information related to the error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    info: Option<CiString50Type>,
+    /// This contains the current status of the Charge Point.
+    status: ChargePointStatus,
+    /// The time for which the status is reported. If absent
+    /// time of receipt of the message will be assumed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timestamp: Option<DateTime<Utc>>,
+    /// This identifies the vendor-specific implementation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vendor_id: Option<CiString255Type>,
+    /// This contains the vendor-specific error code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vendor_error_code: Option<CiString50Type>,
+}
+
+impl StatusNotificationReq {
+    pub fn builder(
+        connector_id: usize,
+        error_code: ChargePointErrorCode,
+        status: ChargePointStatus,
+    ) -> StatusNotificationReqBuilder {
+        StatusNotificationReqBuilder {
+            inner: StatusNotificationReq {
+                connector_id,
+                error_code,
+                status,
+                info: None,
+                timestamp: None,
+                vendor_id: None,
+                vendor_error_code: None,
+            },
+        }
+    }
+
+    pub fn connector_id(&self) -> usize {
+        self.connector_id
+    }
+
+    pub fn error_code(&self) -> ChargePointErrorCode {
+        self.error_code
+    }
+
+    pub fn info(&self) -> Option<&CiString50Type> {
+        self.info.as_ref()
+    }
+
+    pub fn status(&self) -> ChargePointStatus {
+        self.status
+    }
+
+    pub fn timestamp(&self) -> Option<DateTime<Utc>> {
+        self.timestamp
+    }
+
+    pub fn vendor_id(&self) -> Option<&CiString255Type> {
+        self.vendor_id.as_ref()
+    }
+
+    pub fn vendor_error_code(&self) -> Option<&CiString50Type> {
+        self.vendor_error_code.as_ref()
+    }
+}
+
+/// Builder for StatusNotificationReq, created by [`StatusNotificationReq::builder`].
+#[derive(Debug, Clone)]
+pub struct StatusNotificationReqBuilder {
+    inner: StatusNotificationReq,
+}
+
+impl StatusNotificationReqBuilder {
+    pub fn info(mut self, info: CiString50Type) -> StatusNotificationReqBuilder {
+        self.inner.info = Some(info);
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: DateTime<Utc>) -> StatusNotificationReqBuilder {
+        self.inner.timestamp = Some(timestamp);
+        self
+    }
+
+    pub fn vendor_id(mut self, vendor_id: CiString255Type) -> StatusNotificationReqBuilder {
+        self.inner.vendor_id = Some(vendor_id);
+        self
+    }
+
+    pub fn vendor_error_code(
+        mut self,
+        vendor_error_code: CiString50Type,
+    ) -> StatusNotificationReqBuilder {
+        self.inner.vendor_error_code = Some(vendor_error_code);
+        self
+    }
+
+    /// Finishes the StatusNotificationReq, checking its constraints.
+    pub fn build(self) -> Result<StatusNotificationReq, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for StatusNotificationReq {
+    fn validate(&self) -> Result<(), OcppError> {
+        let controller_status = matches!(
+            self.status,
+            ChargePointStatus::Available
+                | ChargePointStatus::Unavailable
+                | ChargePointStatus::Faulted
+        );
+        if self.connector_id == 0 && !controller_status {
+            return Err(OcppError::PropertyConstraintViolation(format!(
+                "status {} cannot be reported for connectorId 0",
+                self.status
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// StatusNotification.conf PDU sent by the Central System to the
+/// Charge Point in response to a StatusNotification.req PDU.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct StatusNotificationConf {}
+
+impl StatusNotificationConf {
+    pub fn new() -> StatusNotificationConf {
+        StatusNotificationConf {}
+    }
+}
+
+impl Validate for StatusNotificationConf {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_status_notification_req_round_trip() {
+        let payload = json!({
+            "connectorId": 1,
+            "errorCode": "EVCommunicationError",
+            "info": "Pilot signal lost",
+            "status": "SuspendedEVSE",
+            "timestamp": "2013-02-01T15:09:18Z",
+            "vendorId": "com.vendorx",
+            "vendorErrorCode": "E042"
+        });
+
+        let req: StatusNotificationReq = serde_json::from_value(payload.clone()).unwrap();
+
+        assert!(req.validate().is_ok());
+        assert_eq!(serde_json::to_value(&req).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_status_notification_req_for_main_controller() {
+        let available = json!({ "connectorId": 0, "errorCode": "NoError", "status": "Available" });
+        let charging = json!({ "connectorId": 0, "errorCode": "NoError", "status": "Charging" });
+
+        let available: StatusNotificationReq = serde_json::from_value(available).unwrap();
+        let charging: StatusNotificationReq = serde_json::from_value(charging).unwrap();
+
+        assert!(available.validate().is_ok());
+        assert!(matches!(
+            charging.validate(),
+            Err(OcppError::PropertyConstraintViolation(_))
+        ));
+    }
+}
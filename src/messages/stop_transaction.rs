@@ -1,3 +1,242 @@
-pub struct StopTransactionReq {}
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
-pub struct StopTransactionConf {}
+use crate::error::OcppError;
+use crate::messages::Validate;
+use crate::types::authorization::{IdTagInfo, IdToken};
+use crate::types::enumerations::Reason;
+use crate::types::meter_values::MeterValues;
+
+/// StopTransaction.req PDU sent by the Charge Point to the
+/// Central System.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StopTransactionReq {
+    /// This contains the identifier which requested to stop
+    /// the charging. It is optional because a Charge Point may
+    /// terminate charging without the presence of an idTag,
+    /// e.g. in case of a reset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id_tag: Option<IdToken>,
+    /// This contains the meter value in Wh for the connector
+    /// at end of the transaction.
+    meter_stop: usize,
+    /// This contains the date and time on which the transaction
+    /// is stopped.
+    timestamp: DateTime<Utc>,
+    /// This contains the transaction-id as received by the
+    /// StartTransaction.conf.
+    transaction_id: usize,
+    /// This contains the reason why the transaction was stopped.
+    /// MAY only be omitted when the Reason is "Local".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<Reason>,
+    /// This contains transaction usage details relevant
+    /// for billing purposes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transaction_data: Option<Vec<MeterValues>>,
+}
+
+impl StopTransactionReq {
+    pub fn builder(
+        meter_stop: usize,
+        timestamp: DateTime<Utc>,
+        transaction_id: usize,
+    ) -> StopTransactionReqBuilder {
+        StopTransactionReqBuilder {
+            inner: StopTransactionReq {
+                meter_stop,
+                timestamp,
+                transaction_id,
+                id_tag: None,
+                reason: None,
+                transaction_data: None,
+            },
+        }
+    }
+
+    pub fn id_tag(&self) -> Option<&IdToken> {
+        self.id_tag.as_ref()
+    }
+
+    pub fn meter_stop(&self) -> usize {
+        self.meter_stop
+    }
+
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    pub fn transaction_id(&self) -> usize {
+        self.transaction_id
+    }
+
+    pub fn reason(&self) -> Option<Reason> {
+        self.reason
+    }
+
+    pub fn transaction_data(&self) -> Option<&[MeterValues]> {
+        self.transaction_data.as_deref()
+    }
+}
+
+/// Builder for StopTransactionReq, created by [`StopTransactionReq::builder`].
+#[derive(Debug, Clone)]
+pub struct StopTransactionReqBuilder {
+    inner: StopTransactionReq,
+}
+
+impl StopTransactionReqBuilder {
+    pub fn id_tag(mut self, id_tag: IdToken) -> StopTransactionReqBuilder {
+        self.inner.id_tag = Some(id_tag);
+        self
+    }
+
+    pub fn reason(mut self, reason: Reason) -> StopTransactionReqBuilder {
+        self.inner.reason = Some(reason);
+        self
+    }
+
+    pub fn transaction_data(
+        mut self,
+        transaction_data: Vec<MeterValues>,
+    ) -> StopTransactionReqBuilder {
+        self.inner.transaction_data = Some(transaction_data);
+        self
+    }
+
+    /// Finishes the StopTransactionReq, checking its constraints.
+    pub fn build(self) -> Result<StopTransactionReq, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for StopTransactionReq {
+    fn validate(&self) -> Result<(), OcppError> {
+        if let Some(transaction_data) = &self.transaction_data {
+            for meter_value in transaction_data {
+                meter_value.validate()?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// StopTransaction.conf PDU sent by the Central System to the
+/// Charge Point in response to a StopTransaction.req PDU.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StopTransactionConf {
+    /// This contains information about authorization status,
+    /// expiry and parent id. It is optional, because a transaction
+    /// may have been stopped without an identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id_tag_info: Option<IdTagInfo>,
+}
+
+impl StopTransactionConf {
+    /// The idTagInfo is only expected when the request carried an
+    /// idTag.
+    pub fn builder() -> StopTransactionConfBuilder {
+        StopTransactionConfBuilder {
+            inner: StopTransactionConf { id_tag_info: None },
+        }
+    }
+
+    pub fn id_tag_info(&self) -> Option<&IdTagInfo> {
+        self.id_tag_info.as_ref()
+    }
+}
+
+/// Builder for StopTransactionConf, created by [`StopTransactionConf::builder`].
+#[derive(Debug, Clone)]
+pub struct StopTransactionConfBuilder {
+    inner: StopTransactionConf,
+}
+
+impl StopTransactionConfBuilder {
+    pub fn id_tag_info(mut self, id_tag_info: IdTagInfo) -> StopTransactionConfBuilder {
+        self.inner.id_tag_info = Some(id_tag_info);
+        self
+    }
+
+    /// Finishes the StopTransactionConf, checking its constraints.
+    pub fn build(self) -> Result<StopTransactionConf, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for StopTransactionConf {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_stop_transaction_req_round_trip() {
+        let payload = json!({
+            "idTag": "B4A63CDF",
+            "meterStop": 20670,
+            "timestamp": "2013-02-01T17:09:18Z",
+            "transactionId": 1764,
+            "reason": "EVDisconnected",
+            "transactionData": [
+                {
+                    "timestamp": "2013-02-01T15:09:18Z",
+                    "sampledValue": [
+                        { "value": "1250", "context": "Transaction.Begin" }
+                    ]
+                },
+                {
+                    "timestamp": "2013-02-01T17:09:18Z",
+                    "sampledValue": [
+                        {
+                            "value": "20.670",
+                            "context": "Transaction.End",
+                            "measurand": "Energy.Active.Import.Register",
+                            "unit": "kWh"
+                        }
+                    ]
+                }
+            ]
+        });
+
+        let req: StopTransactionReq = serde_json::from_value(payload.clone()).unwrap();
+
+        assert!(req.validate().is_ok());
+        assert_eq!(serde_json::to_value(&req).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_stop_transaction_req_with_empty_meter_value() {
+        let payload = json!({
+            "meterStop": 20670,
+            "timestamp": "2013-02-01T17:09:18Z",
+            "transactionId": 1764,
+            "transactionData": [
+                { "timestamp": "2013-02-01T17:09:18Z", "sampledValue": [] }
+            ]
+        });
+
+        let req: StopTransactionReq = serde_json::from_value(payload).unwrap();
+
+        assert!(matches!(
+            req.validate(),
+            Err(OcppError::OccurenceConstraintViolation(_))
+        ));
+    }
+
+    #[test]
+    fn test_stop_transaction_conf_without_id_tag_info() {
+        let conf: StopTransactionConf = serde_json::from_value(json!({})).unwrap();
+
+        assert_eq!(serde_json::to_value(&conf).unwrap(), json!({}));
+    }
+}
@@ -1,3 +1,156 @@
-pub struct TriggerMessageReq {}
+use serde::{Deserialize, Serialize};
 
-pub struct TriggerMessageConf {}
+use crate::error::OcppError;
+use crate::messages::Validate;
+use crate::types::enumerations::{MessageTrigger, TriggerMessageStatus};
+
+/// TriggerMessage.req PDU sent by the Central System to the
+/// Charge Point.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerMessageReq {
+    /// Type of message to be triggered.
+    requested_message: MessageTrigger,
+    /// Only filled in when request applies to a specific
+    /// connector. connectorId SHALL be > 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    connector_id: Option<usize>,
+}
+
+impl TriggerMessageReq {
+    pub fn builder(requested_message: MessageTrigger) -> TriggerMessageReqBuilder {
+        TriggerMessageReqBuilder {
+            inner: TriggerMessageReq {
+                requested_message,
+                connector_id: None,
+            },
+        }
+    }
+
+    pub fn requested_message(&self) -> MessageTrigger {
+        self.requested_message
+    }
+
+    pub fn connector_id(&self) -> Option<usize> {
+        self.connector_id
+    }
+
+    /// Connector the triggered message must be sent for.
+    ///
+    /// The connectorId is only relevant when triggering MeterValues
+    /// or StatusNotification and is ignored otherwise. None means the
+    /// message must be sent for all connectors, or that the message
+    /// does not relate to a connector at all.
+    pub fn target_connector(&self) -> Option<usize> {
+        match self.requested_message {
+            MessageTrigger::MeterValues | MessageTrigger::StatusNotification => self.connector_id,
+            _ => None,
+        }
+    }
+}
+
+/// Builder for TriggerMessageReq, created by [`TriggerMessageReq::builder`].
+#[derive(Debug, Clone)]
+pub struct TriggerMessageReqBuilder {
+    inner: TriggerMessageReq,
+}
+
+impl TriggerMessageReqBuilder {
+    pub fn connector_id(mut self, connector_id: usize) -> TriggerMessageReqBuilder {
+        self.inner.connector_id = Some(connector_id);
+        self
+    }
+
+    /// Finishes the TriggerMessageReq, checking its constraints.
+    pub fn build(self) -> Result<TriggerMessageReq, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for TriggerMessageReq {
+    fn validate(&self) -> Result<(), OcppError> {
+        if self.connector_id == Some(0) {
+            return Err(OcppError::PropertyConstraintViolation(String::from(
+                "connectorId of a triggered message must be greater than 0",
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// TriggerMessage.conf PDU sent by the Charge Point to the
+/// Central System in response to a TriggerMessage.req PDU.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerMessageConf {
+    /// Indicates whether the Charge Point will send the
+    /// requested notification or not.
+    status: TriggerMessageStatus,
+}
+
+impl TriggerMessageConf {
+    pub fn new(status: TriggerMessageStatus) -> TriggerMessageConf {
+        TriggerMessageConf { status }
+    }
+
+    pub fn status(&self) -> TriggerMessageStatus {
+        self.status
+    }
+}
+
+impl Validate for TriggerMessageConf {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    fn trigger(requested_message: &str, connector_id: Option<usize>) -> TriggerMessageReq {
+        let mut payload = json!({ "requestedMessage": requested_message });
+        if let Some(connector_id) = connector_id {
+            payload["connectorId"] = json!(connector_id);
+        }
+
+        serde_json::from_value(payload).unwrap()
+    }
+
+    #[test]
+    fn test_trigger_message_req_round_trip() {
+        let payload = json!({ "requestedMessage": "StatusNotification", "connectorId": 1 });
+
+        let req: TriggerMessageReq = serde_json::from_value(payload.clone()).unwrap();
+
+        assert_eq!(serde_json::to_value(&req).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_target_connector_for_connector_related_messages() {
+        assert_eq!(trigger("MeterValues", Some(2)).target_connector(), Some(2));
+        assert_eq!(
+            trigger("StatusNotification", Some(1)).target_connector(),
+            Some(1)
+        );
+        assert_eq!(trigger("StatusNotification", None).target_connector(), None);
+    }
+
+    #[test]
+    fn test_target_connector_is_ignored_for_other_messages() {
+        assert_eq!(trigger("Heartbeat", Some(1)).target_connector(), None);
+        assert_eq!(
+            trigger("BootNotification", Some(1)).target_connector(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_trigger_message_req_on_connector_zero() {
+        assert!(matches!(
+            trigger("MeterValues", Some(0)).validate(),
+            Err(OcppError::PropertyConstraintViolation(_))
+        ));
+    }
+}
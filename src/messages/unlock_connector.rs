@@ -1,3 +1,78 @@
-pub struct UnlockConnectorReq {}
+use serde::{Deserialize, Serialize};
 
-pub struct UnlockConnectorConf {}
+use crate::error::OcppError;
+use crate::messages::Validate;
+use crate::types::enumerations::UnlockStatus;
+
+/// UnlockConnector.req PDU sent by the Central System to the
+/// Charge Point.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnlockConnectorReq {
+    /// This contains the identifier of the connector to be
+    /// unlocked. connectorId SHALL be > 0.
+    connector_id: usize,
+}
+
+impl UnlockConnectorReq {
+    /// Builds the PDU, checking its constraints.
+    pub fn new(connector_id: usize) -> Result<UnlockConnectorReq, OcppError> {
+        let pdu = UnlockConnectorReq { connector_id };
+        pdu.validate()?;
+
+        Ok(pdu)
+    }
+
+    pub fn connector_id(&self) -> usize {
+        self.connector_id
+    }
+}
+
+impl Validate for UnlockConnectorReq {
+    fn validate(&self) -> Result<(), OcppError> {
+        if self.connector_id == 0 {
+            return Err(OcppError::PropertyConstraintViolation(String::from(
+                "connectorId to unlock must be greater than 0",
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// UnlockConnector.conf PDU sent by the Charge Point to the
+/// Central System in response to an UnlockConnector.req PDU.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnlockConnectorConf {
+    /// This indicates whether the Charge Point has unlocked
+    /// the connector.
+    status: UnlockStatus,
+}
+
+impl UnlockConnectorConf {
+    pub fn new(status: UnlockStatus) -> UnlockConnectorConf {
+        UnlockConnectorConf { status }
+    }
+
+    pub fn status(&self) -> UnlockStatus {
+        self.status
+    }
+}
+
+impl Validate for UnlockConnectorConf {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_unlock_connector_req_new() {
+        assert_eq!(UnlockConnectorReq::new(1).unwrap().connector_id(), 1);
+        assert!(matches!(
+            UnlockConnectorReq::new(0),
+            Err(OcppError::PropertyConstraintViolation(_))
+        ));
+    }
+}
@@ -1,3 +1,98 @@
-pub struct UpdateFirmwareReq {}
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
+use crate::error::OcppError;
+use crate::messages::Validate;
+
+/// UpdateFirmware.req PDU sent by the Central System to the
+/// Charge Point.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateFirmwareReq {
+    /// This contains a string containing a URI pointing to a
+    /// location from which to retrieve the firmware.
+    location: String,
+    /// This specifies how many times Charge Point must try to
+    /// download the firmware before giving up. If this field is
+    /// not present, it is left to Charge Point to decide how many
+    /// times it wants to retry.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retries: Option<usize>,
+    /// This contains the date and time after which the Charge
+    /// Point is allowed to retrieve the (new) firmware.
+    retrieve_date: DateTime<Utc>,
+    /// The interval in seconds after which a retry may be
+    /// attempted. If this field is not present, it is left to
+    /// Charge Point to decide how long to wait between attempts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retry_interval: Option<usize>,
+}
+
+impl UpdateFirmwareReq {
+    pub fn builder(location: String, retrieve_date: DateTime<Utc>) -> UpdateFirmwareReqBuilder {
+        UpdateFirmwareReqBuilder {
+            inner: UpdateFirmwareReq {
+                location,
+                retrieve_date,
+                retries: None,
+                retry_interval: None,
+            },
+        }
+    }
+
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    pub fn retries(&self) -> Option<usize> {
+        self.retries
+    }
+
+    pub fn retrieve_date(&self) -> DateTime<Utc> {
+        self.retrieve_date
+    }
+
+    pub fn retry_interval(&self) -> Option<usize> {
+        self.retry_interval
+    }
+}
+
+/// Builder for UpdateFirmwareReq, created by [`UpdateFirmwareReq::builder`].
+#[derive(Debug, Clone)]
+pub struct UpdateFirmwareReqBuilder {
+    inner: UpdateFirmwareReq,
+}
+
+impl UpdateFirmwareReqBuilder {
+    pub fn retries(mut self, retries: usize) -> UpdateFirmwareReqBuilder {
+        self.inner.retries = Some(retries);
+        self
+    }
+
+    pub fn retry_interval(mut self, retry_interval: usize) -> UpdateFirmwareReqBuilder {
+        self.inner.retry_interval = Some(retry_interval);
+        self
+    }
+
+    /// Finishes the UpdateFirmwareReq, checking its constraints.
+    pub fn build(self) -> Result<UpdateFirmwareReq, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for UpdateFirmwareReq {}
+
+/// UpdateFirmware.conf PDU sent by the Charge Point to the
+/// Central System in response to a UpdateFirmware.req PDU.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct UpdateFirmwareConf {}
+
+impl UpdateFirmwareConf {
+    pub fn new() -> UpdateFirmwareConf {
+        UpdateFirmwareConf {}
+    }
+}
+
+impl Validate for UpdateFirmwareConf {}
@@ -0,0 +1,71 @@
+//! File helpers shared by the storages of the configuration and of the
+//! transaction queue.
+
+use std::fmt;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::error::OcppError;
+
+/// Error about the file at `path`.
+pub(crate) fn file_error(path: &Path, err: impl fmt::Display) -> OcppError {
+    OcppError::InternalError(format!("{}: {}", path.display(), err))
+}
+
+/// Path of the file written before replacing the one at `path`.
+pub(crate) fn temporary_path(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".tmp");
+    path.with_file_name(file_name)
+}
+
+/// Replaces the file at `path` with `contents`.
+///
+/// The contents are first written and synced to a temporary file next
+/// to `path`, which is then renamed over it, so that an interrupted
+/// write leaves the previous file intact.
+pub(crate) fn write_atomically(path: &Path, contents: &[u8]) -> Result<(), OcppError> {
+    let temporary_path = temporary_path(path);
+
+    let mut file = fs::File::create(&temporary_path).map_err(|err| file_error(path, err))?;
+    file.write_all(contents)
+        .and_then(|()| file.sync_all())
+        .map_err(|err| file_error(path, err))?;
+    fs::rename(&temporary_path, path).map_err(|err| file_error(path, err))
+}
+
+/// Path of `file_name` in an empty directory unique to `test`.
+#[cfg(test)]
+pub(crate) fn test_path(test: &str, file_name: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("open_ocpp-{}-{}", std::process::id(), test));
+    let _ = fs::remove_dir_all(&directory);
+    fs::create_dir_all(&directory).unwrap();
+    directory.join(file_name)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_write_atomically_replaces_file() {
+        let path = test_path("write_atomically", "data.json");
+        fs::write(&path, "old").unwrap();
+
+        write_atomically(&path, b"new").unwrap();
+
+        assert_eq!(fs::read(&path).unwrap(), b"new");
+        assert!(!temporary_path(&path).exists());
+    }
+
+    #[test]
+    fn test_write_atomically_in_missing_directory() {
+        let path = test_path("missing_directory", "missing/data.json");
+
+        let err = write_atomically(&path, b"new").unwrap_err();
+
+        assert!(err.description().starts_with(&path.display().to_string()));
+    }
+}
@@ -0,0 +1,278 @@
+//! Validation of payloads against the official OCPP 1.6 JSON schemas.
+//!
+//! Deserialization into the typed PDUs does not catch every constraint
+//! of the specification (additional properties, `multipleOf` on
+//! decimals, string formats), so CALL and CALLRESULT payloads are first
+//! checked against the schema of their action.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use jsonschema::error::ValidationErrorKind;
+use jsonschema::{ValidationError, Validator};
+use serde_json::Value;
+
+use crate::error::OcppError;
+
+macro_rules! schemas {
+    ($($action:ident),+ $(,)?) => {
+        /// Request and response schemas of every action.
+        const SCHEMAS: &[(&str, &str, &str)] = &[
+            $((
+                stringify!($action),
+                include_str!(concat!("json/", stringify!($action), ".json")),
+                include_str!(concat!("json/", stringify!($action), "Response.json")),
+            ),)+
+        ];
+    };
+}
+
+schemas! {
+    Authorize,
+    BootNotification,
+    CancelReservation,
+    ChangeAvailability,
+    ChangeConfiguration,
+    ClearCache,
+    ClearChargingProfile,
+    DataTransfer,
+    DiagnosticsStatusNotification,
+    FirmwareStatusNotification,
+    GetCompositeSchedule,
+    GetConfiguration,
+    GetDiagnostics,
+    GetLocalListVersion,
+    Heartbeat,
+    MeterValues,
+    RemoteStartTransaction,
+    RemoteStopTransaction,
+    ReserveNow,
+    Reset,
+    SendLocalList,
+    SetChargingProfile,
+    StartTransaction,
+    StatusNotification,
+    StopTransaction,
+    TriggerMessage,
+    UnlockConnector,
+    UpdateFirmware,
+}
+
+/// Compiled request and response validators of an action.
+struct ActionSchemas {
+    request: Validator,
+    response: Validator,
+}
+
+fn validators() -> &'static HashMap<&'static str, ActionSchemas> {
+    static VALIDATORS: OnceLock<HashMap<&'static str, ActionSchemas>> = OnceLock::new();
+
+    VALIDATORS.get_or_init(|| {
+        SCHEMAS
+            .iter()
+            .map(|(action, request, response)| {
+                let schemas = ActionSchemas {
+                    request: compile(request),
+                    response: compile(response),
+                };
+                (*action, schemas)
+            })
+            .collect()
+    })
+}
+
+fn compile(schema: &str) -> Validator {
+    // The schemas are embedded at compile time and covered by tests,
+    // so failing to compile one is a bug in the crate.
+    let schema: Value = serde_json::from_str(schema).expect("embedded schema is valid JSON");
+    jsonschema::draft4::options()
+        .should_validate_formats(true)
+        .build(&schema)
+        .expect("embedded schema is a valid draft-04 schema")
+}
+
+fn lookup(action: &str) -> Result<&'static ActionSchemas, OcppError> {
+    validators()
+        .get(action)
+        .ok_or_else(|| OcppError::NotImplemented(format!("unknown action {}", action)))
+}
+
+/// Checks the payload of a CALL against the request schema of `action`.
+pub fn validate_request(action: &str, payload: &Value) -> Result<(), OcppError> {
+    let schemas = lookup(action)?;
+    schemas
+        .request
+        .validate(payload)
+        .map_err(|err| to_ocpp_error(action, &err))
+}
+
+/// Checks the payload of a CALLRESULT against the response schema of
+/// `action`.
+pub fn validate_response(action: &str, payload: &Value) -> Result<(), OcppError> {
+    let schemas = lookup(action)?;
+    schemas
+        .response
+        .validate(payload)
+        .map_err(|err| to_ocpp_error(action, &err))
+}
+
+/// Classifies a schema violation into the matching CALLERROR code.
+fn to_ocpp_error(action: &str, err: &ValidationError<'_>) -> OcppError {
+    let path = err.instance_path().to_string();
+    let description = if path.is_empty() {
+        format!("{}: {}", action, err)
+    } else {
+        format!("{}: {} at {}", action, err, path)
+    };
+
+    match err.kind() {
+        ValidationErrorKind::AdditionalProperties { .. }
+        | ValidationErrorKind::AdditionalItems { .. } => OcppError::FormationViolation(description),
+        ValidationErrorKind::Required { .. }
+        | ValidationErrorKind::MinItems { .. }
+        | ValidationErrorKind::MaxItems { .. } => {
+            OcppError::OccurenceConstraintViolation(description)
+        }
+        // Date-time and URI formats and the single decimal of the
+        // numeric fields are part of the field data types. Too long
+        // strings are invalid values, as reported for the CiString types
+        // by `From<CiStringError> for OcppError`.
+        ValidationErrorKind::Type { .. }
+        | ValidationErrorKind::Format { .. }
+        | ValidationErrorKind::MultipleOf { .. } => OcppError::TypeConstraintViolation(description),
+        _ => OcppError::PropertyConstraintViolation(description),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::error::ErrorCode;
+    use serde_json::json;
+
+    #[test]
+    fn test_all_schemas_compile() {
+        assert_eq!(validators().len(), 28);
+    }
+
+    #[test]
+    fn test_valid_request() {
+        let payload = json!({
+            "chargePointVendor": "VendorX",
+            "chargePointModel": "SingleSocketCharger"
+        });
+
+        assert_eq!(validate_request("BootNotification", &payload), Ok(()));
+    }
+
+    #[test]
+    fn test_valid_response() {
+        let payload = json!({
+            "status": "Accepted",
+            "currentTime": "2013-02-01T20:53:32.486Z",
+            "interval": 300
+        });
+
+        assert_eq!(validate_response("BootNotification", &payload), Ok(()));
+    }
+
+    #[test]
+    fn test_unknown_action() {
+        let err = validate_request("Unknown", &json!({})).unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::NotImplemented);
+    }
+
+    #[test]
+    fn test_additional_property() {
+        let payload = json!({ "idTag": "ABCD-01234", "extra": true });
+
+        let err = validate_request("Authorize", &payload).unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::FormationViolation);
+    }
+
+    #[test]
+    fn test_missing_required_property() {
+        let err = validate_request("Authorize", &json!({})).unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::OccurenceConstraintViolation);
+    }
+
+    #[test]
+    fn test_wrong_type() {
+        let err = validate_request("Authorize", &json!({ "idTag": 42 })).unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::TypeConstraintViolation);
+        assert!(err.description().contains("/idTag"));
+    }
+
+    #[test]
+    fn test_too_long_string() {
+        let payload = json!({ "idTag": "a".repeat(21) });
+
+        let err = validate_request("Authorize", &payload).unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::PropertyConstraintViolation);
+    }
+
+    #[test]
+    fn test_invalid_date_time() {
+        let payload = json!({ "currentTime": "yesterday" });
+
+        let err = validate_response("Heartbeat", &payload).unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::TypeConstraintViolation);
+    }
+
+    #[test]
+    fn test_limit_with_more_than_one_decimal() {
+        let payload = json!({
+            "connectorId": 1,
+            "csChargingProfiles": {
+                "chargingProfileId": 1,
+                "stackLevel": 0,
+                "chargingProfilePurpose": "TxDefaultProfile",
+                "chargingProfileKind": "Absolute",
+                "chargingSchedule": {
+                    "chargingRateUnit": "A",
+                    "chargingSchedulePeriod": [
+                        { "startPeriod": 0, "limit": 16.25 }
+                    ]
+                }
+            }
+        });
+
+        let err = validate_request("SetChargingProfile", &payload).unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::TypeConstraintViolation);
+    }
+
+    #[test]
+    fn test_limit_with_one_decimal() {
+        let payload = json!({
+            "status": "Accepted",
+            "connectorId": 1,
+            "chargingSchedule": {
+                "chargingRateUnit": "W",
+                "chargingSchedulePeriod": [
+                    { "startPeriod": 0, "limit": 16.2 },
+                    { "startPeriod": 60, "limit": 7360.3 }
+                ],
+                "minChargingRate": 0.7
+            }
+        });
+
+        assert_eq!(validate_response("GetCompositeSchedule", &payload), Ok(()));
+    }
+
+    #[test]
+    fn test_invalid_enumeration_value() {
+        let payload = json!({ "connectorId": 0, "type": "Medium" });
+
+        let err = validate_request("ChangeAvailability", &payload).unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::PropertyConstraintViolation);
+    }
+}
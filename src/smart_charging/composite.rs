@@ -0,0 +1,868 @@
+//! Calculation of the composite schedule reported in a
+//! GetCompositeSchedule.conf PDU.
+//!
+//! The composite schedule of a connector is obtained by combining, at
+//! every instant:
+//!
+//! * the ChargePointMaxProfile installed on connector 0, which limits
+//!   the whole Charge Point;
+//! * the TxProfile of the connector or, when none is active, its
+//!   TxDefaultProfile. A TxDefaultProfile installed on the connector
+//!   overrides one installed on connector 0.
+//!
+//! Within each purpose the active profile with the highest stack level
+//! prevails, and the resulting limit is the minimum of the purposes.
+//!
+//! Limits expressed in another unit than the schedule are converted
+//! with the [`UnitConverter`] of the calculator, if any.
+
+use chrono::{DateTime, Duration, Utc};
+
+use super::units::{Supply, UnitConverter};
+use crate::error::OcppError;
+use crate::types::charging_profile::{
+    AbsolutePeriod, ChargingProfile, ChargingSchedule, ChargingSchedulePeriod,
+};
+use crate::types::enumerations::{ChargingProfilePurposeType, ChargingRateUnitType};
+
+/// Calculates composite schedules from the charging profiles installed
+/// on a Charge Point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompositeScheduleCalculator {
+    /// Unit of the schedule when the request does not force one.
+    charging_rate_unit: ChargingRateUnitType,
+    /// Limit of the Charge Point itself, expressed in
+    /// `charging_rate_unit`. Used when no profile restricts charging.
+    max_limit: f64,
+    /// Converts limits between Amperes and Watts.
+    unit_converter: Option<UnitConverter>,
+}
+
+impl CompositeScheduleCalculator {
+    pub fn new(charging_rate_unit: ChargingRateUnitType, max_limit: f64) -> Self {
+        CompositeScheduleCalculator {
+            charging_rate_unit,
+            max_limit,
+            unit_converter: None,
+        }
+    }
+
+    /// Allows combining limits expressed in Amperes and Watts, and
+    /// forcing the unit of the schedule.
+    pub fn with_unit_converter(mut self, unit_converter: UnitConverter) -> Self {
+        self.unit_converter = Some(unit_converter);
+        self
+    }
+
+    pub fn charging_rate_unit(&self) -> ChargingRateUnitType {
+        self.charging_rate_unit
+    }
+
+    pub fn max_limit(&self) -> f64 {
+        self.max_limit
+    }
+
+    pub fn unit_converter(&self) -> Option<&UnitConverter> {
+        self.unit_converter.as_ref()
+    }
+
+    /// Calculates the composite schedule of `connector_id` for the
+    /// `duration` seconds following `start`.
+    ///
+    /// `profiles` are the installed profiles together with the
+    /// connector they were installed on. Connector 0 reports the
+    /// schedule of the grid connection, limited by the
+    /// ChargePointMaxProfile only.
+    ///
+    /// Relative profiles start at `transaction_start`, the start of the
+    /// transaction running on the connector, or at `start` when there
+    /// is none.
+    ///
+    /// A `duration` reaching beyond the representable dates, as a
+    /// Central System may request, fails with a
+    /// PropertyConstraintViolation.
+    pub fn calculate<'a, I>(
+        &self,
+        profiles: I,
+        connector_id: usize,
+        start: DateTime<Utc>,
+        duration: usize,
+        charging_rate_unit: Option<ChargingRateUnitType>,
+        transaction_start: Option<DateTime<Utc>>,
+    ) -> Result<ChargingSchedule, OcppError>
+    where
+        I: IntoIterator<Item = (usize, &'a ChargingProfile)>,
+    {
+        let unit = charging_rate_unit.unwrap_or(self.charging_rate_unit);
+        let max_limit = self
+            .supply(connector_id, self.charging_rate_unit, unit)?
+            .map_or(Ok(self.max_limit), |supply| {
+                supply.convert(self.max_limit, self.charging_rate_unit, unit, None)
+            })?;
+
+        let end = i64::try_from(duration)
+            .ok()
+            .and_then(Duration::try_seconds)
+            .and_then(|duration| start.checked_add_signed(duration))
+            .ok_or_else(|| {
+                OcppError::PropertyConstraintViolation(format!(
+                    "duration {} is out of range",
+                    duration
+                ))
+            })?;
+        let mut layers = Vec::new();
+        for (connector, profile) in profiles {
+            let Some(layer) = Layer::of(profile, connector, connector_id) else {
+                continue;
+            };
+
+            let periods = profile.absolute_periods(start, end, transaction_start);
+            if periods.is_empty() {
+                continue;
+            }
+            let profile_unit = profile.charging_schedule().charging_rate_unit();
+
+            layers.push(Resolved {
+                layer,
+                stack_level: profile.stack_level(),
+                unit: profile_unit,
+                supply: self.supply(connector, profile_unit, unit)?,
+                periods,
+            });
+        }
+
+        let mut instants: Vec<DateTime<Utc>> = layers
+            .iter()
+            .flat_map(|resolved| &resolved.periods)
+            .flat_map(|period| [period.start(), period.end()])
+            .filter(|instant| *instant > start && *instant < end)
+            .collect();
+        instants.push(start);
+        instants.sort();
+        instants.dedup();
+
+        let mut periods: Vec<ChargingSchedulePeriod> = Vec::new();
+        for instant in instants {
+            let (limit, number_phases) = limit_at(&layers, instant, unit, max_limit)?;
+            let unchanged = periods
+                .last()
+                .is_some_and(|last| last.limit() == limit && last.number_phases() == number_phases);
+            if unchanged {
+                continue;
+            }
+
+            let offset = (instant - start).num_seconds() as usize;
+            let mut period = ChargingSchedulePeriod::builder(offset, limit);
+            if let Some(number_phases) = number_phases {
+                period = period.number_phases(number_phases);
+            }
+            periods.push(period.build()?);
+        }
+
+        ChargingSchedule::builder(unit, periods)
+            .duration(duration)
+            .start_schedule(start)
+            .build()
+    }
+
+    /// Supply used to convert limits of `connector_id` from unit `from`
+    /// to unit `to`, or `None` when no conversion is needed.
+    fn supply(
+        &self,
+        connector_id: usize,
+        from: ChargingRateUnitType,
+        to: ChargingRateUnitType,
+    ) -> Result<Option<Supply>, OcppError> {
+        if from == to {
+            return Ok(None);
+        }
+
+        match &self.unit_converter {
+            Some(converter) => Ok(Some(converter.supply(connector_id))),
+            None => Err(OcppError::NotSupported(format!(
+                "cannot convert limits expressed in {} to {}",
+                from, to
+            ))),
+        }
+    }
+}
+
+/// Role a profile plays in the composite schedule of a connector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Layer {
+    ChargePointMax,
+    StationDefault,
+    ConnectorDefault,
+    Tx,
+}
+
+impl Layer {
+    /// Role of a profile installed on `connector` when calculating the
+    /// schedule of `target`, or `None` if it does not apply.
+    fn of(profile: &ChargingProfile, connector: usize, target: usize) -> Option<Layer> {
+        match profile.charging_profile_purpose() {
+            ChargingProfilePurposeType::ChargePointMaxProfile if connector == 0 => {
+                Some(Layer::ChargePointMax)
+            }
+            _ if target == 0 => None,
+            ChargingProfilePurposeType::TxDefaultProfile if connector == 0 => {
+                Some(Layer::StationDefault)
+            }
+            ChargingProfilePurposeType::TxDefaultProfile if connector == target => {
+                Some(Layer::ConnectorDefault)
+            }
+            ChargingProfilePurposeType::TxProfile if connector == target => Some(Layer::Tx),
+            _ => None,
+        }
+    }
+}
+
+/// Profile resolved to absolute time.
+#[derive(Debug)]
+struct Resolved {
+    layer: Layer,
+    stack_level: usize,
+    /// Unit of the profile limits.
+    unit: ChargingRateUnitType,
+    /// Supply converting the limits to the schedule unit, if needed.
+    supply: Option<Supply>,
+    periods: Vec<AbsolutePeriod>,
+}
+
+impl Resolved {
+    /// Limit of `period` expressed in `unit`.
+    fn limit(&self, period: &AbsolutePeriod, unit: ChargingRateUnitType) -> Result<f64, OcppError> {
+        match self.supply {
+            Some(supply) => supply.convert(period.limit(), self.unit, unit, period.number_phases()),
+            None => Ok(period.limit()),
+        }
+    }
+}
+
+/// Limit and number of phases in force at `instant`, expressed in
+/// `unit` and capped by `max_limit`.
+fn limit_at(
+    layers: &[Resolved],
+    instant: DateTime<Utc>,
+    unit: ChargingRateUnitType,
+    max_limit: f64,
+) -> Result<(f64, Option<usize>), OcppError> {
+    let max = active(layers, Layer::ChargePointMax, instant);
+    let tx = active(layers, Layer::Tx, instant)
+        .or_else(|| active(layers, Layer::ConnectorDefault, instant))
+        .or_else(|| active(layers, Layer::StationDefault, instant));
+
+    let applied = [max, tx].into_iter().flatten();
+    let mut limit = max_limit;
+    for (resolved, period) in applied.clone() {
+        limit = limit.min(resolved.limit(period, unit)?);
+    }
+    let number_phases = applied
+        .filter_map(|(_, period)| period.number_phases())
+        .min();
+
+    Ok((limit, number_phases))
+}
+
+/// Period in force at `instant` of the profile with the highest
+/// stack level of `layer`.
+fn active(
+    layers: &[Resolved],
+    layer: Layer,
+    instant: DateTime<Utc>,
+) -> Option<(&Resolved, &AbsolutePeriod)> {
+    layers
+        .iter()
+        .filter(|resolved| resolved.layer == layer)
+        .filter_map(|resolved| {
+            resolved
+                .periods
+                .iter()
+                .find(|period| period.contains(instant))
+                .map(|period| (resolved, period))
+        })
+        .max_by_key(|(resolved, _)| resolved.stack_level)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::enumerations::{ChargingProfileKindType, RecurrencyKindType};
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, 4, hour, minute, 0).unwrap()
+    }
+
+    fn schedule(unit: ChargingRateUnitType, periods: &[(usize, f64)]) -> ChargingSchedule {
+        let periods = periods
+            .iter()
+            .map(|(start, limit)| ChargingSchedulePeriod::builder(*start, *limit).build())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        ChargingSchedule::builder(unit, periods).build().unwrap()
+    }
+
+    fn absolute(
+        id: usize,
+        purpose: ChargingProfilePurposeType,
+        stack_level: usize,
+        start: DateTime<Utc>,
+        duration: Option<usize>,
+        periods: &[(usize, f64)],
+    ) -> ChargingProfile {
+        let mut schedule = ChargingSchedule::builder(
+            ChargingRateUnitType::A,
+            schedule(ChargingRateUnitType::A, periods)
+                .charging_schedule_period()
+                .to_vec(),
+        )
+        .start_schedule(start);
+        if let Some(duration) = duration {
+            schedule = schedule.duration(duration);
+        }
+
+        ChargingProfile::builder(
+            id,
+            stack_level,
+            purpose,
+            ChargingProfileKindType::Absolute,
+            schedule.build().unwrap(),
+        )
+        .build()
+        .unwrap()
+    }
+
+    fn calculator() -> CompositeScheduleCalculator {
+        CompositeScheduleCalculator::new(ChargingRateUnitType::A, 32.0)
+    }
+
+    fn limits(schedule: &ChargingSchedule) -> Vec<(usize, f64)> {
+        schedule
+            .charging_schedule_period()
+            .iter()
+            .map(|period| (period.start_period(), period.limit()))
+            .collect()
+    }
+
+    #[test]
+    fn test_without_profiles_reports_max_limit() {
+        let schedule = calculator()
+            .calculate([], 1, at(8, 0), 3600, None, None)
+            .unwrap();
+
+        assert_eq!(limits(&schedule), vec![(0, 32.0)]);
+        assert_eq!(schedule.duration(), Some(3600));
+        assert_eq!(schedule.start_schedule(), Some(at(8, 0)));
+        assert_eq!(schedule.charging_rate_unit(), ChargingRateUnitType::A);
+    }
+
+    #[test]
+    fn test_higher_stack_level_prevails_while_active() {
+        let base = absolute(
+            1,
+            ChargingProfilePurposeType::TxDefaultProfile,
+            0,
+            at(0, 0),
+            None,
+            &[(0, 20.0)],
+        );
+        let peak = absolute(
+            2,
+            ChargingProfilePurposeType::TxDefaultProfile,
+            1,
+            at(9, 0),
+            Some(3600),
+            &[(0, 10.0)],
+        );
+
+        let schedule = calculator()
+            .calculate([(1, &base), (1, &peak)], 1, at(8, 0), 4 * 3600, None, None)
+            .unwrap();
+
+        assert_eq!(
+            limits(&schedule),
+            vec![(0, 20.0), (3600, 10.0), (7200, 20.0)]
+        );
+    }
+
+    #[test]
+    fn test_charge_point_max_profile_caps_connector_limit() {
+        let max = absolute(
+            1,
+            ChargingProfilePurposeType::ChargePointMaxProfile,
+            0,
+            at(0, 0),
+            None,
+            &[(0, 24.0), (10 * 3600, 16.0)],
+        );
+        let default = absolute(
+            2,
+            ChargingProfilePurposeType::TxDefaultProfile,
+            0,
+            at(0, 0),
+            None,
+            &[(0, 20.0)],
+        );
+
+        let schedule = calculator()
+            .calculate(
+                [(0, &max), (0, &default)],
+                2,
+                at(9, 0),
+                2 * 3600,
+                None,
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(limits(&schedule), vec![(0, 20.0), (3600, 16.0)]);
+    }
+
+    #[test]
+    fn test_tx_profile_overrides_tx_default_profile() {
+        let default = absolute(
+            1,
+            ChargingProfilePurposeType::TxDefaultProfile,
+            5,
+            at(0, 0),
+            None,
+            &[(0, 6.0)],
+        );
+        let tx = absolute(
+            2,
+            ChargingProfilePurposeType::TxProfile,
+            0,
+            at(8, 30),
+            Some(1800),
+            &[(0, 16.0)],
+        );
+
+        let schedule = calculator()
+            .calculate(
+                [(1, &default), (1, &tx)],
+                1,
+                at(8, 0),
+                3600 + 1800,
+                None,
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(limits(&schedule), vec![(0, 6.0), (1800, 16.0), (3600, 6.0)]);
+    }
+
+    #[test]
+    fn test_connector_default_overrides_charge_point_default() {
+        let station = absolute(
+            1,
+            ChargingProfilePurposeType::TxDefaultProfile,
+            3,
+            at(0, 0),
+            None,
+            &[(0, 10.0)],
+        );
+        let connector = absolute(
+            2,
+            ChargingProfilePurposeType::TxDefaultProfile,
+            0,
+            at(0, 0),
+            None,
+            &[(0, 14.0)],
+        );
+
+        let calculator = calculator();
+        let profiles = [(0, &station), (1, &connector)];
+
+        let first = calculator
+            .calculate(profiles, 1, at(8, 0), 3600, None, None)
+            .unwrap();
+        let second = calculator
+            .calculate(profiles, 2, at(8, 0), 3600, None, None)
+            .unwrap();
+
+        assert_eq!(limits(&first), vec![(0, 14.0)]);
+        assert_eq!(limits(&second), vec![(0, 10.0)]);
+    }
+
+    #[test]
+    fn test_connector_zero_only_uses_charge_point_max_profile() {
+        let max = absolute(
+            1,
+            ChargingProfilePurposeType::ChargePointMaxProfile,
+            0,
+            at(0, 0),
+            None,
+            &[(0, 25.0)],
+        );
+        let default = absolute(
+            2,
+            ChargingProfilePurposeType::TxDefaultProfile,
+            0,
+            at(0, 0),
+            None,
+            &[(0, 10.0)],
+        );
+
+        let schedule = calculator()
+            .calculate([(0, &max), (0, &default)], 0, at(8, 0), 3600, None, None)
+            .unwrap();
+
+        assert_eq!(limits(&schedule), vec![(0, 25.0)]);
+    }
+
+    #[test]
+    fn test_profile_validity_is_honored() {
+        let schedule = ChargingSchedule::builder(
+            ChargingRateUnitType::A,
+            vec![ChargingSchedulePeriod::builder(0, 8.0).build().unwrap()],
+        )
+        .start_schedule(at(0, 0))
+        .build()
+        .unwrap();
+        let profile = ChargingProfile::builder(
+            1,
+            0,
+            ChargingProfilePurposeType::TxDefaultProfile,
+            ChargingProfileKindType::Absolute,
+            schedule,
+        )
+        .valid_from(at(8, 15))
+        .valid_to(at(8, 45))
+        .build()
+        .unwrap();
+
+        let schedule = calculator()
+            .calculate([(1, &profile)], 1, at(8, 0), 3600, None, None)
+            .unwrap();
+
+        assert_eq!(limits(&schedule), vec![(0, 32.0), (900, 8.0), (2700, 32.0)]);
+    }
+
+    #[test]
+    fn test_daily_recurring_profile() {
+        let night = ChargingSchedule::builder(
+            ChargingRateUnitType::A,
+            vec![
+                ChargingSchedulePeriod::builder(0, 16.0).build().unwrap(),
+                ChargingSchedulePeriod::builder(7 * 3600, 32.0)
+                    .build()
+                    .unwrap(),
+            ],
+        )
+        .start_schedule(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+        .duration(24 * 3600)
+        .build()
+        .unwrap();
+        let profile = ChargingProfile::builder(
+            1,
+            0,
+            ChargingProfilePurposeType::TxDefaultProfile,
+            ChargingProfileKindType::Recurring,
+            night,
+        )
+        .recurrency_kind(RecurrencyKindType::Daily)
+        .build()
+        .unwrap();
+
+        let schedule = CompositeScheduleCalculator::new(ChargingRateUnitType::A, 40.0)
+            .calculate([(1, &profile)], 1, at(6, 0), 20 * 3600, None, None)
+            .unwrap();
+
+        assert_eq!(
+            limits(&schedule),
+            vec![(0, 16.0), (3600, 32.0), (18 * 3600, 16.0)]
+        );
+    }
+
+    #[test]
+    fn test_relative_tx_profile_starts_with_transaction() {
+        let profile = ChargingProfile::builder(
+            1,
+            0,
+            ChargingProfilePurposeType::TxProfile,
+            ChargingProfileKindType::Relative,
+            schedule(ChargingRateUnitType::A, &[(0, 10.0), (3600, 20.0)]),
+        )
+        .build()
+        .unwrap();
+
+        let schedule = calculator()
+            .calculate([(1, &profile)], 1, at(8, 0), 3600, None, Some(at(7, 30)))
+            .unwrap();
+
+        assert_eq!(limits(&schedule), vec![(0, 10.0), (1800, 20.0)]);
+    }
+
+    #[test]
+    fn test_number_phases_are_reported() {
+        let schedule = ChargingSchedule::builder(
+            ChargingRateUnitType::A,
+            vec![ChargingSchedulePeriod::builder(0, 16.0)
+                .number_phases(1)
+                .build()
+                .unwrap()],
+        )
+        .start_schedule(at(0, 0))
+        .build()
+        .unwrap();
+        let profile = ChargingProfile::builder(
+            1,
+            0,
+            ChargingProfilePurposeType::TxProfile,
+            ChargingProfileKindType::Absolute,
+            schedule,
+        )
+        .build()
+        .unwrap();
+
+        let schedule = calculator()
+            .calculate([(1, &profile)], 1, at(8, 0), 3600, None, None)
+            .unwrap();
+
+        assert_eq!(
+            schedule.charging_schedule_period()[0].number_phases(),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_zero_duration() {
+        let schedule = calculator()
+            .calculate([], 1, at(8, 0), 0, None, None)
+            .unwrap();
+
+        assert_eq!(limits(&schedule), vec![(0, 32.0)]);
+    }
+
+    #[test]
+    fn test_mixed_units_with_unit_converter() {
+        let max = ChargingProfile::builder(
+            1,
+            0,
+            ChargingProfilePurposeType::ChargePointMaxProfile,
+            ChargingProfileKindType::Relative,
+            schedule(ChargingRateUnitType::W, &[(0, 11000.0)]),
+        )
+        .build()
+        .unwrap();
+        let default = ChargingProfile::builder(
+            2,
+            0,
+            ChargingProfilePurposeType::TxDefaultProfile,
+            ChargingProfileKindType::Relative,
+            schedule(ChargingRateUnitType::A, &[(0, 10.0), (600, 20.0)]),
+        )
+        .build()
+        .unwrap();
+        let calculator =
+            calculator().with_unit_converter(UnitConverter::new(Supply::new(230.0, 3).unwrap()));
+        let profiles = [(0, &max), (1, &default)];
+
+        let amperes = calculator
+            .calculate(profiles, 1, at(8, 0), 3600, None, None)
+            .unwrap();
+        let watts = calculator
+            .calculate(
+                profiles,
+                1,
+                at(8, 0),
+                3600,
+                Some(ChargingRateUnitType::W),
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(limits(&amperes), vec![(0, 10.0), (600, 15.9)]);
+        assert_eq!(watts.charging_rate_unit(), ChargingRateUnitType::W);
+        assert_eq!(limits(&watts), vec![(0, 6900.0), (600, 11000.0)]);
+    }
+
+    #[test]
+    fn test_mixed_units_are_not_supported() {
+        let profile = ChargingProfile::builder(
+            1,
+            0,
+            ChargingProfilePurposeType::TxDefaultProfile,
+            ChargingProfileKindType::Relative,
+            schedule(ChargingRateUnitType::W, &[(0, 11000.0)]),
+        )
+        .build()
+        .unwrap();
+
+        let calculator = calculator();
+
+        assert!(matches!(
+            calculator.calculate([(1, &profile)], 1, at(8, 0), 3600, None, None),
+            Err(OcppError::NotSupported(_))
+        ));
+        assert!(matches!(
+            calculator.calculate([], 1, at(8, 0), 3600, Some(ChargingRateUnitType::W), None),
+            Err(OcppError::NotSupported(_))
+        ));
+    }
+
+    /// Three phase schedule in Watts starting at `start_schedule`.
+    fn three_phase_schedule(
+        start_schedule: Option<DateTime<Utc>>,
+        duration: Option<usize>,
+        periods: &[(usize, f64)],
+    ) -> ChargingSchedule {
+        let periods = periods
+            .iter()
+            .map(|(start, limit)| {
+                ChargingSchedulePeriod::builder(*start, *limit)
+                    .number_phases(3)
+                    .build()
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        let mut schedule = ChargingSchedule::builder(ChargingRateUnitType::W, periods);
+        if let Some(start_schedule) = start_schedule {
+            schedule = schedule.start_schedule(start_schedule);
+        }
+        if let Some(duration) = duration {
+            schedule = schedule.duration(duration);
+        }
+        schedule.build().unwrap()
+    }
+
+    /// TxDefaultProfile of the specification example limiting charging
+    /// to 6 kW during the day, from 08:00 to 20:00, and to 11 kW
+    /// otherwise.
+    fn day_profile() -> ChargingProfile {
+        ChargingProfile::builder(
+            100,
+            0,
+            ChargingProfilePurposeType::TxDefaultProfile,
+            ChargingProfileKindType::Recurring,
+            three_phase_schedule(
+                Some(Utc.with_ymd_and_hms(2013, 1, 1, 0, 0, 0).unwrap()),
+                Some(86400),
+                &[(0, 11000.0), (28800, 6000.0), (72000, 11000.0)],
+            ),
+        )
+        .recurrency_kind(RecurrencyKindType::Daily)
+        .build()
+        .unwrap()
+    }
+
+    fn on_january_2(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2013, 1, 2, hour, 0, 0).unwrap()
+    }
+
+    fn watt_calculator() -> CompositeScheduleCalculator {
+        CompositeScheduleCalculator::new(ChargingRateUnitType::W, 22000.0)
+    }
+
+    #[test]
+    fn test_spec_example_day_profile() {
+        let profile = day_profile();
+
+        let schedule = watt_calculator()
+            .calculate([(0, &profile)], 1, on_january_2(6), 86400, None, None)
+            .unwrap();
+
+        assert_eq!(
+            limits(&schedule),
+            vec![(0, 11000.0), (7200, 6000.0), (50400, 11000.0)]
+        );
+        assert!(schedule
+            .charging_schedule_period()
+            .iter()
+            .all(|period| period.number_phases() == Some(3)));
+    }
+
+    #[test]
+    fn test_spec_example_stacked_on_day_profile() {
+        // A TxDefaultProfile with a higher stack level lifts the day
+        // limit for a single day, after which the day profile applies
+        // again.
+        let day = day_profile();
+        let holiday = ChargingProfile::builder(
+            101,
+            1,
+            ChargingProfilePurposeType::TxDefaultProfile,
+            ChargingProfileKindType::Absolute,
+            three_phase_schedule(Some(on_january_2(0)), Some(86400), &[(0, 9000.0)]),
+        )
+        .build()
+        .unwrap();
+
+        let schedule = watt_calculator()
+            .calculate(
+                [(0, &day), (0, &holiday)],
+                1,
+                on_january_2(6),
+                86400,
+                None,
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(limits(&schedule), vec![(0, 9000.0), (64800, 11000.0)]);
+    }
+
+    #[test]
+    fn test_spec_example_combined_purposes() {
+        // The ChargePointMaxProfile caps the Charge Point at 10 kW, the
+        // TxProfile of the transaction started at 19:00 overrides the
+        // day profile while it lasts, and the composite schedule is the
+        // minimum of both purposes.
+        let day = day_profile();
+        let max = ChargingProfile::builder(
+            1,
+            0,
+            ChargingProfilePurposeType::ChargePointMaxProfile,
+            ChargingProfileKindType::Absolute,
+            three_phase_schedule(
+                Some(Utc.with_ymd_and_hms(2013, 1, 1, 0, 0, 0).unwrap()),
+                None,
+                &[(0, 10000.0)],
+            ),
+        )
+        .build()
+        .unwrap();
+        let tx = ChargingProfile::builder(
+            2,
+            0,
+            ChargingProfilePurposeType::TxProfile,
+            ChargingProfileKindType::Relative,
+            three_phase_schedule(None, Some(7200), &[(0, 4000.0), (3600, 22000.0)]),
+        )
+        .transaction_id(1)
+        .build()
+        .unwrap();
+
+        let schedule = watt_calculator()
+            .calculate(
+                [(0, &max), (0, &day), (1, &tx)],
+                1,
+                on_january_2(18),
+                6 * 3600,
+                None,
+                Some(on_january_2(19)),
+            )
+            .unwrap();
+
+        assert_eq!(
+            limits(&schedule),
+            vec![(0, 6000.0), (3600, 4000.0), (7200, 10000.0)]
+        );
+    }
+
+    #[test]
+    fn test_duration_out_of_range() {
+        let calculator = calculator();
+
+        for duration in [100_000_000_000_000, usize::MAX] {
+            assert!(matches!(
+                calculator.calculate([], 1, at(8, 0), duration, None, None),
+                Err(OcppError::PropertyConstraintViolation(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,6 @@
+//! Local smart charging, as described in chapter 3.13 of the
+//! OCPP 1.6 specification.
+
+pub mod composite;
+pub mod store;
+pub mod units;
@@ -0,0 +1,474 @@
+//! Charging profiles installed on a Charge Point.
+
+use std::collections::HashMap;
+
+use crate::messages::clear_charging_profile::ClearChargingProfileReq;
+use crate::messages::set_charging_profile::SetChargingProfileReq;
+use crate::types::charging_profile::ChargingProfile;
+use crate::types::enumerations::{
+    ChargingProfilePurposeType, ChargingProfileStatus, ClearChargingProfileStatus,
+};
+
+/// Charging profiles installed on a Charge Point, maintained according
+/// to the SetChargingProfile and ClearChargingProfile semantics.
+///
+/// The store also tracks the transaction running on each connector, as
+/// a TxProfile is only accepted during a transaction and is removed
+/// when it ends.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChargingProfileStore {
+    /// Number of connectors of the Charge Point, not counting
+    /// connector 0.
+    number_of_connectors: usize,
+    /// Installed profiles, with the connector they apply to.
+    profiles: Vec<(usize, ChargingProfile)>,
+    /// Transaction running on each connector.
+    transactions: HashMap<usize, usize>,
+}
+
+impl ChargingProfileStore {
+    pub fn new(number_of_connectors: usize) -> Self {
+        ChargingProfileStore {
+            number_of_connectors,
+            profiles: Vec::new(),
+            transactions: HashMap::new(),
+        }
+    }
+
+    pub fn number_of_connectors(&self) -> usize {
+        self.number_of_connectors
+    }
+
+    /// Installed profiles, with the connector they apply to. Suitable
+    /// as input of a
+    /// [`CompositeScheduleCalculator`](super::composite::CompositeScheduleCalculator).
+    pub fn profiles(&self) -> impl Iterator<Item = (usize, &ChargingProfile)> + Clone {
+        self.profiles
+            .iter()
+            .map(|(connector_id, profile)| (*connector_id, profile))
+    }
+
+    /// Profiles installed on `connector_id`.
+    pub fn profiles_for(&self, connector_id: usize) -> impl Iterator<Item = &ChargingProfile> {
+        self.profiles
+            .iter()
+            .filter(move |(connector, _)| *connector == connector_id)
+            .map(|(_, profile)| profile)
+    }
+
+    /// Transaction running on `connector_id`, if any.
+    pub fn transaction_id(&self, connector_id: usize) -> Option<usize> {
+        self.transactions.get(&connector_id).copied()
+    }
+
+    /// Records that a transaction started on `connector_id`, allowing
+    /// TxProfiles to be installed on it.
+    pub fn start_transaction(&mut self, connector_id: usize, transaction_id: usize) {
+        self.transactions.insert(connector_id, transaction_id);
+    }
+
+    /// Records that the transaction on `connector_id` ended, removing
+    /// the TxProfiles installed for it.
+    pub fn stop_transaction(&mut self, connector_id: usize) {
+        self.transactions.remove(&connector_id);
+        self.profiles.retain(|(connector, profile)| {
+            *connector != connector_id
+                || profile.charging_profile_purpose() != ChargingProfilePurposeType::TxProfile
+        });
+    }
+
+    /// Installs the profile of a SetChargingProfile.req.
+    ///
+    /// The profile replaces an installed profile with the same
+    /// chargingProfileId, or with the same stackLevel and purpose on
+    /// the same connector. It is rejected when:
+    ///
+    /// * the connector does not exist;
+    /// * a ChargePointMaxProfile targets a connector other than 0;
+    /// * a TxProfile targets connector 0, a connector without a
+    ///   transaction, or another transaction than the running one.
+    pub fn set(&mut self, request: &SetChargingProfileReq) -> ChargingProfileStatus {
+        let connector_id = request.connector_id();
+        let profile = request.cs_charging_profiles();
+
+        if connector_id > self.number_of_connectors {
+            return ChargingProfileStatus::Rejected;
+        }
+
+        match profile.charging_profile_purpose() {
+            ChargingProfilePurposeType::ChargePointMaxProfile if connector_id != 0 => {
+                return ChargingProfileStatus::Rejected;
+            }
+            ChargingProfilePurposeType::TxProfile => {
+                let Some(running) = self.transaction_id(connector_id) else {
+                    return ChargingProfileStatus::Rejected;
+                };
+                if profile
+                    .transaction_id()
+                    .is_some_and(|transaction_id| transaction_id != running)
+                {
+                    return ChargingProfileStatus::Rejected;
+                }
+            }
+            _ => {}
+        }
+
+        self.profiles.retain(|(connector, installed)| {
+            let same_id = installed.charging_profile_id() == profile.charging_profile_id();
+            let same_slot = *connector == connector_id
+                && installed.stack_level() == profile.stack_level()
+                && installed.charging_profile_purpose() == profile.charging_profile_purpose();
+
+            !same_id && !same_slot
+        });
+        self.profiles.push((connector_id, profile.clone()));
+
+        ChargingProfileStatus::Accepted
+    }
+
+    /// Removes the profiles selected by a ClearChargingProfile.req.
+    ///
+    /// When the request carries an id only that profile is removed and
+    /// the other criteria are ignored. Otherwise every profile matching
+    /// all the given criteria is removed, an empty request clearing all
+    /// profiles.
+    pub fn clear(&mut self, request: &ClearChargingProfileReq) -> ClearChargingProfileStatus {
+        let before = self.profiles.len();
+
+        self.profiles.retain(|(connector, profile)| {
+            let matches = match request.id() {
+                Some(id) => profile.charging_profile_id() == id,
+                None => {
+                    request.connector_id().is_none_or(|id| id == *connector)
+                        && request
+                            .charging_profile_purpose()
+                            .is_none_or(|purpose| purpose == profile.charging_profile_purpose())
+                        && request
+                            .stack_level()
+                            .is_none_or(|level| level == profile.stack_level())
+                }
+            };
+
+            !matches
+        });
+
+        if self.profiles.len() < before {
+            ClearChargingProfileStatus::Accepted
+        } else {
+            ClearChargingProfileStatus::Unknown
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::charging_profile::{ChargingSchedule, ChargingSchedulePeriod};
+    use crate::types::enumerations::{ChargingProfileKindType, ChargingRateUnitType};
+
+    fn profile(
+        id: usize,
+        stack_level: usize,
+        purpose: ChargingProfilePurposeType,
+    ) -> ChargingProfile {
+        let schedule = ChargingSchedule::builder(
+            ChargingRateUnitType::A,
+            vec![ChargingSchedulePeriod::builder(0, 16.0).build().unwrap()],
+        )
+        .build()
+        .unwrap();
+
+        ChargingProfile::builder(
+            id,
+            stack_level,
+            purpose,
+            ChargingProfileKindType::Relative,
+            schedule,
+        )
+        .build()
+        .unwrap()
+    }
+
+    fn set(
+        store: &mut ChargingProfileStore,
+        connector_id: usize,
+        profile: ChargingProfile,
+    ) -> ChargingProfileStatus {
+        store.set(&SetChargingProfileReq::new(connector_id, profile).unwrap())
+    }
+
+    fn ids(store: &ChargingProfileStore) -> Vec<usize> {
+        let mut ids: Vec<usize> = store
+            .profiles()
+            .map(|(_, profile)| profile.charging_profile_id())
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn test_set_profile() {
+        let mut store = ChargingProfileStore::new(2);
+
+        let status = set(
+            &mut store,
+            1,
+            profile(1, 0, ChargingProfilePurposeType::TxDefaultProfile),
+        );
+
+        assert_eq!(status, ChargingProfileStatus::Accepted);
+        assert_eq!(store.profiles_for(1).count(), 1);
+        assert_eq!(store.profiles_for(2).count(), 0);
+    }
+
+    #[test]
+    fn test_set_replaces_same_id() {
+        let mut store = ChargingProfileStore::new(2);
+        set(
+            &mut store,
+            1,
+            profile(1, 0, ChargingProfilePurposeType::TxDefaultProfile),
+        );
+
+        set(
+            &mut store,
+            2,
+            profile(1, 3, ChargingProfilePurposeType::TxDefaultProfile),
+        );
+
+        assert_eq!(store.profiles_for(1).count(), 0);
+        assert_eq!(store.profiles_for(2).next().unwrap().stack_level(), 3);
+    }
+
+    #[test]
+    fn test_set_replaces_same_stack_level_and_purpose() {
+        let mut store = ChargingProfileStore::new(2);
+        set(
+            &mut store,
+            1,
+            profile(1, 2, ChargingProfilePurposeType::TxDefaultProfile),
+        );
+        set(
+            &mut store,
+            2,
+            profile(2, 2, ChargingProfilePurposeType::TxDefaultProfile),
+        );
+
+        set(
+            &mut store,
+            1,
+            profile(3, 2, ChargingProfilePurposeType::TxDefaultProfile),
+        );
+
+        assert_eq!(ids(&store), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_set_keeps_other_stack_levels() {
+        let mut store = ChargingProfileStore::new(1);
+        set(
+            &mut store,
+            1,
+            profile(1, 0, ChargingProfilePurposeType::TxDefaultProfile),
+        );
+
+        set(
+            &mut store,
+            1,
+            profile(2, 1, ChargingProfilePurposeType::TxDefaultProfile),
+        );
+
+        assert_eq!(ids(&store), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_set_rejects_unknown_connector() {
+        let mut store = ChargingProfileStore::new(2);
+
+        let status = set(
+            &mut store,
+            3,
+            profile(1, 0, ChargingProfilePurposeType::TxDefaultProfile),
+        );
+
+        assert_eq!(status, ChargingProfileStatus::Rejected);
+    }
+
+    #[test]
+    fn test_set_rejects_charge_point_max_profile_on_connector() {
+        let mut store = ChargingProfileStore::new(2);
+
+        let rejected = set(
+            &mut store,
+            1,
+            profile(1, 0, ChargingProfilePurposeType::ChargePointMaxProfile),
+        );
+        let accepted = set(
+            &mut store,
+            0,
+            profile(1, 0, ChargingProfilePurposeType::ChargePointMaxProfile),
+        );
+
+        assert_eq!(rejected, ChargingProfileStatus::Rejected);
+        assert_eq!(accepted, ChargingProfileStatus::Accepted);
+    }
+
+    #[test]
+    fn test_set_rejects_tx_profile_without_transaction() {
+        let mut store = ChargingProfileStore::new(2);
+
+        let status = set(
+            &mut store,
+            1,
+            profile(1, 0, ChargingProfilePurposeType::TxProfile),
+        );
+
+        assert_eq!(status, ChargingProfileStatus::Rejected);
+        assert_eq!(store.profiles().count(), 0);
+    }
+
+    #[test]
+    fn test_set_tx_profile_during_transaction() {
+        let mut store = ChargingProfileStore::new(2);
+        store.start_transaction(1, 42);
+
+        let status = set(
+            &mut store,
+            1,
+            profile(1, 0, ChargingProfilePurposeType::TxProfile),
+        );
+
+        assert_eq!(status, ChargingProfileStatus::Accepted);
+    }
+
+    #[test]
+    fn test_set_rejects_tx_profile_for_other_transaction() {
+        let mut store = ChargingProfileStore::new(2);
+        store.start_transaction(1, 42);
+        let schedule = ChargingSchedule::builder(
+            ChargingRateUnitType::A,
+            vec![ChargingSchedulePeriod::builder(0, 16.0).build().unwrap()],
+        )
+        .build()
+        .unwrap();
+        let profile = ChargingProfile::builder(
+            1,
+            0,
+            ChargingProfilePurposeType::TxProfile,
+            ChargingProfileKindType::Relative,
+            schedule,
+        )
+        .transaction_id(7)
+        .build()
+        .unwrap();
+
+        assert_eq!(set(&mut store, 1, profile), ChargingProfileStatus::Rejected);
+    }
+
+    #[test]
+    fn test_stop_transaction_removes_tx_profiles() {
+        let mut store = ChargingProfileStore::new(2);
+        store.start_transaction(1, 42);
+        set(
+            &mut store,
+            1,
+            profile(1, 0, ChargingProfilePurposeType::TxProfile),
+        );
+        set(
+            &mut store,
+            1,
+            profile(2, 0, ChargingProfilePurposeType::TxDefaultProfile),
+        );
+
+        store.stop_transaction(1);
+
+        assert_eq!(store.transaction_id(1), None);
+        assert_eq!(ids(&store), vec![2]);
+    }
+
+    fn populated() -> ChargingProfileStore {
+        let mut store = ChargingProfileStore::new(2);
+        set(
+            &mut store,
+            0,
+            profile(1, 0, ChargingProfilePurposeType::ChargePointMaxProfile),
+        );
+        set(
+            &mut store,
+            0,
+            profile(2, 0, ChargingProfilePurposeType::TxDefaultProfile),
+        );
+        set(
+            &mut store,
+            1,
+            profile(3, 1, ChargingProfilePurposeType::TxDefaultProfile),
+        );
+        set(
+            &mut store,
+            2,
+            profile(4, 1, ChargingProfilePurposeType::TxDefaultProfile),
+        );
+        store
+    }
+
+    #[test]
+    fn test_clear_by_id() {
+        let mut store = populated();
+        let request = ClearChargingProfileReq::builder()
+            .id(3)
+            .connector_id(2)
+            .build()
+            .unwrap();
+
+        assert_eq!(store.clear(&request), ClearChargingProfileStatus::Accepted);
+        assert_eq!(ids(&store), vec![1, 2, 4]);
+    }
+
+    #[test]
+    fn test_clear_by_criteria() {
+        let mut store = populated();
+        let request = ClearChargingProfileReq::builder()
+            .charging_profile_purpose(ChargingProfilePurposeType::TxDefaultProfile)
+            .stack_level(1)
+            .build()
+            .unwrap();
+
+        assert_eq!(store.clear(&request), ClearChargingProfileStatus::Accepted);
+        assert_eq!(ids(&store), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_clear_by_connector() {
+        let mut store = populated();
+        let request = ClearChargingProfileReq::builder()
+            .connector_id(0)
+            .build()
+            .unwrap();
+
+        assert_eq!(store.clear(&request), ClearChargingProfileStatus::Accepted);
+        assert_eq!(ids(&store), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_clear_all() {
+        let mut store = populated();
+        let request = ClearChargingProfileReq::builder().build().unwrap();
+
+        assert_eq!(store.clear(&request), ClearChargingProfileStatus::Accepted);
+        assert_eq!(store.profiles().count(), 0);
+    }
+
+    #[test]
+    fn test_clear_without_match() {
+        let mut store = populated();
+        let request = ClearChargingProfileReq::builder()
+            .connector_id(1)
+            .charging_profile_purpose(ChargingProfilePurposeType::ChargePointMaxProfile)
+            .build()
+            .unwrap();
+
+        assert_eq!(store.clear(&request), ClearChargingProfileStatus::Unknown);
+        assert_eq!(ids(&store), vec![1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,312 @@
+//! Conversion of charging limits between Amperes and Watts.
+//!
+//! A limit of `I` Amperes per phase corresponds to `U * I * n` Watts,
+//! where `U` is the nominal phase to neutral voltage and `n` the number
+//! of phases used for charging. The number of phases is taken from the
+//! schedule period when given, and from the connector otherwise.
+//!
+//! Converted limits are rounded down to one decimal, the precision
+//! accepted by the schema, so that a converted limit never exceeds the
+//! original one.
+
+use std::collections::HashMap;
+
+use crate::error::OcppError;
+use crate::types::charging_profile::{ChargingSchedule, ChargingSchedulePeriod};
+use crate::types::enumerations::ChargingRateUnitType;
+
+/// Electrical supply of a connector.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Supply {
+    /// Nominal phase to neutral voltage, in Volts.
+    nominal_voltage: f64,
+    /// Number of phases the connector is wired with.
+    number_phases: usize,
+}
+
+impl Supply {
+    /// Describes a supply of `number_phases` phases at
+    /// `nominal_voltage`, both of which must be positive for limits to
+    /// be converted.
+    pub fn new(nominal_voltage: f64, number_phases: usize) -> Result<Self, OcppError> {
+        if nominal_voltage.is_nan() || nominal_voltage <= 0.0 {
+            return Err(OcppError::PropertyConstraintViolation(format!(
+                "nominal voltage must be positive, got {}",
+                nominal_voltage
+            )));
+        }
+        check_number_phases(number_phases)?;
+
+        Ok(Supply {
+            nominal_voltage,
+            number_phases,
+        })
+    }
+
+    pub fn nominal_voltage(&self) -> f64 {
+        self.nominal_voltage
+    }
+
+    pub fn number_phases(&self) -> usize {
+        self.number_phases
+    }
+
+    /// Converts `limit` from unit `from` to unit `to`, charging on
+    /// `number_phases` phases or on all phases of the supply when
+    /// absent. Charging on no phase at all is rejected.
+    pub fn convert(
+        &self,
+        limit: f64,
+        from: ChargingRateUnitType,
+        to: ChargingRateUnitType,
+        number_phases: Option<usize>,
+    ) -> Result<f64, OcppError> {
+        let number_phases = number_phases.unwrap_or(self.number_phases);
+        check_number_phases(number_phases)?;
+
+        let factor = self.nominal_voltage * number_phases as f64;
+        Ok(match (from, to) {
+            (ChargingRateUnitType::A, ChargingRateUnitType::W) => round_down(limit * factor),
+            (ChargingRateUnitType::W, ChargingRateUnitType::A) => round_down(limit / factor),
+            _ => limit,
+        })
+    }
+
+    /// Converts every limit of `schedule`, as well as its minimum
+    /// charging rate, to `unit`.
+    pub fn convert_schedule(
+        &self,
+        schedule: &ChargingSchedule,
+        unit: ChargingRateUnitType,
+    ) -> Result<ChargingSchedule, OcppError> {
+        let from = schedule.charging_rate_unit();
+        let periods = schedule
+            .charging_schedule_period()
+            .iter()
+            .map(|period| {
+                let limit = self.convert(period.limit(), from, unit, period.number_phases())?;
+                let mut converted = ChargingSchedulePeriod::builder(period.start_period(), limit);
+                if let Some(number_phases) = period.number_phases() {
+                    converted = converted.number_phases(number_phases);
+                }
+                converted.build()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut converted = ChargingSchedule::builder(unit, periods);
+        if let Some(duration) = schedule.duration() {
+            converted = converted.duration(duration);
+        }
+        if let Some(start_schedule) = schedule.start_schedule() {
+            converted = converted.start_schedule(start_schedule);
+        }
+        if let Some(min_charging_rate) = schedule.min_charging_rate() {
+            converted =
+                converted.min_charging_rate(self.convert(min_charging_rate, from, unit, None)?);
+        }
+        converted.build()
+    }
+}
+
+/// Supply of every connector of a Charge Point, used to convert limits
+/// between Amperes and Watts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitConverter {
+    /// Supply of the connectors without a specific one.
+    default: Supply,
+    /// Supply of specific connectors.
+    connectors: HashMap<usize, Supply>,
+}
+
+impl UnitConverter {
+    /// Builds a converter where every connector has the `default`
+    /// supply.
+    pub fn new(default: Supply) -> Self {
+        UnitConverter {
+            default,
+            connectors: HashMap::new(),
+        }
+    }
+
+    /// Sets the supply of `connector_id`. Connector 0 stands for the
+    /// grid connection of the Charge Point.
+    pub fn with_connector(mut self, connector_id: usize, supply: Supply) -> Self {
+        self.connectors.insert(connector_id, supply);
+        self
+    }
+
+    /// Supply of `connector_id`.
+    pub fn supply(&self, connector_id: usize) -> Supply {
+        self.connectors
+            .get(&connector_id)
+            .copied()
+            .unwrap_or(self.default)
+    }
+
+    /// Converts `schedule`, applying to `connector_id`, to `unit`.
+    pub fn convert_schedule(
+        &self,
+        connector_id: usize,
+        schedule: &ChargingSchedule,
+        unit: ChargingRateUnitType,
+    ) -> Result<ChargingSchedule, OcppError> {
+        self.supply(connector_id).convert_schedule(schedule, unit)
+    }
+}
+
+fn check_number_phases(number_phases: usize) -> Result<(), OcppError> {
+    if number_phases == 0 {
+        return Err(OcppError::PropertyConstraintViolation(String::from(
+            "number of phases must be positive",
+        )));
+    }
+    Ok(())
+}
+
+/// Rounds down to one decimal, tolerating the representation error of
+/// values that are already exact.
+fn round_down(value: f64) -> f64 {
+    (value * 10.0 + 1e-6).floor() / 10.0
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const THREE_PHASE: Supply = Supply {
+        nominal_voltage: 230.0,
+        number_phases: 3,
+    };
+
+    #[test]
+    fn test_amperes_to_watts() {
+        assert_eq!(
+            THREE_PHASE.convert(16.0, ChargingRateUnitType::A, ChargingRateUnitType::W, None),
+            Ok(11040.0)
+        );
+    }
+
+    #[test]
+    fn test_watts_to_amperes_rounds_down() {
+        // 11000 W / 690 V = 15.942 A
+        assert_eq!(
+            THREE_PHASE.convert(
+                11000.0,
+                ChargingRateUnitType::W,
+                ChargingRateUnitType::A,
+                None
+            ),
+            Ok(15.9)
+        );
+    }
+
+    #[test]
+    fn test_exact_values_are_not_rounded_down() {
+        // 7.2 * 230 is not exactly representable as a binary float.
+        let supply = Supply::new(230.0, 1).unwrap();
+
+        assert_eq!(
+            supply.convert(7.2, ChargingRateUnitType::A, ChargingRateUnitType::W, None),
+            Ok(1656.0)
+        );
+        assert_eq!(
+            supply.convert(
+                1656.0,
+                ChargingRateUnitType::W,
+                ChargingRateUnitType::A,
+                None
+            ),
+            Ok(7.2)
+        );
+    }
+
+    #[test]
+    fn test_period_number_phases_overrides_supply() {
+        assert_eq!(
+            THREE_PHASE.convert(
+                16.0,
+                ChargingRateUnitType::A,
+                ChargingRateUnitType::W,
+                Some(1)
+            ),
+            Ok(3680.0)
+        );
+    }
+
+    #[test]
+    fn test_same_unit_is_unchanged() {
+        assert_eq!(
+            THREE_PHASE.convert(
+                16.25,
+                ChargingRateUnitType::A,
+                ChargingRateUnitType::A,
+                None
+            ),
+            Ok(16.25)
+        );
+    }
+
+    #[test]
+    fn test_convert_schedule() {
+        let schedule = ChargingSchedule::builder(
+            ChargingRateUnitType::W,
+            vec![
+                ChargingSchedulePeriod::builder(0, 11040.0).build().unwrap(),
+                ChargingSchedulePeriod::builder(600, 3680.0)
+                    .number_phases(1)
+                    .build()
+                    .unwrap(),
+            ],
+        )
+        .duration(3600)
+        .min_charging_rate(4140.0)
+        .build()
+        .unwrap();
+
+        let converted = THREE_PHASE
+            .convert_schedule(&schedule, ChargingRateUnitType::A)
+            .unwrap();
+
+        let periods = converted.charging_schedule_period();
+        assert_eq!(converted.charging_rate_unit(), ChargingRateUnitType::A);
+        assert_eq!(converted.duration(), Some(3600));
+        assert_eq!(converted.min_charging_rate(), Some(6.0));
+        assert_eq!((periods[0].start_period(), periods[0].limit()), (0, 16.0));
+        assert_eq!((periods[1].start_period(), periods[1].limit()), (600, 16.0));
+        assert_eq!(periods[1].number_phases(), Some(1));
+    }
+
+    #[test]
+    fn test_converter_per_connector() {
+        let converter =
+            UnitConverter::new(THREE_PHASE).with_connector(2, Supply::new(120.0, 1).unwrap());
+
+        assert_eq!(converter.supply(1), THREE_PHASE);
+        assert_eq!(converter.supply(2).nominal_voltage(), 120.0);
+        assert_eq!(converter.supply(2).number_phases(), 1);
+    }
+
+    #[test]
+    fn test_invalid_supply_is_rejected() {
+        for (nominal_voltage, number_phases) in [(230.0, 0), (0.0, 3), (-230.0, 3), (f64::NAN, 3)] {
+            assert!(matches!(
+                Supply::new(nominal_voltage, number_phases),
+                Err(OcppError::PropertyConstraintViolation(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_zero_phases_are_rejected() {
+        assert!(matches!(
+            THREE_PHASE.convert(
+                11000.0,
+                ChargingRateUnitType::W,
+                ChargingRateUnitType::A,
+                Some(0)
+            ),
+            Err(OcppError::PropertyConstraintViolation(_))
+        ));
+    }
+}
@@ -0,0 +1,583 @@
+//! WebSocket client of a Charge Point, connecting to the Central
+//! System at `<csms-url>/<chargePointId>`.
+//!
+//! The connection is owned by a background task, which reconnects
+//! with an exponential backoff whenever it is lost. Frames sent while
+//! disconnected are held until the connection is established again,
+//! while a frame whose transmission failed is lost.
+
+use std::time::Duration;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::Serialize;
+use tokio::net::TcpStream;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::header::{AUTHORIZATION, SEC_WEBSOCKET_PROTOCOL};
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+
+use super::{percent_encode, serve, Incoming, Sender, Served, SUBPROTOCOL};
+use crate::error::OcppError;
+use crate::frame::{CallError, CallResult, Frame, OcppRequest, Request};
+
+type Stream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Delays between connection attempts, doubling after every failed
+/// attempt up to a maximum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Backoff {
+    initial: Duration,
+    max: Duration,
+}
+
+impl Backoff {
+    pub fn new(initial: Duration, max: Duration) -> Self {
+        Backoff { initial, max }
+    }
+
+    pub fn initial(&self) -> Duration {
+        self.initial
+    }
+
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// Delay before the connection attempt following `failures`
+    /// consecutive failures.
+    pub fn delay(&self, failures: u32) -> Duration {
+        2u32.checked_pow(failures)
+            .and_then(|factor| self.initial.checked_mul(factor))
+            .map_or(self.max, |delay| delay.min(self.max))
+    }
+}
+
+impl Default for Backoff {
+    /// From one second up to one minute.
+    fn default() -> Self {
+        Backoff::new(Duration::from_secs(1), Duration::from_secs(60))
+    }
+}
+
+/// Connection settings of a [`Client`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientConfig {
+    /// URL of the Central System, such as `ws://csms.example.com/ocpp`.
+    csms_url: String,
+    /// Identity of the Charge Point, appended to the URL.
+    charge_point_id: String,
+    /// Password of HTTP Basic authentication, whose user name is the
+    /// identity of the Charge Point.
+    password: Option<String>,
+    backoff: Backoff,
+}
+
+impl ClientConfig {
+    /// Connects to the [`endpoint`](Self::endpoint) of the Charge
+    /// Point without authentication, with the default [`Backoff`].
+    pub fn builder(
+        csms_url: impl Into<String>,
+        charge_point_id: impl Into<String>,
+    ) -> ClientConfigBuilder {
+        ClientConfigBuilder {
+            inner: ClientConfig {
+                csms_url: csms_url.into(),
+                charge_point_id: charge_point_id.into(),
+                password: None,
+                backoff: Backoff::default(),
+            },
+        }
+    }
+
+    pub fn csms_url(&self) -> &str {
+        &self.csms_url
+    }
+
+    pub fn charge_point_id(&self) -> &str {
+        &self.charge_point_id
+    }
+
+    pub fn password(&self) -> Option<&str> {
+        self.password.as_deref()
+    }
+
+    pub fn backoff(&self) -> Backoff {
+        self.backoff
+    }
+
+    /// URL the Charge Point connects to, with its identity
+    /// percent-encoded.
+    pub fn endpoint(&self) -> String {
+        format!(
+            "{}/{}",
+            self.csms_url.trim_end_matches('/'),
+            percent_encode(&self.charge_point_id)
+        )
+    }
+
+    /// Value of the Authorization header, when a password is set.
+    fn authorization(&self) -> Option<String> {
+        self.password.as_ref().map(|password| {
+            let credentials = format!("{}:{}", self.charge_point_id, password);
+            format!("Basic {}", STANDARD.encode(credentials))
+        })
+    }
+}
+
+/// Builder for ClientConfig, created by [`ClientConfig::builder`].
+#[derive(Debug, Clone)]
+pub struct ClientConfigBuilder {
+    inner: ClientConfig,
+}
+
+impl ClientConfigBuilder {
+    pub fn password(mut self, password: impl Into<String>) -> ClientConfigBuilder {
+        self.inner.password = Some(password.into());
+        self
+    }
+
+    pub fn backoff(mut self, backoff: Backoff) -> ClientConfigBuilder {
+        self.inner.backoff = backoff;
+        self
+    }
+
+    /// Finishes the ClientConfig, checking that the URL is a WebSocket
+    /// one and that the identity is not empty.
+    ///
+    /// `wss://` URLs are refused, as the client is built without TLS
+    /// support and could never connect.
+    pub fn build(self) -> Result<ClientConfig, OcppError> {
+        let config = self.inner;
+        if config.csms_url.starts_with("wss://") {
+            return Err(OcppError::NotSupported(format!(
+                "{}: TLS is not supported",
+                config.csms_url
+            )));
+        }
+        if !config.csms_url.starts_with("ws://") {
+            return Err(OcppError::PropertyConstraintViolation(format!(
+                "{}: not a WebSocket URL",
+                config.csms_url
+            )));
+        }
+        if config.charge_point_id.is_empty() {
+            return Err(OcppError::PropertyConstraintViolation(String::from(
+                "empty charge point id",
+            )));
+        }
+
+        Ok(config)
+    }
+}
+
+/// What happened on the connection of a [`Client`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// The connection was established.
+    Connected,
+    /// A connection attempt failed, and will be retried.
+    ConnectFailed(OcppError),
+    /// The established connection was lost, and will be retried.
+    Disconnected,
+    /// The Central System sent a request, to be answered with
+    /// [`Client::send_response`] or [`Client::send_error`]. CALLs that
+    /// cannot be decoded are answered with a CALLERROR by the client.
+    Request { unique_id: String, request: Request },
+    /// The Central System answered a request of the Charge Point.
+    CallResult(CallResult),
+    /// The Central System rejected a request of the Charge Point.
+    CallError(CallError),
+}
+
+impl From<Incoming> for Event {
+    fn from(incoming: Incoming) -> Self {
+        match incoming {
+            Incoming::Request { unique_id, request } => Event::Request { unique_id, request },
+            Incoming::CallResult(result) => Event::CallResult(result),
+            Incoming::CallError(error) => Event::CallError(error),
+        }
+    }
+}
+
+/// WebSocket client of a Charge Point, reconnecting automatically.
+///
+/// Dropping the client closes the connection.
+#[derive(Debug)]
+pub struct Client {
+    sender: Sender,
+    events: UnboundedReceiver<Event>,
+    task: JoinHandle<()>,
+}
+
+impl Client {
+    /// Starts connecting to the Central System. Must be called within
+    /// a Tokio runtime.
+    pub fn connect(config: ClientConfig) -> Client {
+        let (outgoing, outgoing_rx) = mpsc::unbounded_channel();
+        let (events_tx, events) = mpsc::unbounded_channel();
+        let task = tokio::spawn(run(config, outgoing_rx, events_tx));
+
+        Client {
+            sender: Sender::new(outgoing),
+            events,
+            task,
+        }
+    }
+
+    /// Handle sending frames on this connection.
+    pub fn sender(&self) -> Sender {
+        self.sender.clone()
+    }
+
+    /// Sends a raw frame.
+    pub fn send_frame(&self, frame: Frame) -> Result<(), OcppError> {
+        self.sender.send_frame(frame)
+    }
+
+    /// Sends `request` in a CALL identified by `unique_id`.
+    pub fn send_request<R: OcppRequest>(
+        &self,
+        unique_id: impl Into<String>,
+        request: &R,
+    ) -> Result<(), OcppError> {
+        self.sender.send_request(unique_id, request)
+    }
+
+    /// Answers the CALL identified by `unique_id` with `response`.
+    pub fn send_response<T: Serialize>(
+        &self,
+        unique_id: impl Into<String>,
+        response: &T,
+    ) -> Result<(), OcppError> {
+        self.sender.send_response(unique_id, response)
+    }
+
+    /// Rejects the CALL identified by `unique_id` with `error`.
+    pub fn send_error(&self, unique_id: &str, error: &OcppError) -> Result<(), OcppError> {
+        self.sender.send_error(unique_id, error)
+    }
+
+    /// Waits for the next event.
+    pub async fn recv(&mut self) -> Option<Event> {
+        self.events.recv().await
+    }
+}
+
+impl Drop for Client {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Connects, serves the connection and reconnects until the client is
+/// dropped.
+async fn run(
+    config: ClientConfig,
+    mut outgoing: UnboundedReceiver<Frame>,
+    events: UnboundedSender<Event>,
+) {
+    let mut failures = 0;
+    loop {
+        let event = match connect(&config).await {
+            Ok(stream) => {
+                failures = 0;
+                if events.send(Event::Connected).is_err() {
+                    return;
+                }
+                let deliver = |incoming| events.send(Event::from(incoming)).is_ok();
+                let served = serve(stream, &mut outgoing, deliver, std::future::pending()).await;
+                if served == Served::Stopped {
+                    return;
+                }
+                Event::Disconnected
+            }
+            Err(err) => {
+                failures += 1;
+                Event::ConnectFailed(err)
+            }
+        };
+        if events.send(event).is_err() {
+            return;
+        }
+
+        tokio::time::sleep(config.backoff.delay(failures)).await;
+    }
+}
+
+async fn connect(config: &ClientConfig) -> Result<Stream, OcppError> {
+    let endpoint = config.endpoint();
+    let to_error =
+        |err: &dyn std::fmt::Display| OcppError::InternalError(format!("{}: {}", endpoint, err));
+
+    let mut request = endpoint
+        .as_str()
+        .into_client_request()
+        .map_err(|err| to_error(&err))?;
+    let headers = request.headers_mut();
+    headers.insert(
+        SEC_WEBSOCKET_PROTOCOL,
+        HeaderValue::from_static(SUBPROTOCOL),
+    );
+    if let Some(authorization) = config.authorization() {
+        let value = HeaderValue::from_str(&authorization).map_err(|err| to_error(&err))?;
+        headers.insert(AUTHORIZATION, value);
+    }
+
+    let (stream, response) = connect_async(request).await.map_err(|err| to_error(&err))?;
+    if response.headers().get(SEC_WEBSOCKET_PROTOCOL)
+        != Some(&HeaderValue::from_static(SUBPROTOCOL))
+    {
+        return Err(to_error(&format!("subprotocol {} refused", SUBPROTOCOL)));
+    }
+
+    Ok(stream)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::error::ErrorCode;
+    use crate::messages::heartbeat::HeartbeatReq;
+    use crate::messages::reset::{ResetConf, ResetReq};
+    use crate::types::enumerations::{ResetStatus, ResetType};
+    use futures_util::{SinkExt, StreamExt};
+    use tokio::net::TcpListener;
+    use tokio::sync::oneshot;
+    use tokio::time::timeout;
+    use tokio_tungstenite::tungstenite::handshake::server::{
+        ErrorResponse, Request as HttpRequest, Response as HttpResponse,
+    };
+    use tokio_tungstenite::tungstenite::Message;
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    async fn listen() -> (TcpListener, String) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/ocpp", listener.local_addr().unwrap());
+        (listener, url)
+    }
+
+    /// Accepts a connection, answering with `subprotocol`, and returns
+    /// the upgrade request of the client.
+    // The callback signature is imposed by tungstenite.
+    #[allow(clippy::result_large_err)]
+    async fn accept(
+        listener: &TcpListener,
+        subprotocol: Option<&'static str>,
+    ) -> (WebSocketStream<TcpStream>, HttpRequest) {
+        let (tcp, _) = listener.accept().await.unwrap();
+        let (request_tx, request_rx) = oneshot::channel();
+        let callback = move |request: &HttpRequest, mut response: HttpResponse| {
+            let _ = request_tx.send(request.clone());
+            if let Some(subprotocol) = subprotocol {
+                response.headers_mut().insert(
+                    SEC_WEBSOCKET_PROTOCOL,
+                    HeaderValue::from_static(subprotocol),
+                );
+            }
+            Ok::<_, ErrorResponse>(response)
+        };
+        let stream = tokio_tungstenite::accept_hdr_async(tcp, callback)
+            .await
+            .unwrap();
+
+        (stream, request_rx.await.unwrap())
+    }
+
+    fn config(url: &str) -> ClientConfig {
+        ClientConfig::builder(url, "CP 01")
+            .backoff(Backoff::new(
+                Duration::from_millis(10),
+                Duration::from_millis(50),
+            ))
+            .build()
+            .unwrap()
+    }
+
+    async fn next_event(client: &mut Client) -> Event {
+        timeout(TIMEOUT, client.recv()).await.unwrap().unwrap()
+    }
+
+    async fn next_text(stream: &mut WebSocketStream<TcpStream>) -> String {
+        match timeout(TIMEOUT, stream.next()).await.unwrap() {
+            Some(Ok(Message::Text(text))) => text.to_string(),
+            message => panic!("expected a text message, got {:?}", message),
+        }
+    }
+
+    #[test]
+    fn test_endpoint() {
+        let config = ClientConfig::builder("ws://csms.example.com/ocpp/", "CP 01/é")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            config.endpoint(),
+            "ws://csms.example.com/ocpp/CP%2001%2F%C3%A9"
+        );
+    }
+
+    #[test]
+    fn test_basic_authorization() {
+        let config = ClientConfig::builder("ws://csms.example.com", "CP01")
+            .password("secret")
+            .build()
+            .unwrap();
+
+        // base64("CP01:secret")
+        assert_eq!(
+            config.authorization().as_deref(),
+            Some("Basic Q1AwMTpzZWNyZXQ=")
+        );
+    }
+
+    #[test]
+    fn test_config_requires_websocket_url() {
+        let err = ClientConfig::builder("http://csms.example.com", "CP01")
+            .build()
+            .unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::PropertyConstraintViolation);
+    }
+
+    #[test]
+    fn test_config_refuses_secure_url() {
+        let err = ClientConfig::builder("wss://csms.example.com", "CP01")
+            .password("secret")
+            .build()
+            .unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::NotSupported);
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_max() {
+        let backoff = Backoff::new(Duration::from_secs(1), Duration::from_secs(10));
+
+        assert_eq!(backoff.delay(0), Duration::from_secs(1));
+        assert_eq!(backoff.delay(3), Duration::from_secs(8));
+        assert_eq!(backoff.delay(4), Duration::from_secs(10));
+        assert_eq!(backoff.delay(64), Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn test_handshake() {
+        let (listener, url) = listen().await;
+        let config = ClientConfig::builder(url, "CP01")
+            .password("secret")
+            .build()
+            .unwrap();
+        let mut client = Client::connect(config);
+
+        let (_stream, request) = accept(&listener, Some(SUBPROTOCOL)).await;
+
+        assert_eq!(next_event(&mut client).await, Event::Connected);
+        assert_eq!(request.uri().path(), "/ocpp/CP01");
+        assert_eq!(
+            request.headers().get(SEC_WEBSOCKET_PROTOCOL).unwrap(),
+            SUBPROTOCOL
+        );
+        assert_eq!(
+            request.headers().get(AUTHORIZATION).unwrap(),
+            "Basic Q1AwMTpzZWNyZXQ="
+        );
+    }
+
+    #[tokio::test]
+    async fn test_refused_subprotocol() {
+        let (listener, url) = listen().await;
+        let mut client = Client::connect(config(&url));
+
+        accept(&listener, None).await;
+
+        assert!(matches!(
+            next_event(&mut client).await,
+            Event::ConnectFailed(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_exchange_typed_messages() {
+        let (listener, url) = listen().await;
+        let mut client = Client::connect(config(&url));
+        let (mut stream, _) = accept(&listener, Some(SUBPROTOCOL)).await;
+        assert_eq!(next_event(&mut client).await, Event::Connected);
+
+        client.send_request("1", &HeartbeatReq::new()).unwrap();
+        let call = next_text(&mut stream).await;
+        stream
+            .send(Message::text(
+                r#"[3,"1",{"currentTime":"2024-03-04T12:00:00Z"}]"#,
+            ))
+            .await
+            .unwrap();
+        let result = next_event(&mut client).await;
+        stream
+            .send(Message::text(r#"[2,"7","Reset",{"type":"Soft"}]"#))
+            .await
+            .unwrap();
+        let request = next_event(&mut client).await;
+        client
+            .send_response("7", &ResetConf::new(ResetStatus::Accepted))
+            .unwrap();
+        let response = next_text(&mut stream).await;
+
+        assert_eq!(call, r#"[2,"1","Heartbeat",{}]"#);
+        match result {
+            Event::CallResult(result) => {
+                result.decode::<HeartbeatReq>().unwrap();
+            }
+            event => panic!("expected a CALLRESULT, got {:?}", event),
+        }
+        assert_eq!(
+            request,
+            Event::Request {
+                unique_id: String::from("7"),
+                request: Request::Reset(ResetReq::new(ResetType::Soft)),
+            }
+        );
+        assert_eq!(response, r#"[3,"7",{"status":"Accepted"}]"#);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_call_is_answered_with_call_error() {
+        let (listener, url) = listen().await;
+        let mut client = Client::connect(config(&url));
+        let (mut stream, _) = accept(&listener, Some(SUBPROTOCOL)).await;
+        assert_eq!(next_event(&mut client).await, Event::Connected);
+
+        stream
+            .send(Message::text(r#"[2,"8","Reboot",{}]"#))
+            .await
+            .unwrap();
+        let error = Frame::parse(&next_text(&mut stream).await).unwrap();
+
+        match error {
+            Frame::CallError(error) => {
+                assert_eq!(error.unique_id(), "8");
+                assert_eq!(error.error_code(), ErrorCode::NotImplemented);
+            }
+            frame => panic!("expected a CALLERROR, got {:?}", frame),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reconnects_after_disconnection() {
+        let (listener, url) = listen().await;
+        let mut client = Client::connect(config(&url));
+        let (stream, _) = accept(&listener, Some(SUBPROTOCOL)).await;
+        assert_eq!(next_event(&mut client).await, Event::Connected);
+
+        drop(stream);
+        assert_eq!(next_event(&mut client).await, Event::Disconnected);
+        client.send_request("2", &HeartbeatReq::new()).unwrap();
+        let (mut stream, _) = accept(&listener, Some(SUBPROTOCOL)).await;
+
+        assert_eq!(next_event(&mut client).await, Event::Connected);
+        assert_eq!(next_text(&mut stream).await, r#"[2,"2","Heartbeat",{}]"#);
+    }
+}
@@ -0,0 +1,385 @@
+//! Correlation of the CALLs sent to the peer with their answers.
+//!
+//! OCPP-J allows a single outstanding CALL per direction: a request is
+//! only sent once the previous one was answered by a CALLRESULT or a
+//! CALLERROR carrying its unique id, or timed out.
+//!
+//! Unique ids are a counter prefixed with a random nonce drawn by every
+//! correlator, so that they are not reused across reconnections and
+//! restarts.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use tokio::sync::oneshot;
+
+use super::Sender;
+use crate::error::OcppError;
+use crate::frame::{CallError, CallResult, Frame, OcppRequest};
+
+/// Sends requests to the peer and awaits their typed answers.
+///
+/// The CALLRESULTs and CALLERRORs received from the peer must be handed
+/// to [`Correlator::on_call_result`] and [`Correlator::on_call_error`].
+/// The correlator can be cloned, so that requests are sent from other
+/// tasks than the one receiving the messages.
+#[derive(Debug, Clone)]
+pub struct Correlator {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    sender: Sender,
+    timeout: Duration,
+    /// Random prefix of the unique ids of this correlator.
+    nonce: u64,
+    next_id: AtomicU64,
+    /// Held while a CALL is outstanding, queuing the next ones.
+    turn: tokio::sync::Mutex<()>,
+    outstanding: Mutex<Option<Outstanding>>,
+}
+
+#[derive(Debug)]
+struct Outstanding {
+    unique_id: String,
+    answer: oneshot::Sender<Answer>,
+}
+
+#[derive(Debug)]
+enum Answer {
+    Result(CallResult),
+    Error(CallError),
+}
+
+/// Answer received from the peer that matches no outstanding CALL.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Unmatched {
+    /// No CALL was outstanding, e.g. because it timed out.
+    Orphaned(Frame),
+    /// Another CALL, identified by `expected`, was outstanding.
+    Mismatched { expected: String, frame: Frame },
+}
+
+/// Clears the outstanding CALL when its sending completes, times out or
+/// is cancelled.
+struct OutstandingGuard<'a> {
+    inner: &'a Inner,
+    unique_id: String,
+}
+
+impl Drop for OutstandingGuard<'_> {
+    fn drop(&mut self) {
+        let mut outstanding = self.inner.outstanding.lock().unwrap();
+        if outstanding
+            .as_ref()
+            .is_some_and(|outstanding| outstanding.unique_id == self.unique_id)
+        {
+            *outstanding = None;
+        }
+    }
+}
+
+impl Correlator {
+    /// Default time waited for the answer of the peer.
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+    /// Sends the requests through `sender`, waiting at most `timeout`
+    /// for each answer.
+    pub fn new(sender: Sender, timeout: Duration) -> Correlator {
+        Correlator {
+            inner: Arc::new(Inner {
+                sender,
+                timeout,
+                nonce: nonce(),
+                next_id: AtomicU64::new(1),
+                turn: tokio::sync::Mutex::new(()),
+                outstanding: Mutex::new(None),
+            }),
+        }
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.inner.timeout
+    }
+
+    /// Sends `request` in a CALL with a new unique id and waits for its
+    /// answer.
+    ///
+    /// The request is queued until the previous ones are answered, the
+    /// timeout only running once it is sent. A CALLERROR of the peer is
+    /// returned as the matching [`OcppError`], and a CALLRESULT whose
+    /// payload does not decode as `R::Response` as the decoding error.
+    pub async fn send<R: OcppRequest>(&self, request: &R) -> Result<R::Response, OcppError> {
+        let inner = &*self.inner;
+        let _turn = inner.turn.lock().await;
+
+        let unique_id = format!(
+            "{:016x}-{}",
+            inner.nonce,
+            inner.next_id.fetch_add(1, Ordering::Relaxed)
+        );
+        let (answer, answered) = oneshot::channel();
+        *inner.outstanding.lock().unwrap() = Some(Outstanding {
+            unique_id: unique_id.clone(),
+            answer,
+        });
+        let _outstanding = OutstandingGuard {
+            inner,
+            unique_id: unique_id.clone(),
+        };
+        inner.sender.send_request(unique_id.as_str(), request)?;
+
+        match tokio::time::timeout(inner.timeout, answered).await {
+            Ok(Ok(Answer::Result(result))) => result.decode::<R>(),
+            Ok(Ok(Answer::Error(error))) => Err(OcppError::from(&error)),
+            Ok(Err(_)) => Err(OcppError::InternalError(String::from("correlator dropped"))),
+            Err(_) => Err(OcppError::InternalError(format!(
+                "{} {}: no answer within {:?}",
+                R::ACTION,
+                unique_id,
+                inner.timeout
+            ))),
+        }
+    }
+
+    /// Completes the outstanding CALL answered by `result`.
+    pub fn on_call_result(&self, result: CallResult) -> Result<(), Unmatched> {
+        self.answer(result.unique_id().to_string(), Answer::Result(result))
+    }
+
+    /// Fails the outstanding CALL rejected by `error`.
+    pub fn on_call_error(&self, error: CallError) -> Result<(), Unmatched> {
+        self.answer(error.unique_id().to_string(), Answer::Error(error))
+    }
+
+    fn answer(&self, unique_id: String, answer: Answer) -> Result<(), Unmatched> {
+        let mut outstanding = self.inner.outstanding.lock().unwrap();
+        match outstanding.take() {
+            Some(call) if call.unique_id == unique_id => {
+                // The sending task may just have timed out.
+                let _ = call.answer.send(answer);
+                Ok(())
+            }
+            Some(call) => {
+                let expected = call.unique_id.clone();
+                *outstanding = Some(call);
+                Err(Unmatched::Mismatched {
+                    expected,
+                    frame: answer.into(),
+                })
+            }
+            None => Err(Unmatched::Orphaned(answer.into())),
+        }
+    }
+}
+
+/// Random nonce, or the current time when the system provides no
+/// randomness.
+fn nonce() -> u64 {
+    getrandom::u64().unwrap_or_else(|_| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+    })
+}
+
+impl From<Answer> for Frame {
+    fn from(answer: Answer) -> Frame {
+        match answer {
+            Answer::Result(result) => result.into(),
+            Answer::Error(error) => error.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::error::ErrorCode;
+    use crate::frame::Call;
+    use crate::messages::authorize::{AuthorizeConf, AuthorizeReq};
+    use crate::messages::heartbeat::HeartbeatReq;
+    use crate::types::authorization::{IdTagInfo, IdToken};
+    use crate::types::enumerations::AuthorizationStatus;
+    use serde_json::json;
+    use tokio::sync::mpsc::{self, UnboundedReceiver};
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    fn correlator(timeout: Duration) -> (Correlator, UnboundedReceiver<Frame>) {
+        let (outgoing, outgoing_rx) = mpsc::unbounded_channel();
+        (Correlator::new(Sender::new(outgoing), timeout), outgoing_rx)
+    }
+
+    async fn next_call(outgoing: &mut UnboundedReceiver<Frame>) -> Call {
+        match tokio::time::timeout(TIMEOUT, outgoing.recv()).await {
+            Ok(Some(Frame::Call(call))) => call,
+            frame => panic!("expected a CALL, got {:?}", frame),
+        }
+    }
+
+    fn authorize_req() -> AuthorizeReq {
+        AuthorizeReq::new(IdToken::try_from("TAG01").unwrap())
+    }
+
+    fn authorize_conf() -> AuthorizeConf {
+        AuthorizeConf::new(
+            IdTagInfo::builder(AuthorizationStatus::Accepted)
+                .build()
+                .unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_send_returns_typed_response() {
+        let (correlator, mut outgoing) = correlator(TIMEOUT);
+        let sending = tokio::spawn({
+            let correlator = correlator.clone();
+            async move { correlator.send(&authorize_req()).await }
+        });
+
+        let call = next_call(&mut outgoing).await;
+        let answered =
+            correlator.on_call_result(CallResult::new(call.unique_id(), &authorize_conf()));
+
+        assert_eq!(call.action(), "Authorize");
+        assert_eq!(answered, Ok(()));
+        assert_eq!(sending.await.unwrap(), Ok(authorize_conf()));
+    }
+
+    #[tokio::test]
+    async fn test_call_error_is_returned() {
+        let (correlator, mut outgoing) = correlator(TIMEOUT);
+        let sending = tokio::spawn({
+            let correlator = correlator.clone();
+            async move { correlator.send(&authorize_req()).await }
+        });
+
+        let call = next_call(&mut outgoing).await;
+        let error = OcppError::NotSupported(String::from("Authorize"));
+        correlator
+            .on_call_error(error.to_call_error(call.unique_id()))
+            .unwrap();
+
+        assert_eq!(sending.await.unwrap(), Err(error));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_response_is_reported() {
+        let (correlator, mut outgoing) = correlator(TIMEOUT);
+        let sending = tokio::spawn({
+            let correlator = correlator.clone();
+            async move { correlator.send(&authorize_req()).await }
+        });
+
+        let call = next_call(&mut outgoing).await;
+        correlator
+            .on_call_result(CallResult::new(call.unique_id(), &json!({})))
+            .unwrap();
+
+        let err = sending.await.unwrap().unwrap_err();
+        assert_eq!(err.code(), ErrorCode::OccurenceConstraintViolation);
+    }
+
+    #[tokio::test]
+    async fn test_timeout_orphans_late_result() {
+        let (correlator, mut outgoing) = correlator(Duration::from_millis(20));
+
+        let result = correlator.send(&HeartbeatReq::new()).await;
+        let call = next_call(&mut outgoing).await;
+        let late = CallResult::new(call.unique_id(), &authorize_conf());
+
+        assert_eq!(result.unwrap_err().code(), ErrorCode::InternalError);
+        assert_eq!(
+            correlator.on_call_result(late.clone()),
+            Err(Unmatched::Orphaned(late.into()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mismatched_result_is_reported() {
+        let (correlator, mut outgoing) = correlator(TIMEOUT);
+        let sending = tokio::spawn({
+            let correlator = correlator.clone();
+            async move { correlator.send(&authorize_req()).await }
+        });
+
+        let call = next_call(&mut outgoing).await;
+        let other = CallResult::new("other", &authorize_conf());
+        let mismatched = correlator.on_call_result(other.clone());
+        correlator
+            .on_call_result(CallResult::new(call.unique_id(), &authorize_conf()))
+            .unwrap();
+
+        assert_eq!(
+            mismatched,
+            Err(Unmatched::Mismatched {
+                expected: call.unique_id().to_string(),
+                frame: other.into(),
+            })
+        );
+        assert_eq!(sending.await.unwrap(), Ok(authorize_conf()));
+    }
+
+    #[tokio::test]
+    async fn test_calls_are_sent_one_at_a_time() {
+        let (correlator, mut outgoing) = correlator(TIMEOUT);
+        let first = tokio::spawn({
+            let correlator = correlator.clone();
+            async move { correlator.send(&authorize_req()).await }
+        });
+        let second = tokio::spawn({
+            let correlator = correlator.clone();
+            async move { correlator.send(&authorize_req()).await }
+        });
+
+        let first_call = next_call(&mut outgoing).await;
+        tokio::task::yield_now().await;
+        let queued = outgoing.try_recv();
+        correlator
+            .on_call_result(CallResult::new(first_call.unique_id(), &authorize_conf()))
+            .unwrap();
+        let second_call = next_call(&mut outgoing).await;
+        correlator
+            .on_call_result(CallResult::new(second_call.unique_id(), &authorize_conf()))
+            .unwrap();
+
+        assert!(queued.is_err());
+        assert_ne!(first_call.unique_id(), second_call.unique_id());
+        assert_eq!(first.await.unwrap(), Ok(authorize_conf()));
+        assert_eq!(second.await.unwrap(), Ok(authorize_conf()));
+    }
+
+    #[tokio::test]
+    async fn test_send_on_closed_connection() {
+        let (correlator, outgoing) = correlator(TIMEOUT);
+        drop(outgoing);
+
+        let err = correlator.send(&HeartbeatReq::new()).await.unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::InternalError);
+        assert_eq!(
+            correlator.on_call_result(CallResult::new("1", &authorize_conf())),
+            Err(Unmatched::Orphaned(
+                CallResult::new("1", &authorize_conf()).into()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unique_ids_differ_between_correlators() {
+        let (first, mut first_outgoing) = correlator(Duration::from_millis(1));
+        let (second, mut second_outgoing) = correlator(Duration::from_millis(1));
+
+        let _ = first.send(&HeartbeatReq::new()).await;
+        let _ = second.send(&HeartbeatReq::new()).await;
+        let first_call = next_call(&mut first_outgoing).await;
+        let second_call = next_call(&mut second_outgoing).await;
+
+        assert_ne!(first_call.unique_id(), second_call.unique_id());
+        assert!(first_call.unique_id().len() <= 36);
+    }
+}
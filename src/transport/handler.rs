@@ -0,0 +1,530 @@
+//! Handling of the requests a Charge Point sends to the Central System.
+//!
+//! The Central System implements [`CentralSystemHandler`], whose methods
+//! receive the decoded requests and return the matching confirmations,
+//! and [`serve_session`] answers the requests received on a
+//! [`Session`].
+
+use std::future::{self, Future};
+
+use futures_util::stream::{FuturesUnordered, StreamExt};
+
+use super::correlator::Correlator;
+use super::server::Session;
+use super::Incoming;
+use crate::error::OcppError;
+use crate::frame::{CallResult, Frame, OcppRequest, Request};
+use crate::messages::authorize::{AuthorizeConf, AuthorizeReq};
+use crate::messages::boot_notification::{BootNotificationConf, BootNotificationReq};
+use crate::messages::data_transfer::{DataTransferConf, DataTransferReq};
+use crate::messages::diagnostics_status_notification::{
+    DiagnosticsStatusNotificationConf, DiagnosticsStatusNotificationReq,
+};
+use crate::messages::firmware_status_notification::{
+    FirmwareStatusNotificationConf, FirmwareStatusNotificationReq,
+};
+use crate::messages::heartbeat::{HeartbeatConf, HeartbeatReq};
+use crate::messages::meter_values::{MeterValuesConf, MeterValuesReq};
+use crate::messages::start_transaction::{StartTransactionConf, StartTransactionReq};
+use crate::messages::status_notification::{StatusNotificationConf, StatusNotificationReq};
+use crate::messages::stop_transaction::{StopTransactionConf, StopTransactionReq};
+
+/// Business logic of the Central System, with one method per action
+/// initiated by a Charge Point.
+///
+/// Each method receives the identity of the Charge Point and its
+/// request, and returns the confirmation, or the error answered in a
+/// CALLERROR. The methods that are not implemented answer with
+/// NotImplemented. They can be implemented with `async fn`.
+pub trait CentralSystemHandler: Send + Sync {
+    fn on_authorize(
+        &self,
+        charge_point_id: &str,
+        request: AuthorizeReq,
+    ) -> impl Future<Output = Result<AuthorizeConf, OcppError>> + Send {
+        let _ = charge_point_id;
+        not_implemented(request)
+    }
+
+    fn on_boot_notification(
+        &self,
+        charge_point_id: &str,
+        request: BootNotificationReq,
+    ) -> impl Future<Output = Result<BootNotificationConf, OcppError>> + Send {
+        let _ = charge_point_id;
+        not_implemented(request)
+    }
+
+    fn on_data_transfer(
+        &self,
+        charge_point_id: &str,
+        request: DataTransferReq,
+    ) -> impl Future<Output = Result<DataTransferConf, OcppError>> + Send {
+        let _ = charge_point_id;
+        not_implemented(request)
+    }
+
+    fn on_diagnostics_status_notification(
+        &self,
+        charge_point_id: &str,
+        request: DiagnosticsStatusNotificationReq,
+    ) -> impl Future<Output = Result<DiagnosticsStatusNotificationConf, OcppError>> + Send {
+        let _ = charge_point_id;
+        not_implemented(request)
+    }
+
+    fn on_firmware_status_notification(
+        &self,
+        charge_point_id: &str,
+        request: FirmwareStatusNotificationReq,
+    ) -> impl Future<Output = Result<FirmwareStatusNotificationConf, OcppError>> + Send {
+        let _ = charge_point_id;
+        not_implemented(request)
+    }
+
+    fn on_heartbeat(
+        &self,
+        charge_point_id: &str,
+        request: HeartbeatReq,
+    ) -> impl Future<Output = Result<HeartbeatConf, OcppError>> + Send {
+        let _ = charge_point_id;
+        not_implemented(request)
+    }
+
+    fn on_meter_values(
+        &self,
+        charge_point_id: &str,
+        request: MeterValuesReq,
+    ) -> impl Future<Output = Result<MeterValuesConf, OcppError>> + Send {
+        let _ = charge_point_id;
+        not_implemented(request)
+    }
+
+    fn on_start_transaction(
+        &self,
+        charge_point_id: &str,
+        request: StartTransactionReq,
+    ) -> impl Future<Output = Result<StartTransactionConf, OcppError>> + Send {
+        let _ = charge_point_id;
+        not_implemented(request)
+    }
+
+    fn on_status_notification(
+        &self,
+        charge_point_id: &str,
+        request: StatusNotificationReq,
+    ) -> impl Future<Output = Result<StatusNotificationConf, OcppError>> + Send {
+        let _ = charge_point_id;
+        not_implemented(request)
+    }
+
+    fn on_stop_transaction(
+        &self,
+        charge_point_id: &str,
+        request: StopTransactionReq,
+    ) -> impl Future<Output = Result<StopTransactionConf, OcppError>> + Send {
+        let _ = charge_point_id;
+        not_implemented(request)
+    }
+}
+
+fn not_implemented<R: OcppRequest>(
+    _request: R,
+) -> impl Future<Output = Result<R::Response, OcppError>> + Send
+where
+    R::Response: Send,
+{
+    future::ready(Err(OcppError::NotImplemented(format!(
+        "{}: not implemented",
+        R::ACTION
+    ))))
+}
+
+/// Routes `request`, received from `charge_point_id` in the CALL
+/// identified by `unique_id`, to `handler`, and returns the CALLRESULT
+/// or CALLERROR answering it.
+///
+/// Requests that only the Central System sends are answered with
+/// NotSupported.
+pub async fn handle<H: CentralSystemHandler>(
+    handler: &H,
+    charge_point_id: &str,
+    unique_id: &str,
+    request: Request,
+) -> Frame {
+    let id = charge_point_id;
+    let result = match request {
+        Request::Authorize(request) => answer(unique_id, handler.on_authorize(id, request).await),
+        Request::BootNotification(request) => {
+            answer(unique_id, handler.on_boot_notification(id, request).await)
+        }
+        Request::DataTransfer(request) => {
+            answer(unique_id, handler.on_data_transfer(id, request).await)
+        }
+        Request::DiagnosticsStatusNotification(request) => answer(
+            unique_id,
+            handler
+                .on_diagnostics_status_notification(id, request)
+                .await,
+        ),
+        Request::FirmwareStatusNotification(request) => answer(
+            unique_id,
+            handler.on_firmware_status_notification(id, request).await,
+        ),
+        Request::Heartbeat(request) => answer(unique_id, handler.on_heartbeat(id, request).await),
+        Request::MeterValues(request) => {
+            answer(unique_id, handler.on_meter_values(id, request).await)
+        }
+        Request::StartTransaction(request) => {
+            answer(unique_id, handler.on_start_transaction(id, request).await)
+        }
+        Request::StatusNotification(request) => {
+            answer(unique_id, handler.on_status_notification(id, request).await)
+        }
+        Request::StopTransaction(request) => {
+            answer(unique_id, handler.on_stop_transaction(id, request).await)
+        }
+        request => Err(OcppError::NotSupported(format!(
+            "{}: not sent by a Charge Point",
+            request.action()
+        ))),
+    };
+
+    match result {
+        Ok(result) => result.into(),
+        Err(err) => err.to_call_error(unique_id).into(),
+    }
+}
+
+fn answer<T: serde::Serialize>(
+    unique_id: &str,
+    result: Result<T, OcppError>,
+) -> Result<CallResult, OcppError> {
+    result.map(|response| CallResult::new(unique_id, &response))
+}
+
+/// Answers the requests received on `session` with `handler`, until
+/// the connection is closed.
+///
+/// The requests are handled concurrently with the reception of the
+/// next messages, so that a handler can itself send requests to the
+/// Charge Point through `correlator` and await their answers. Those
+/// answers are handed to `correlator`, the ones matching no outstanding
+/// CALL being dropped.
+///
+/// Once the connection is closed, the requests being handled still run
+/// to completion, so that their business logic is not cut short, and
+/// their answers are discarded.
+pub async fn serve_session<H: CentralSystemHandler>(
+    handler: &H,
+    session: &mut Session,
+    correlator: &Correlator,
+) {
+    let charge_point_id = session.charge_point_id().to_string();
+    let charge_point_id = charge_point_id.as_str();
+    let sender = session.sender();
+    let mut handling = FuturesUnordered::new();
+    loop {
+        tokio::select! {
+            incoming = session.recv() => match incoming {
+                Some(Incoming::Request { unique_id, request }) => {
+                    handling.push(async move {
+                        handle(handler, charge_point_id, &unique_id, request).await
+                    });
+                }
+                Some(Incoming::CallResult(result)) => {
+                    let _ = correlator.on_call_result(result);
+                }
+                Some(Incoming::CallError(error)) => {
+                    let _ = correlator.on_call_error(error);
+                }
+                None => break,
+            },
+            Some(answer) = handling.next(), if !handling.is_empty() => {
+                if sender.send_frame(answer).is_err() {
+                    break;
+                }
+            }
+        }
+    }
+
+    while handling.next().await.is_some() {}
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::error::ErrorCode;
+    use crate::messages::get_configuration::{GetConfigurationConf, GetConfigurationReq};
+    use crate::messages::reset::{ResetConf, ResetReq};
+    use crate::transport::client::{Client, ClientConfig, Event};
+    use crate::transport::server::{DuplicatePolicy, Server, ServerConfig};
+    use crate::types::authorization::{IdTagInfo, IdToken};
+    use crate::types::enumerations::AuthorizationStatus;
+    use crate::types::enumerations::{
+        ChargePointErrorCode, ChargePointStatus, RegistrationStatus, ResetStatus, ResetType,
+    };
+    use crate::types::utils::CiString20Type;
+    use chrono::{DateTime, TimeZone, Utc};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::sync::Notify;
+    use tokio::time::timeout;
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap()
+    }
+
+    struct Backend;
+
+    impl CentralSystemHandler for Backend {
+        async fn on_boot_notification(
+            &self,
+            charge_point_id: &str,
+            _request: BootNotificationReq,
+        ) -> Result<BootNotificationConf, OcppError> {
+            let status = if charge_point_id == "CP01" {
+                RegistrationStatus::Accepted
+            } else {
+                RegistrationStatus::Rejected
+            };
+            Ok(BootNotificationConf::new(now(), 300, status))
+        }
+
+        async fn on_heartbeat(
+            &self,
+            _charge_point_id: &str,
+            _request: HeartbeatReq,
+        ) -> Result<HeartbeatConf, OcppError> {
+            Ok(HeartbeatConf::new(now()))
+        }
+    }
+
+    fn boot_notification() -> BootNotificationReq {
+        BootNotificationReq::builder(
+            CiString20Type::try_from("ACME").unwrap(),
+            CiString20Type::try_from("Model 1").unwrap(),
+        )
+        .build()
+        .unwrap()
+    }
+
+    fn call_result(frame: Frame) -> CallResult {
+        match frame {
+            Frame::CallResult(result) => result,
+            frame => panic!("expected a CALLRESULT, got {:?}", frame),
+        }
+    }
+
+    fn call_error_code(frame: Frame) -> ErrorCode {
+        match frame {
+            Frame::CallError(error) => error.error_code(),
+            frame => panic!("expected a CALLERROR, got {:?}", frame),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_routes_request_to_handler() {
+        let frame = handle(&Backend, "CP01", "1", boot_notification().into()).await;
+
+        let result = call_result(frame);
+        let conf = result.decode::<BootNotificationReq>().unwrap();
+        assert_eq!(result.unique_id(), "1");
+        assert_eq!(conf.status(), RegistrationStatus::Accepted);
+    }
+
+    #[tokio::test]
+    async fn test_unimplemented_action() {
+        let request = AuthorizeReq::new(CiString20Type::try_from("TAG01").unwrap());
+
+        let frame = handle(&Backend, "CP01", "1", request.into()).await;
+
+        assert_eq!(call_error_code(frame), ErrorCode::NotImplemented);
+    }
+
+    #[tokio::test]
+    async fn test_central_system_action() {
+        let frame = handle(&Backend, "CP01", "1", ResetReq::new(ResetType::Soft).into()).await;
+
+        assert_eq!(call_error_code(frame), ErrorCode::NotSupported);
+    }
+
+    #[tokio::test]
+    async fn test_serve_session() {
+        let mut server = Server::bind("127.0.0.1:0", ServerConfig::new(DuplicatePolicy::Reject))
+            .await
+            .unwrap();
+        let url = format!("ws://{}/ocpp", server.local_addr());
+        let mut client = Client::connect(ClientConfig::builder(url, "CP01").build().unwrap());
+        let mut session = timeout(TIMEOUT, server.accept()).await.unwrap().unwrap();
+        let correlator = Correlator::new(session.sender(), TIMEOUT);
+        let serving = tokio::spawn({
+            let correlator = correlator.clone();
+            async move { serve_session(&Backend, &mut session, &correlator).await }
+        });
+        assert_eq!(
+            timeout(TIMEOUT, client.recv()).await.unwrap(),
+            Some(Event::Connected)
+        );
+
+        client.send_request("1", &boot_notification()).unwrap();
+        let boot = timeout(TIMEOUT, client.recv()).await.unwrap();
+        let reset = tokio::spawn({
+            let correlator = correlator.clone();
+            async move { correlator.send(&ResetReq::new(ResetType::Hard)).await }
+        });
+        let request = timeout(TIMEOUT, client.recv()).await.unwrap();
+        let Some(Event::Request { unique_id, .. }) = request else {
+            panic!("expected a request, got {:?}", request);
+        };
+        client
+            .send_response(unique_id, &ResetConf::new(ResetStatus::Accepted))
+            .unwrap();
+
+        match boot {
+            Some(Event::CallResult(result)) => {
+                let conf = result.decode::<BootNotificationReq>().unwrap();
+                assert_eq!(conf.status(), RegistrationStatus::Accepted);
+            }
+            event => panic!("expected a CALLRESULT, got {:?}", event),
+        }
+        assert_eq!(
+            timeout(TIMEOUT, reset).await.unwrap().unwrap(),
+            Ok(ResetConf::new(ResetStatus::Accepted))
+        );
+        drop(client);
+        timeout(TIMEOUT, serving).await.unwrap().unwrap();
+    }
+
+    /// Handler querying the configuration of the Charge Point while
+    /// handling its StatusNotification.req.
+    struct Configurator {
+        correlator: Correlator,
+    }
+
+    impl CentralSystemHandler for Configurator {
+        async fn on_status_notification(
+            &self,
+            _charge_point_id: &str,
+            _request: StatusNotificationReq,
+        ) -> Result<StatusNotificationConf, OcppError> {
+            let request = GetConfigurationReq::builder().build()?;
+            self.correlator.send(&request).await?;
+            Ok(StatusNotificationConf::new())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handler_awaits_central_system_request() {
+        let mut server = Server::bind("127.0.0.1:0", ServerConfig::new(DuplicatePolicy::Reject))
+            .await
+            .unwrap();
+        let url = format!("ws://{}/ocpp", server.local_addr());
+        let mut client = Client::connect(ClientConfig::builder(url, "CP01").build().unwrap());
+        let mut session = timeout(TIMEOUT, server.accept()).await.unwrap().unwrap();
+        let correlator = Correlator::new(session.sender(), TIMEOUT);
+        tokio::spawn(async move {
+            let handler = Configurator {
+                correlator: correlator.clone(),
+            };
+            serve_session(&handler, &mut session, &correlator).await
+        });
+        assert_eq!(
+            timeout(TIMEOUT, client.recv()).await.unwrap(),
+            Some(Event::Connected)
+        );
+
+        let status = StatusNotificationReq::builder(
+            1,
+            ChargePointErrorCode::NoError,
+            ChargePointStatus::Available,
+        )
+        .build()
+        .unwrap();
+        client.send_request("1", &status).unwrap();
+        let request = timeout(TIMEOUT, client.recv()).await.unwrap();
+        let Some(Event::Request { unique_id, .. }) = request else {
+            panic!("expected a request, got {:?}", request);
+        };
+        let conf = GetConfigurationConf::builder().build().unwrap();
+        client.send_response(unique_id, &conf).unwrap();
+        let answer = timeout(TIMEOUT, client.recv()).await.unwrap();
+
+        match answer {
+            Some(Event::CallResult(result)) => {
+                assert_eq!(result.unique_id(), "1");
+                result.decode::<StatusNotificationReq>().unwrap();
+            }
+            event => panic!("expected a CALLRESULT, got {:?}", event),
+        }
+    }
+
+    /// Handler storing transactions until released.
+    #[derive(Default)]
+    struct SlowStore {
+        started: Notify,
+        release: Notify,
+        stored: AtomicBool,
+    }
+
+    impl CentralSystemHandler for SlowStore {
+        async fn on_start_transaction(
+            &self,
+            _charge_point_id: &str,
+            _request: StartTransactionReq,
+        ) -> Result<StartTransactionConf, OcppError> {
+            self.started.notify_one();
+            self.release.notified().await;
+            self.stored.store(true, Ordering::SeqCst);
+            Ok(StartTransactionConf::new(
+                IdTagInfo::builder(AuthorizationStatus::Accepted).build()?,
+                1,
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pending_handler_completes_after_disconnection() {
+        let mut server = Server::bind("127.0.0.1:0", ServerConfig::new(DuplicatePolicy::Reject))
+            .await
+            .unwrap();
+        let url = format!("ws://{}/ocpp", server.local_addr());
+        let mut client = Client::connect(ClientConfig::builder(url, "CP01").build().unwrap());
+        let mut session = timeout(TIMEOUT, server.accept()).await.unwrap().unwrap();
+        let correlator = Correlator::new(session.sender(), TIMEOUT);
+        let store = Arc::new(SlowStore::default());
+        let serving = tokio::spawn({
+            let store = store.clone();
+            async move { serve_session(&*store, &mut session, &correlator).await }
+        });
+        assert_eq!(
+            timeout(TIMEOUT, client.recv()).await.unwrap(),
+            Some(Event::Connected)
+        );
+
+        let start = StartTransactionReq::builder(1, IdToken::try_from("TAG01").unwrap(), 0, now())
+            .build()
+            .unwrap();
+        client.send_request("1", &start).unwrap();
+        timeout(TIMEOUT, store.started.notified()).await.unwrap();
+        drop(client);
+        timeout(TIMEOUT, async {
+            while server.is_connected("CP01") {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let served_early = serving.is_finished();
+        store.release.notify_one();
+        timeout(TIMEOUT, serving).await.unwrap().unwrap();
+
+        assert!(!served_early);
+        assert!(store.stored.load(Ordering::SeqCst));
+    }
+}
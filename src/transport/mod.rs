@@ -0,0 +1,196 @@
+//! OCPP-J WebSocket transport, carrying the RPC frames of
+//! [`crate::frame`] between Charge Points and the Central System.
+
+use std::future::Future;
+
+use futures_util::{SinkExt, StreamExt};
+use serde::Serialize;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::WebSocketStream;
+
+use crate::error::OcppError;
+use crate::frame::{Call, CallError, CallResult, Frame, OcppRequest, Request};
+
+pub mod client;
+pub mod correlator;
+pub mod handler;
+pub mod queue;
+pub mod server;
+
+/// WebSocket subprotocol negotiated by OCPP 1.6 JSON peers.
+pub const SUBPROTOCOL: &str = "ocpp1.6";
+
+/// Message received from the peer.
+///
+/// CALLs that cannot be decoded are answered with a CALLERROR by the
+/// transport and never received.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Incoming {
+    /// A request, to be answered with a CALLRESULT or a CALLERROR
+    /// carrying the same unique id.
+    Request { unique_id: String, request: Request },
+    /// The answer to a request sent to the peer.
+    CallResult(CallResult),
+    /// The rejection of a request sent to the peer.
+    CallError(CallError),
+}
+
+/// Handle sending frames to the peer of a [`client::Client`] or a
+/// [`server::Session`], which can be cloned and moved to other tasks.
+#[derive(Debug, Clone)]
+pub struct Sender {
+    outgoing: UnboundedSender<Frame>,
+}
+
+impl Sender {
+    pub(crate) fn new(outgoing: UnboundedSender<Frame>) -> Sender {
+        Sender { outgoing }
+    }
+
+    /// Sends a raw frame.
+    pub fn send_frame(&self, frame: Frame) -> Result<(), OcppError> {
+        self.outgoing
+            .send(frame)
+            .map_err(|_| OcppError::InternalError(String::from("connection closed")))
+    }
+
+    /// Sends `request` in a CALL identified by `unique_id`.
+    pub fn send_request<R: OcppRequest>(
+        &self,
+        unique_id: impl Into<String>,
+        request: &R,
+    ) -> Result<(), OcppError> {
+        self.send_frame(Call::new(unique_id, request).into())
+    }
+
+    /// Answers the CALL identified by `unique_id` with `response`.
+    pub fn send_response<T: Serialize>(
+        &self,
+        unique_id: impl Into<String>,
+        response: &T,
+    ) -> Result<(), OcppError> {
+        self.send_frame(CallResult::new(unique_id, response).into())
+    }
+
+    /// Rejects the CALL identified by `unique_id` with `error`.
+    pub fn send_error(&self, unique_id: &str, error: &OcppError) -> Result<(), OcppError> {
+        self.send_frame(error.to_call_error(unique_id).into())
+    }
+}
+
+/// How [`serve`] ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Served {
+    /// The connection was lost or closed by the peer.
+    Disconnected,
+    /// The local side stopped, and the connection was closed.
+    Stopped,
+}
+
+/// Exchanges frames on `stream` until the connection is lost, the
+/// outgoing channel is closed, `deliver` refuses a message or `stop`
+/// completes.
+pub(crate) async fn serve<S: AsyncRead + AsyncWrite + Unpin>(
+    mut stream: WebSocketStream<S>,
+    outgoing: &mut UnboundedReceiver<Frame>,
+    mut deliver: impl FnMut(Incoming) -> bool,
+    stop: impl Future<Output = ()>,
+) -> Served {
+    tokio::pin!(stop);
+    loop {
+        tokio::select! {
+            frame = outgoing.recv() => match frame {
+                Some(frame) => {
+                    if stream.send(Message::text(frame.encode())).await.is_err() {
+                        return Served::Disconnected;
+                    }
+                }
+                None => break,
+            },
+            message = stream.next() => match message {
+                Some(Ok(Message::Text(text))) => {
+                    let incoming = match Frame::parse(text.as_str()) {
+                        Ok(Frame::Call(call)) => match call.decode() {
+                            Ok(request) => Incoming::Request {
+                                unique_id: call.unique_id().to_string(),
+                                request,
+                            },
+                            Err(err) => {
+                                let error = Frame::from(err.to_call_error(call.unique_id()));
+                                if stream.send(Message::text(error.encode())).await.is_err() {
+                                    return Served::Disconnected;
+                                }
+                                continue;
+                            }
+                        },
+                        Ok(Frame::CallResult(result)) => Incoming::CallResult(result),
+                        Ok(Frame::CallError(error)) => Incoming::CallError(error),
+                        Err(_) => continue,
+                    };
+                    if !deliver(incoming) {
+                        break;
+                    }
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return Served::Disconnected,
+                Some(Ok(_)) => {}
+            },
+            () = &mut stop => break,
+        }
+    }
+
+    let _ = stream.close(None).await;
+    Served::Stopped
+}
+
+/// Percent-encodes every character but the unreserved ones of RFC 3986.
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+/// Decodes the percent-encoded `value`, failing on malformed escapes
+/// and on invalid UTF-8.
+fn percent_decode(value: &str) -> Option<String> {
+    let mut decoded = Vec::with_capacity(value.len());
+    let mut bytes = value.bytes();
+    while let Some(byte) = bytes.next() {
+        if byte == b'%' {
+            let high = (bytes.next()? as char).to_digit(16)?;
+            let low = (bytes.next()? as char).to_digit(16)?;
+            decoded.push((high * 16 + low) as u8);
+        } else {
+            decoded.push(byte);
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_percent_encoding_round_trip() {
+        let encoded = percent_encode("CP 01/é");
+
+        assert_eq!(encoded, "CP%2001%2F%C3%A9");
+        assert_eq!(percent_decode(&encoded).as_deref(), Some("CP 01/é"));
+    }
+
+    #[test]
+    fn test_percent_decode_malformed() {
+        assert_eq!(percent_decode("CP%2"), None);
+        assert_eq!(percent_decode("CP%ZZ"), None);
+        assert_eq!(percent_decode("%FF"), None);
+    }
+}
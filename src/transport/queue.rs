@@ -0,0 +1,709 @@
+//! Queue of the transaction-related messages of a Charge Point.
+//!
+//! StartTransaction.req, StopTransaction.req and the MeterValues.req of
+//! a transaction must reach the Central System even when the connection
+//! is lost or the Charge Point reboots. They are delivered in the order
+//! they were queued, and a message the Central System fails to process
+//! is retried up to TransactionMessageAttempts times, waiting
+//! TransactionMessageRetryInterval times the number of previous
+//! attempts before each retry.
+
+use std::collections::VecDeque;
+use std::fs;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::configuration::store::ConfigurationStore;
+use crate::error::OcppError;
+use crate::frame::{Call, Request};
+use crate::messages::meter_values::MeterValuesReq;
+use crate::messages::start_transaction::StartTransactionReq;
+use crate::messages::stop_transaction::StopTransactionReq;
+use crate::persistence::{file_error, write_atomically};
+
+/// Transaction-related request, which must not be lost.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "action", content = "payload")]
+pub enum TransactionMessage {
+    StartTransaction(StartTransactionReq),
+    StopTransaction(StopTransactionReq),
+    MeterValues(MeterValuesReq),
+}
+
+impl TransactionMessage {
+    /// Action name of the request.
+    pub fn action(&self) -> &'static str {
+        match self {
+            TransactionMessage::StartTransaction(_) => "StartTransaction",
+            TransactionMessage::StopTransaction(_) => "StopTransaction",
+            TransactionMessage::MeterValues(_) => "MeterValues",
+        }
+    }
+
+    /// Builds a CALL frame carrying this request.
+    pub fn to_call(&self, unique_id: impl Into<String>) -> Call {
+        match self {
+            TransactionMessage::StartTransaction(request) => Call::new(unique_id, request),
+            TransactionMessage::StopTransaction(request) => Call::new(unique_id, request),
+            TransactionMessage::MeterValues(request) => Call::new(unique_id, request),
+        }
+    }
+}
+
+impl From<StartTransactionReq> for TransactionMessage {
+    fn from(request: StartTransactionReq) -> Self {
+        TransactionMessage::StartTransaction(request)
+    }
+}
+
+impl From<StopTransactionReq> for TransactionMessage {
+    fn from(request: StopTransactionReq) -> Self {
+        TransactionMessage::StopTransaction(request)
+    }
+}
+
+impl TryFrom<MeterValuesReq> for TransactionMessage {
+    type Error = OcppError;
+
+    /// Only the meter values of a transaction are transaction-related.
+    fn try_from(request: MeterValuesReq) -> Result<Self, Self::Error> {
+        if request.transaction_id().is_none() {
+            return Err(OcppError::PropertyConstraintViolation(String::from(
+                "MeterValues: no transactionId",
+            )));
+        }
+
+        Ok(TransactionMessage::MeterValues(request))
+    }
+}
+
+impl From<TransactionMessage> for Request {
+    fn from(message: TransactionMessage) -> Self {
+        match message {
+            TransactionMessage::StartTransaction(request) => request.into(),
+            TransactionMessage::StopTransaction(request) => request.into(),
+            TransactionMessage::MeterValues(request) => request.into(),
+        }
+    }
+}
+
+/// Message waiting in the queue.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedMessage {
+    /// Identifies the message in the queue, in the order of queuing.
+    id: u64,
+    message: TransactionMessage,
+    /// Number of deliveries the Central System failed to process.
+    attempts: u32,
+    /// Date and time before which the message is not retried.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retry_at: Option<DateTime<Utc>>,
+}
+
+impl QueuedMessage {
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn message(&self) -> &TransactionMessage {
+        &self.message
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    pub fn retry_at(&self) -> Option<DateTime<Utc>> {
+        self.retry_at
+    }
+}
+
+/// Retries of the messages the Central System failed to process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    attempts: u32,
+    retry_interval: u32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            attempts: 3,
+            retry_interval: 60,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Tries each message `attempts` times, waiting `retry_interval`
+    /// seconds times the number of previous attempts before a retry.
+    pub fn new(attempts: u32, retry_interval: u32) -> Self {
+        RetryPolicy {
+            attempts,
+            retry_interval,
+        }
+    }
+
+    /// Reads the TransactionMessageAttempts and
+    /// TransactionMessageRetryInterval keys, keeping the default of
+    /// those that are not set.
+    pub fn from_configuration(store: &ConfigurationStore) -> Self {
+        let default = RetryPolicy::default();
+        RetryPolicy {
+            attempts: store
+                .get_integer("TransactionMessageAttempts")
+                .unwrap_or(default.attempts),
+            retry_interval: store
+                .get_integer("TransactionMessageRetryInterval")
+                .unwrap_or(default.retry_interval),
+        }
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    pub fn retry_interval(&self) -> u32 {
+        self.retry_interval
+    }
+
+    /// Time waited before retrying a message after `attempts` failed
+    /// attempts.
+    pub fn retry_delay(&self, attempts: u32) -> Duration {
+        let seconds = i64::from(self.retry_interval) * i64::from(attempts);
+        Duration::try_seconds(seconds).unwrap_or(Duration::MAX)
+    }
+}
+
+/// Storage of the queued messages.
+pub trait QueueStorage {
+    /// Loads the queued messages, oldest first.
+    fn load(&self) -> Result<Vec<QueuedMessage>, OcppError>;
+
+    /// Saves `message`, replacing the message with the same id or
+    /// appending it to the queue.
+    fn put(&mut self, message: &QueuedMessage) -> Result<(), OcppError>;
+
+    /// Removes the message identified by `id`.
+    fn remove(&mut self, id: u64) -> Result<(), OcppError>;
+
+    /// Replaces the saved messages with `messages`, reclaiming the
+    /// space of the removed ones.
+    fn compact(&mut self, messages: &[QueuedMessage]) -> Result<(), OcppError>;
+}
+
+/// Storage keeping the messages in memory only, so that they are lost
+/// on reboot.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MemoryQueueStorage {
+    messages: Vec<QueuedMessage>,
+}
+
+impl MemoryQueueStorage {
+    pub fn new() -> Self {
+        MemoryQueueStorage::default()
+    }
+}
+
+impl QueueStorage for MemoryQueueStorage {
+    fn load(&self) -> Result<Vec<QueuedMessage>, OcppError> {
+        Ok(self.messages.clone())
+    }
+
+    fn put(&mut self, message: &QueuedMessage) -> Result<(), OcppError> {
+        put(&mut self.messages, message.clone());
+
+        Ok(())
+    }
+
+    fn remove(&mut self, id: u64) -> Result<(), OcppError> {
+        self.messages.retain(|message| message.id != id);
+
+        Ok(())
+    }
+
+    fn compact(&mut self, messages: &[QueuedMessage]) -> Result<(), OcppError> {
+        self.messages = messages.to_vec();
+
+        Ok(())
+    }
+}
+
+fn put(messages: &mut Vec<QueuedMessage>, message: QueuedMessage) {
+    match messages.iter_mut().find(|queued| queued.id == message.id) {
+        Some(queued) => *queued = message,
+        None => messages.push(message),
+    }
+}
+
+/// Change of the queue, as written to a [`JournalFile`].
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "camelCase")]
+enum Record {
+    Put { message: QueuedMessage },
+    Remove { id: u64 },
+}
+
+/// Storage keeping the messages in a journal file, one JSON record per
+/// line.
+///
+/// Each change is appended and synced to the disk before returning. A
+/// record torn by a crash or a failed write is the last line of the
+/// file, which is ignored when loading and dropped by the next append
+/// or compaction. Compaction replaces the journal atomically with the
+/// records of the queued messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalFile {
+    path: PathBuf,
+}
+
+impl JournalFile {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        JournalFile { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn append(&self, record: &Record) -> Result<(), OcppError> {
+        let mut line = serde_json::to_vec(record).map_err(|err| file_error(&self.path, err))?;
+        line.push(b'\n');
+
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|err| file_error(&self.path, err))?;
+        let length = complete_length(&mut file).map_err(|err| file_error(&self.path, err))?;
+        if let Err(err) = file.write_all(&line).and_then(|()| file.sync_data()) {
+            // Drop the partial record, so that the next one starts on its
+            // own line.
+            let _ = file.set_len(length);
+            return Err(file_error(&self.path, err));
+        }
+
+        Ok(())
+    }
+}
+
+/// Length of the complete records of a journal, truncating the record
+/// torn by an append that failed and could not be undone.
+fn complete_length(file: &mut fs::File) -> io::Result<u64> {
+    let length = file.metadata()?.len();
+    if length == 0 {
+        return Ok(0);
+    }
+
+    let mut last = [0];
+    file.seek(SeekFrom::Start(length - 1))?;
+    file.read_exact(&mut last)?;
+    if last[0] == b'\n' {
+        return Ok(length);
+    }
+
+    let mut contents = Vec::new();
+    file.seek(SeekFrom::Start(0))?;
+    file.read_to_end(&mut contents)?;
+    let complete = contents
+        .iter()
+        .rposition(|byte| *byte == b'\n')
+        .map_or(0, |newline| newline + 1) as u64;
+    file.set_len(complete)?;
+    Ok(complete)
+}
+
+impl QueueStorage for JournalFile {
+    fn load(&self) -> Result<Vec<QueuedMessage>, OcppError> {
+        let contents = match fs::read(&self.path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(file_error(&self.path, err)),
+        };
+
+        let mut messages = Vec::new();
+        // The part after the last newline is a torn record, if any.
+        let mut lines = contents.split(|byte| *byte == b'\n');
+        lines.next_back();
+        for (number, line) in lines.enumerate() {
+            let record = serde_json::from_slice(line)
+                .map_err(|err| file_error(&self.path, format!("line {}: {}", number + 1, err)))?;
+            match record {
+                Record::Put { message } => put(&mut messages, message),
+                Record::Remove { id } => messages.retain(|message| message.id != id),
+            }
+        }
+
+        Ok(messages)
+    }
+
+    fn put(&mut self, message: &QueuedMessage) -> Result<(), OcppError> {
+        self.append(&Record::Put {
+            message: message.clone(),
+        })
+    }
+
+    fn remove(&mut self, id: u64) -> Result<(), OcppError> {
+        self.append(&Record::Remove { id })
+    }
+
+    fn compact(&mut self, messages: &[QueuedMessage]) -> Result<(), OcppError> {
+        let mut contents = Vec::new();
+        for message in messages {
+            let record = Record::Put {
+                message: message.clone(),
+            };
+            serde_json::to_writer(&mut contents, &record)
+                .map_err(|err| file_error(&self.path, err))?;
+            contents.push(b'\n');
+        }
+
+        write_atomically(&self.path, &contents)
+    }
+}
+
+/// Queue of the transaction-related messages, saved to a storage so
+/// that they survive a reboot.
+///
+/// The message at the front is delivered with [`OfflineQueue::next`]
+/// and, once answered, reported with [`OfflineQueue::delivered`] or
+/// [`OfflineQueue::failed`]. While the Central System cannot be
+/// reached, the messages just stay queued.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OfflineQueue<S> {
+    messages: VecDeque<QueuedMessage>,
+    storage: S,
+    policy: RetryPolicy,
+    next_id: u64,
+}
+
+impl<S: QueueStorage> OfflineQueue<S> {
+    /// Restores the messages saved in `storage`, which is compacted.
+    pub fn open(mut storage: S, policy: RetryPolicy) -> Result<Self, OcppError> {
+        let messages = storage.load()?;
+        storage.compact(&messages)?;
+        let next_id = messages.iter().map(|message| message.id + 1).max();
+
+        Ok(OfflineQueue {
+            messages: messages.into(),
+            storage,
+            policy,
+            next_id: next_id.unwrap_or(1),
+        })
+    }
+
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    pub fn policy(&self) -> RetryPolicy {
+        self.policy
+    }
+
+    /// Applies a change of the TransactionMessageAttempts or
+    /// TransactionMessageRetryInterval keys.
+    pub fn set_policy(&mut self, policy: RetryPolicy) {
+        self.policy = policy;
+    }
+
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Queued messages, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &QueuedMessage> {
+        self.messages.iter()
+    }
+
+    /// Saves `message` at the end of the queue, returning its id.
+    pub fn push(&mut self, message: TransactionMessage) -> Result<u64, OcppError> {
+        let queued = QueuedMessage {
+            id: self.next_id,
+            message,
+            attempts: 0,
+            retry_at: None,
+        };
+        self.storage.put(&queued)?;
+
+        self.next_id += 1;
+        self.messages.push_back(queued);
+        Ok(self.next_id - 1)
+    }
+
+    /// Message to deliver at `now`: the oldest one, unless it waits for
+    /// a retry.
+    pub fn next(&self, now: DateTime<Utc>) -> Option<&QueuedMessage> {
+        self.messages
+            .front()
+            .filter(|message| message.retry_at.is_none_or(|retry_at| retry_at <= now))
+    }
+
+    /// Removes the message identified by `id`, which the Central System
+    /// answered.
+    pub fn delivered(&mut self, id: u64) -> Result<(), OcppError> {
+        self.remove(id)
+    }
+
+    /// Records that the Central System failed to process the message
+    /// identified by `id` at `now`, e.g. answering it with a CALLERROR.
+    ///
+    /// The message is retried later, until it was attempted as many
+    /// times as the policy allows. It is then dropped from the queue
+    /// and returned, so that the Charge Point can report it.
+    pub fn failed(
+        &mut self,
+        id: u64,
+        now: DateTime<Utc>,
+    ) -> Result<Option<QueuedMessage>, OcppError> {
+        let Some(message) = self.messages.iter().find(|message| message.id == id) else {
+            return Ok(None);
+        };
+
+        let mut message = message.clone();
+        message.attempts += 1;
+        if message.attempts >= self.policy.attempts {
+            self.remove(id)?;
+            return Ok(Some(message));
+        }
+
+        let delay = self.policy.retry_delay(message.attempts);
+        message.retry_at = Some(
+            now.checked_add_signed(delay)
+                .unwrap_or(DateTime::<Utc>::MAX_UTC),
+        );
+        self.storage.put(&message)?;
+        if let Some(queued) = self.messages.iter_mut().find(|queued| queued.id == id) {
+            *queued = message;
+        }
+        Ok(None)
+    }
+
+    fn remove(&mut self, id: u64) -> Result<(), OcppError> {
+        if !self.messages.iter().any(|message| message.id == id) {
+            return Ok(());
+        }
+        self.storage.remove(id)?;
+
+        self.messages.retain(|message| message.id != id);
+        if self.messages.is_empty() {
+            self.storage.compact(&[])?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::error::ErrorCode;
+    use crate::types::authorization::IdToken;
+    use crate::types::meter_values::{MeterValues, SampledValue};
+    use chrono::TimeZone;
+
+    fn at(minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 5, 1, 12, minute, 0).unwrap()
+    }
+
+    fn start(meter_start: usize) -> TransactionMessage {
+        StartTransactionReq::builder(1, IdToken::try_from("TAG01").unwrap(), meter_start, at(0))
+            .build()
+            .unwrap()
+            .into()
+    }
+
+    fn stop(transaction_id: usize) -> TransactionMessage {
+        StopTransactionReq::builder(1200, at(30), transaction_id)
+            .build()
+            .unwrap()
+            .into()
+    }
+
+    fn meter_values(transaction_id: Option<usize>) -> MeterValuesReq {
+        let sampled_value = SampledValue::builder(String::from("1100")).build().unwrap();
+        let meter_value = MeterValues::new(at(15), vec![sampled_value]).unwrap();
+        let builder = MeterValuesReq::builder(1, vec![meter_value]);
+        match transaction_id {
+            Some(transaction_id) => builder.transaction_id(transaction_id),
+            None => builder,
+        }
+        .build()
+        .unwrap()
+    }
+
+    fn test_path(test: &str) -> PathBuf {
+        crate::persistence::test_path(&format!("queue-{}", test), "transactions.jsonl")
+    }
+
+    fn queued(queue: &OfflineQueue<impl QueueStorage>) -> Vec<TransactionMessage> {
+        queue
+            .iter()
+            .map(|message| message.message().clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_messages_are_delivered_in_order() {
+        let mut queue =
+            OfflineQueue::open(MemoryQueueStorage::new(), RetryPolicy::default()).unwrap();
+        let first = queue.push(start(100)).unwrap();
+        let second = queue.push(stop(7)).unwrap();
+
+        let next = queue.next(at(0)).map(QueuedMessage::id);
+        queue.delivered(first).unwrap();
+        let after = queue.next(at(0)).map(QueuedMessage::id);
+        queue.delivered(second).unwrap();
+
+        assert_eq!(next, Some(first));
+        assert_eq!(after, Some(second));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_failed_message_is_retried_later() {
+        let policy = RetryPolicy::new(3, 60);
+        let mut queue = OfflineQueue::open(MemoryQueueStorage::new(), policy).unwrap();
+        let id = queue.push(start(100)).unwrap();
+        queue.push(stop(7)).unwrap();
+
+        let first = queue.failed(id, at(0)).unwrap();
+        let waiting = queue.next(at(0)).is_none();
+        let retried = queue.next(at(1)).map(QueuedMessage::id);
+        let second = queue.failed(id, at(1)).unwrap();
+
+        assert_eq!(first, None);
+        assert!(waiting);
+        assert_eq!(retried, Some(id));
+        assert_eq!(second, None);
+        assert!(queue.next(at(2)).is_none());
+        assert_eq!(queue.next(at(3)).map(QueuedMessage::attempts), Some(2));
+    }
+
+    #[test]
+    fn test_message_is_dropped_after_last_attempt() {
+        let mut queue =
+            OfflineQueue::open(MemoryQueueStorage::new(), RetryPolicy::new(2, 0)).unwrap();
+        let id = queue.push(start(100)).unwrap();
+        queue.push(stop(7)).unwrap();
+
+        queue.failed(id, at(0)).unwrap();
+        let dropped = queue.failed(id, at(0)).unwrap().unwrap();
+
+        assert_eq!(dropped.message(), &start(100));
+        assert_eq!(dropped.attempts(), 2);
+        assert_eq!(queued(&queue), vec![stop(7)]);
+    }
+
+    #[test]
+    fn test_only_transaction_meter_values_are_queued() {
+        let err = TransactionMessage::try_from(meter_values(None)).unwrap_err();
+        let message = TransactionMessage::try_from(meter_values(Some(7))).unwrap();
+
+        assert_eq!(err.code(), ErrorCode::PropertyConstraintViolation);
+        assert_eq!(message.action(), "MeterValues");
+        assert_eq!(message.to_call("1").action(), "MeterValues");
+    }
+
+    #[test]
+    fn test_policy_from_configuration() {
+        let store = ConfigurationStore::new()
+            .with_value("TransactionMessageAttempts", "5")
+            .unwrap();
+
+        let policy = RetryPolicy::from_configuration(&store);
+
+        assert_eq!(policy, RetryPolicy::new(5, 60));
+        assert_eq!(policy.retry_delay(3), Duration::seconds(180));
+    }
+
+    #[test]
+    fn test_queue_survives_reopening() {
+        let path = test_path("reopening");
+        let mut queue =
+            OfflineQueue::open(JournalFile::new(&path), RetryPolicy::default()).unwrap();
+        let first = queue.push(start(100)).unwrap();
+        let second = queue
+            .push(meter_values(Some(7)).try_into().unwrap())
+            .unwrap();
+        queue.push(stop(7)).unwrap();
+        queue.delivered(first).unwrap();
+        queue.failed(second, at(0)).unwrap();
+
+        let mut reopened =
+            OfflineQueue::open(JournalFile::new(&path), RetryPolicy::default()).unwrap();
+        let restored: Vec<QueuedMessage> = reopened.iter().cloned().collect();
+        let third = reopened.push(stop(8)).unwrap();
+
+        assert_eq!(restored, queue.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(restored[0].attempts(), 1);
+        assert!(third > second + 1);
+    }
+
+    #[test]
+    fn test_torn_record_is_ignored() {
+        let path = test_path("torn_record");
+        let mut queue =
+            OfflineQueue::open(JournalFile::new(&path), RetryPolicy::default()).unwrap();
+        queue.push(start(100)).unwrap();
+        let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"{\"op\":\"put\",\"mess").unwrap();
+
+        let mut reopened =
+            OfflineQueue::open(JournalFile::new(&path), RetryPolicy::default()).unwrap();
+        reopened.push(stop(7)).unwrap();
+
+        assert_eq!(queued(&reopened), vec![start(100), stop(7)]);
+        assert_eq!(
+            queued(&OfflineQueue::open(JournalFile::new(&path), RetryPolicy::default()).unwrap()),
+            vec![start(100), stop(7)]
+        );
+    }
+
+    #[test]
+    fn test_record_torn_at_runtime_is_dropped() {
+        let path = test_path("torn_at_runtime");
+        let mut queue =
+            OfflineQueue::open(JournalFile::new(&path), RetryPolicy::default()).unwrap();
+        queue.push(start(100)).unwrap();
+        // A write failing partway through a line, e.g. on a full disk.
+        let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"{\"op\":\"put\",\"mess").unwrap();
+
+        queue.push(stop(7)).unwrap();
+        let reopened = OfflineQueue::open(JournalFile::new(&path), RetryPolicy::default()).unwrap();
+
+        assert_eq!(queued(&reopened), vec![start(100), stop(7)]);
+    }
+
+    #[test]
+    fn test_corrupted_journal_is_reported() {
+        let path = test_path("corrupted_journal");
+        fs::write(&path, "{\"op\":\"put\"}\n").unwrap();
+
+        let err = OfflineQueue::open(JournalFile::new(&path), RetryPolicy::default()).unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::InternalError);
+    }
+
+    #[test]
+    fn test_emptied_queue_is_compacted() {
+        let path = test_path("compacted");
+        let mut queue =
+            OfflineQueue::open(JournalFile::new(&path), RetryPolicy::default()).unwrap();
+        let id = queue.push(start(100)).unwrap();
+
+        queue.delivered(id).unwrap();
+
+        assert_eq!(fs::read(&path).unwrap(), Vec::<u8>::new());
+        assert!(!crate::persistence::temporary_path(&path).exists());
+    }
+}
@@ -0,0 +1,597 @@
+//! WebSocket server of the Central System, accepting Charge Points
+//! connecting at `<path>/<chargePointId>`.
+//!
+//! Every accepted connection is handed over as a [`Session`], which
+//! receives the messages of the Charge Point and sends the requests
+//! and answers of the Central System.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::Serialize;
+use tokio::net::{TcpListener, TcpStream, ToSocketAddrs};
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::handshake::server::{
+    ErrorResponse, Request as HttpRequest, Response as HttpResponse,
+};
+use tokio_tungstenite::tungstenite::http::header::SEC_WEBSOCKET_PROTOCOL;
+use tokio_tungstenite::tungstenite::http::{HeaderValue, StatusCode};
+
+use super::{percent_decode, serve, Incoming, Sender, SUBPROTOCOL};
+use crate::error::OcppError;
+use crate::frame::{Frame, OcppRequest};
+
+/// What to do when a Charge Point connects while a session with the
+/// same identity is still open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DuplicatePolicy {
+    /// Refuse the new connection with HTTP 409 Conflict.
+    #[default]
+    Reject,
+    /// Close the open session and accept the new connection.
+    Replace,
+}
+
+/// Settings of a [`Server`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ServerConfig {
+    policy: DuplicatePolicy,
+    /// Time a Charge Point is given to complete the WebSocket
+    /// handshake once connected, after which the connection is dropped.
+    handshake_timeout: Duration,
+}
+
+impl ServerConfig {
+    /// Default time given to complete the WebSocket handshake.
+    pub const DEFAULT_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+    pub fn new(policy: DuplicatePolicy) -> Self {
+        ServerConfig {
+            policy,
+            handshake_timeout: Self::DEFAULT_HANDSHAKE_TIMEOUT,
+        }
+    }
+
+    pub fn with_handshake_timeout(mut self, handshake_timeout: Duration) -> Self {
+        self.handshake_timeout = handshake_timeout;
+        self
+    }
+
+    pub fn policy(&self) -> DuplicatePolicy {
+        self.policy
+    }
+
+    pub fn handshake_timeout(&self) -> Duration {
+        self.handshake_timeout
+    }
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        ServerConfig::new(DuplicatePolicy::default())
+    }
+}
+
+/// Open sessions, by identity of their Charge Point.
+#[derive(Debug, Default)]
+struct Registry {
+    sessions: Mutex<HashMap<String, Registration>>,
+    next_generation: AtomicU64,
+}
+
+#[derive(Debug)]
+struct Registration {
+    /// Distinguishes the sessions successively opened by a Charge
+    /// Point.
+    generation: u64,
+    /// Closes the session when sent.
+    stop: oneshot::Sender<()>,
+}
+
+impl Registry {
+    fn contains(&self, charge_point_id: &str) -> bool {
+        self.sessions.lock().unwrap().contains_key(charge_point_id)
+    }
+
+    /// Registers a session of `charge_point_id`, returning its
+    /// generation and the receiver closing it, or None when another
+    /// session is open and `policy` rejects duplicates.
+    fn register(
+        &self,
+        charge_point_id: &str,
+        policy: DuplicatePolicy,
+    ) -> Option<(u64, oneshot::Receiver<()>)> {
+        let mut sessions = self.sessions.lock().unwrap();
+        if policy == DuplicatePolicy::Reject && sessions.contains_key(charge_point_id) {
+            return None;
+        }
+
+        let generation = self.next_generation.fetch_add(1, Ordering::Relaxed);
+        let (stop, stopped) = oneshot::channel();
+        if let Some(previous) = sessions.insert(
+            charge_point_id.to_string(),
+            Registration { generation, stop },
+        ) {
+            let _ = previous.stop.send(());
+        }
+
+        Some((generation, stopped))
+    }
+
+    fn unregister(&self, charge_point_id: &str, generation: u64) {
+        let mut sessions = self.sessions.lock().unwrap();
+        if sessions
+            .get(charge_point_id)
+            .is_some_and(|registration| registration.generation == generation)
+        {
+            sessions.remove(charge_point_id);
+        }
+    }
+}
+
+/// Unregisters a session once its task ends or is aborted.
+struct RegistrationGuard {
+    registry: Arc<Registry>,
+    charge_point_id: String,
+    generation: u64,
+}
+
+impl Drop for RegistrationGuard {
+    fn drop(&mut self) {
+        self.registry
+            .unregister(&self.charge_point_id, self.generation);
+    }
+}
+
+/// WebSocket server of the Central System.
+///
+/// Dropping the server stops accepting connections, while the sessions
+/// already accepted stay open.
+#[derive(Debug)]
+pub struct Server {
+    local_addr: SocketAddr,
+    sessions: UnboundedReceiver<Session>,
+    registry: Arc<Registry>,
+    task: JoinHandle<()>,
+}
+
+impl Server {
+    /// Starts accepting connections on `addr`. Must be called within a
+    /// Tokio runtime.
+    pub async fn bind(addr: impl ToSocketAddrs, config: ServerConfig) -> Result<Server, OcppError> {
+        let listener = TcpListener::bind(addr)
+            .await
+            .map_err(|err| OcppError::InternalError(err.to_string()))?;
+        let local_addr = listener
+            .local_addr()
+            .map_err(|err| OcppError::InternalError(err.to_string()))?;
+        let registry = Arc::new(Registry::default());
+        let (sessions_tx, sessions) = mpsc::unbounded_channel();
+        let task = tokio::spawn(listen(listener, config, registry.clone(), sessions_tx));
+
+        Ok(Server {
+            local_addr,
+            sessions,
+            registry,
+            task,
+        })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Whether a session of `charge_point_id` is open.
+    pub fn is_connected(&self, charge_point_id: &str) -> bool {
+        self.registry.contains(charge_point_id)
+    }
+
+    /// Waits for the next Charge Point to connect.
+    pub async fn accept(&mut self) -> Option<Session> {
+        self.sessions.recv().await
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Time waited after a failed accept, such as when the process runs out
+/// of file descriptors, before accepting again.
+const ACCEPT_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+async fn listen(
+    listener: TcpListener,
+    config: ServerConfig,
+    registry: Arc<Registry>,
+    sessions: UnboundedSender<Session>,
+) {
+    loop {
+        let Ok((tcp, _)) = listener.accept().await else {
+            tokio::time::sleep(ACCEPT_RETRY_DELAY).await;
+            continue;
+        };
+        let registry = registry.clone();
+        let sessions = sessions.clone();
+        tokio::spawn(async move {
+            if let Some(session) = open(tcp, config, registry).await {
+                let _ = sessions.send(session);
+            }
+        });
+    }
+}
+
+/// Performs the WebSocket handshake and starts the session.
+/// Connections not upgraded within the handshake timeout are dropped,
+/// so that idle peers cannot hold sockets forever.
+async fn open(tcp: TcpStream, config: ServerConfig, registry: Arc<Registry>) -> Option<Session> {
+    let mut registered = None;
+    // The handshake callback can only fail with a plain HTTP response.
+    #[allow(clippy::result_large_err)]
+    let callback = |request: &HttpRequest, response: HttpResponse| {
+        let charge_point_id = check_handshake(request)?;
+        // Registering before answering, under the lock of the registry,
+        // refuses a racing duplicate with 409 rather than upgrading it.
+        let (generation, stopped) = registry
+            .register(&charge_point_id, config.policy)
+            .ok_or_else(|| {
+                error_response(
+                    StatusCode::CONFLICT,
+                    format!("{} already connected", charge_point_id),
+                )
+            })?;
+        let guard = RegistrationGuard {
+            registry: registry.clone(),
+            charge_point_id,
+            generation,
+        };
+        registered = Some((guard, stopped));
+        Ok(with_subprotocol(response))
+    };
+    let stream = tokio::time::timeout(
+        config.handshake_timeout,
+        tokio_tungstenite::accept_hdr_async(tcp, callback),
+    )
+    .await;
+    // Dropping the guard unregisters a session whose handshake failed.
+    let (guard, stopped) = registered?;
+    let stream = stream.ok()?.ok()?;
+
+    let charge_point_id = guard.charge_point_id.clone();
+    let (outgoing, mut outgoing_rx) = mpsc::unbounded_channel();
+    let (incoming_tx, incoming) = mpsc::unbounded_channel();
+    let task = tokio::spawn(async move {
+        let _guard = guard;
+        let deliver = |message| incoming_tx.send(message).is_ok();
+        serve(stream, &mut outgoing_rx, deliver, async {
+            let _ = stopped.await;
+        })
+        .await;
+    });
+
+    Some(Session {
+        charge_point_id,
+        sender: Sender::new(outgoing),
+        incoming,
+        task,
+    })
+}
+
+/// Checks the upgrade request of a Charge Point, returning its
+/// identity.
+#[allow(clippy::result_large_err)]
+fn check_handshake(request: &HttpRequest) -> Result<String, ErrorResponse> {
+    let offers_subprotocol = request
+        .headers()
+        .get_all(SEC_WEBSOCKET_PROTOCOL)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|protocol| protocol.trim() == SUBPROTOCOL);
+    if !offers_subprotocol {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!("subprotocol {} required", SUBPROTOCOL),
+        ));
+    }
+
+    charge_point_id(request.uri().path()).ok_or_else(|| {
+        error_response(
+            StatusCode::NOT_FOUND,
+            String::from("charge point id required"),
+        )
+    })
+}
+
+fn with_subprotocol(mut response: HttpResponse) -> HttpResponse {
+    response.headers_mut().insert(
+        SEC_WEBSOCKET_PROTOCOL,
+        HeaderValue::from_static(SUBPROTOCOL),
+    );
+    response
+}
+
+fn error_response(status: StatusCode, reason: String) -> ErrorResponse {
+    let mut response = ErrorResponse::new(Some(reason));
+    *response.status_mut() = status;
+    response
+}
+
+/// Identity of the Charge Point, taken from the last segment of the
+/// path of the URL.
+fn charge_point_id(path: &str) -> Option<String> {
+    let segment = path.rsplit('/').next()?;
+    percent_decode(segment).filter(|id| !id.is_empty())
+}
+
+/// Connection of a Charge Point to the Central System.
+///
+/// Dropping the session closes the connection.
+#[derive(Debug)]
+pub struct Session {
+    charge_point_id: String,
+    sender: Sender,
+    incoming: UnboundedReceiver<Incoming>,
+    task: JoinHandle<()>,
+}
+
+impl Session {
+    /// Identity of the connected Charge Point.
+    pub fn charge_point_id(&self) -> &str {
+        &self.charge_point_id
+    }
+
+    /// Handle sending frames on this connection.
+    pub fn sender(&self) -> Sender {
+        self.sender.clone()
+    }
+
+    /// Sends a raw frame.
+    pub fn send_frame(&self, frame: Frame) -> Result<(), OcppError> {
+        self.sender.send_frame(frame)
+    }
+
+    /// Sends `request` in a CALL identified by `unique_id`.
+    pub fn send_request<R: OcppRequest>(
+        &self,
+        unique_id: impl Into<String>,
+        request: &R,
+    ) -> Result<(), OcppError> {
+        self.sender.send_request(unique_id, request)
+    }
+
+    /// Answers the CALL identified by `unique_id` with `response`.
+    pub fn send_response<T: Serialize>(
+        &self,
+        unique_id: impl Into<String>,
+        response: &T,
+    ) -> Result<(), OcppError> {
+        self.sender.send_response(unique_id, response)
+    }
+
+    /// Rejects the CALL identified by `unique_id` with `error`.
+    pub fn send_error(&self, unique_id: &str, error: &OcppError) -> Result<(), OcppError> {
+        self.sender.send_error(unique_id, error)
+    }
+
+    /// Waits for the next message of the Charge Point, None once the
+    /// connection is closed.
+    pub async fn recv(&mut self) -> Option<Incoming> {
+        self.incoming.recv().await
+    }
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::frame::Request;
+    use crate::messages::get_configuration::GetConfigurationReq;
+    use crate::messages::heartbeat::HeartbeatReq;
+    use crate::transport::client::{Client, ClientConfig, Event};
+    use std::time::Duration;
+    use tokio::io::AsyncReadExt;
+    use tokio::time::timeout;
+    use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+    use tokio_tungstenite::tungstenite::Error as WsError;
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    async fn server(policy: DuplicatePolicy) -> (Server, String) {
+        let server = Server::bind("127.0.0.1:0", ServerConfig::new(policy))
+            .await
+            .unwrap();
+        let url = format!("ws://{}/ocpp", server.local_addr());
+        (server, url)
+    }
+
+    async fn accept(server: &mut Server) -> Session {
+        timeout(TIMEOUT, server.accept()).await.unwrap().unwrap()
+    }
+
+    /// Connects without the client, returning the HTTP status of a
+    /// refused handshake.
+    async fn connect_raw(url: &str, subprotocol: Option<&'static str>) -> Result<(), StatusCode> {
+        let mut request = url.into_client_request().unwrap();
+        if let Some(subprotocol) = subprotocol {
+            request.headers_mut().insert(
+                SEC_WEBSOCKET_PROTOCOL,
+                HeaderValue::from_static(subprotocol),
+            );
+        }
+        match tokio_tungstenite::connect_async(request).await {
+            Ok(_) => Ok(()),
+            Err(WsError::Http(response)) => Err(response.status()),
+            Err(err) => panic!("unexpected error {}", err),
+        }
+    }
+
+    async fn connected_client(url: &str, charge_point_id: &str) -> Client {
+        let config = ClientConfig::builder(url, charge_point_id).build().unwrap();
+        let mut client = Client::connect(config);
+        let event = timeout(TIMEOUT, client.recv()).await.unwrap();
+        assert_eq!(event, Some(Event::Connected));
+        client
+    }
+
+    #[test]
+    fn test_charge_point_id_from_path() {
+        assert_eq!(charge_point_id("/ocpp/CP%2001").as_deref(), Some("CP 01"));
+        assert_eq!(charge_point_id("/CP01").as_deref(), Some("CP01"));
+        assert_eq!(charge_point_id("/ocpp/"), None);
+    }
+
+    #[tokio::test]
+    async fn test_session_exchanges_typed_messages() {
+        let (mut server, url) = server(DuplicatePolicy::Reject).await;
+        let mut client = connected_client(&url, "CP01").await;
+        let mut session = accept(&mut server).await;
+
+        client.send_request("1", &HeartbeatReq::new()).unwrap();
+        let request = timeout(TIMEOUT, session.recv()).await.unwrap();
+        session
+            .send_request("cs-1", &GetConfigurationReq::builder().build().unwrap())
+            .unwrap();
+        let event = timeout(TIMEOUT, client.recv()).await.unwrap();
+
+        assert_eq!(session.charge_point_id(), "CP01");
+        assert!(server.is_connected("CP01"));
+        assert_eq!(
+            request,
+            Some(Incoming::Request {
+                unique_id: String::from("1"),
+                request: Request::Heartbeat(HeartbeatReq::new()),
+            })
+        );
+        assert_eq!(
+            event,
+            Some(Event::Request {
+                unique_id: String::from("cs-1"),
+                request: Request::GetConfiguration(GetConfigurationReq::builder().build().unwrap()),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rejects_missing_subprotocol() {
+        let (_server, url) = server(DuplicatePolicy::Reject).await;
+
+        assert_eq!(
+            connect_raw(&format!("{}/CP01", url), None).await,
+            Err(StatusCode::BAD_REQUEST)
+        );
+        assert_eq!(
+            connect_raw(&format!("{}/CP01", url), Some("ocpp2.0.1")).await,
+            Err(StatusCode::BAD_REQUEST)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rejects_missing_identity() {
+        let (_server, url) = server(DuplicatePolicy::Reject).await;
+
+        assert_eq!(
+            connect_raw(&format!("{}/", url), Some(SUBPROTOCOL)).await,
+            Err(StatusCode::NOT_FOUND)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rejects_duplicate_connection() {
+        let (mut server, url) = server(DuplicatePolicy::Reject).await;
+        let _client = connected_client(&url, "CP01").await;
+        let _session = accept(&mut server).await;
+
+        assert_eq!(
+            connect_raw(&format!("{}/CP01", url), Some(SUBPROTOCOL)).await,
+            Err(StatusCode::CONFLICT)
+        );
+        assert_eq!(
+            connect_raw(&format!("{}/CP02", url), Some(SUBPROTOCOL)).await,
+            Ok(())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_racing_duplicate_connections() {
+        let (mut server, url) = server(DuplicatePolicy::Reject).await;
+        let connect = || {
+            let mut request = format!("{}/CP01", url).into_client_request().unwrap();
+            request.headers_mut().insert(
+                SEC_WEBSOCKET_PROTOCOL,
+                HeaderValue::from_static(SUBPROTOCOL),
+            );
+            tokio_tungstenite::connect_async(request)
+        };
+
+        let (first, second) = tokio::join!(connect(), connect());
+        let statuses = [&first, &second].map(|result| match result {
+            Ok(_) => None,
+            Err(WsError::Http(response)) => Some(response.status()),
+            Err(err) => panic!("unexpected error {}", err),
+        });
+        let _session = accept(&mut server).await;
+
+        assert!(
+            statuses == [None, Some(StatusCode::CONFLICT)]
+                || statuses == [Some(StatusCode::CONFLICT), None]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_drops_idle_connection() {
+        let config = ServerConfig::new(DuplicatePolicy::Reject)
+            .with_handshake_timeout(Duration::from_millis(50));
+        let server = Server::bind("127.0.0.1:0", config).await.unwrap();
+        let mut idle = TcpStream::connect(server.local_addr()).await.unwrap();
+
+        let mut buffer = [0; 16];
+        let read = timeout(TIMEOUT, idle.read(&mut buffer)).await.unwrap();
+
+        assert!(matches!(read, Ok(0) | Err(_)));
+    }
+
+    #[tokio::test]
+    async fn test_replaces_duplicate_connection() {
+        let (mut server, url) = server(DuplicatePolicy::Replace).await;
+        let _first_client = connected_client(&url, "CP01").await;
+        let mut first = accept(&mut server).await;
+
+        let _second_client = connected_client(&url, "CP01").await;
+        let second = accept(&mut server).await;
+
+        assert_eq!(timeout(TIMEOUT, first.recv()).await.unwrap(), None);
+        assert_eq!(second.charge_point_id(), "CP01");
+        assert!(server.is_connected("CP01"));
+    }
+
+    #[tokio::test]
+    async fn test_dropped_session_is_unregistered() {
+        let (mut server, url) = server(DuplicatePolicy::Reject).await;
+        let _client = connected_client(&url, "CP01").await;
+        let session = accept(&mut server).await;
+
+        drop(session);
+        let unregistered = timeout(TIMEOUT, async {
+            while server.is_connected("CP01") {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await;
+
+        assert!(unregistered.is_ok());
+    }
+}
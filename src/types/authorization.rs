@@ -1,7 +1,13 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
 use super::enumerations::AuthorizationStatus;
+use super::utils::CiString20Type;
+use crate::error::OcppError;
+use crate::messages::Validate;
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct AuthorizationData {
     /// The identifier to with this authorization applies
     id_tag: IdToken,
@@ -11,83 +17,224 @@ pub struct AuthorizationData {
     /// this entry SHALL be added or updated in the Local
     /// Authorization List. If this element is absent, than
     /// the Local Authorization List SHALL be deleted.
+    #[serde(skip_serializing_if = "Option::is_none")]
     id_tag_info: Option<IdTagInfo>,
 }
 
+impl AuthorizationData {
+    pub fn builder(id_tag: IdToken) -> AuthorizationDataBuilder {
+        AuthorizationDataBuilder {
+            inner: AuthorizationData {
+                id_tag,
+                id_tag_info: None,
+            },
+        }
+    }
+
+    pub fn id_tag(&self) -> &IdToken {
+        &self.id_tag
+    }
+
+    pub fn id_tag_info(&self) -> Option<&IdTagInfo> {
+        self.id_tag_info.as_ref()
+    }
+}
+
+/// Builder for AuthorizationData, created by [`AuthorizationData::builder`].
+#[derive(Debug, Clone)]
+pub struct AuthorizationDataBuilder {
+    inner: AuthorizationData,
+}
+
+impl AuthorizationDataBuilder {
+    pub fn id_tag_info(mut self, id_tag_info: IdTagInfo) -> AuthorizationDataBuilder {
+        self.inner.id_tag_info = Some(id_tag_info);
+        self
+    }
+
+    /// Finishes the AuthorizationData, checking its constraints.
+    pub fn build(self) -> Result<AuthorizationData, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for AuthorizationData {}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct IdTagInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
     expiry_date: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     parent_id_tag: Option<IdToken>,
     status: AuthorizationStatus,
 }
 
-#[derive(Debug)]
-pub struct IdToken {
-    id_token: String,
-}
+impl IdTagInfo {
+    pub fn builder(status: AuthorizationStatus) -> IdTagInfoBuilder {
+        IdTagInfoBuilder {
+            inner: IdTagInfo {
+                status,
+                expiry_date: None,
+                parent_id_tag: None,
+            },
+        }
+    }
+
+    pub fn expiry_date(&self) -> Option<DateTime<Utc>> {
+        self.expiry_date
+    }
+
+    pub fn parent_id_tag(&self) -> Option<&IdToken> {
+        self.parent_id_tag.as_ref()
+    }
+
+    pub fn status(&self) -> AuthorizationStatus {
+        self.status
+    }
+
+    /// Whether the expiry date has passed at `now`.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expiry_date
+            .is_some_and(|expiry_date| expiry_date <= now)
+    }
 
-impl IdToken {
-    pub fn new(id_token: String) -> IdToken {
-        if id_token.len() > 20 {
-            panic!("Max size of 20 characters")
+    /// Status in force at `now`, which is Expired once the expiry date
+    /// has passed.
+    pub fn status_at(&self, now: DateTime<Utc>) -> AuthorizationStatus {
+        if self.is_expired(now) {
+            AuthorizationStatus::Expired
+        } else {
+            self.status
         }
+    }
 
-        IdToken { id_token }
+    /// Marks the identifier as expired, as done by the Charge Point
+    /// once the expiry date of a stored authorization has passed.
+    pub(crate) fn expire(&mut self) {
+        self.status = AuthorizationStatus::Expired;
     }
 }
 
-impl PartialEq for IdToken {
-    fn eq(&self, other: &Self) -> bool {
-        self.id_token.to_lowercase() == other.id_token.to_lowercase()
+/// Builder for IdTagInfo, created by [`IdTagInfo::builder`].
+#[derive(Debug, Clone)]
+pub struct IdTagInfoBuilder {
+    inner: IdTagInfo,
+}
+
+impl IdTagInfoBuilder {
+    pub fn expiry_date(mut self, expiry_date: DateTime<Utc>) -> IdTagInfoBuilder {
+        self.inner.expiry_date = Some(expiry_date);
+        self
+    }
+
+    pub fn parent_id_tag(mut self, parent_id_tag: IdToken) -> IdTagInfoBuilder {
+        self.inner.parent_id_tag = Some(parent_id_tag);
+        self
+    }
+
+    /// Finishes the IdTagInfo, checking its constraints.
+    pub fn build(self) -> Result<IdTagInfo, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
     }
 }
 
+impl Validate for IdTagInfo {}
+
+/// A case insensitive identifier to use for the authorization
+/// and a type of token.
+pub type IdToken = CiString20Type;
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
-    use std::iter;
+    use crate::error::{ErrorCode, OcppError};
+    use crate::types::utils::CiStringError;
 
     #[test]
     fn test_with_valid_id_token() {
-        let token = String::from("abcd-01234");
-        let id_token = IdToken::new(token);
+        let id_token = IdToken::try_from(String::from("abcd-01234")).unwrap();
 
-        assert_eq!(
-            id_token,
-            IdToken {
-                id_token: String::from("abcd-01234")
-            }
-        )
+        assert_eq!(id_token.as_str(), "abcd-01234")
     }
 
     #[test]
-    #[should_panic]
     fn test_with_invalid_id_token() {
-        let token = iter::repeat("a").take(21).collect();
-        let _id_token = IdToken::new(token);
+        let token = "a".repeat(21);
+
+        assert_eq!(
+            IdToken::try_from(token),
+            Err(CiStringError::TooLong {
+                max: 20,
+                length: 21
+            })
+        );
     }
 
     #[test]
     fn test_id_token_partial_equals_with_same_token_case() {
-        let id_token_a = IdToken::new(String::from("abcd-01234"));
-        let id_token_b = IdToken::new(String::from("abcd-01234"));
+        let id_token_a = IdToken::try_from("abcd-01234").unwrap();
+        let id_token_b = IdToken::try_from("abcd-01234").unwrap();
 
-        assert_eq!(true, id_token_a == id_token_b)
+        assert!(id_token_a == id_token_b)
     }
 
     #[test]
     fn test_id_token_partial_equals_with_distinct_token_case() {
-        let id_token_a = IdToken::new(String::from("abcd-01234"));
-        let id_token_b = IdToken::new(String::from("ABCD-01234"));
+        let id_token_a = IdToken::try_from("abcd-01234").unwrap();
+        let id_token_b = IdToken::try_from("ABCD-01234").unwrap();
 
-        assert_eq!(true, id_token_a == id_token_b)
+        assert!(id_token_a == id_token_b)
     }
 
     #[test]
     fn test_id_token_partial_equals_with_distict_tokens() {
-        let id_token_a = IdToken::new(String::from("abcd-01234"));
-        let id_token_b = IdToken::new(String::from("efgh-01234"));
+        let id_token_a = IdToken::try_from("abcd-01234").unwrap();
+        let id_token_b = IdToken::try_from("efgh-01234").unwrap();
+
+        assert!(id_token_a != id_token_b)
+    }
+
+    #[test]
+    fn test_id_token_keeps_original_case() {
+        let id_token = IdToken::try_from("AbCd-01234").unwrap();
+
+        assert_eq!(id_token.as_str(), "AbCd-01234");
+        assert_eq!(id_token.to_string(), "AbCd-01234");
+    }
+
+    #[test]
+    fn test_invalid_id_tag_is_property_constraint_violation() {
+        let payload = serde_json::json!({ "idTag": "a".repeat(21) });
+        let err = match serde_json::from_value::<AuthorizationData>(payload) {
+            Err(err) => err,
+            Ok(_) => panic!("expected deserialization to fail"),
+        };
 
-        assert_eq!(false, id_token_a == id_token_b)
+        let err = OcppError::from_payload_error("SendLocalList", err);
+
+        assert_eq!(err.code(), ErrorCode::PropertyConstraintViolation);
+    }
+
+    #[test]
+    fn test_status_at_expiry_date() {
+        use chrono::TimeZone;
+
+        let expiry_date = Utc.with_ymd_and_hms(2024, 3, 4, 12, 0, 0).unwrap();
+        let info = IdTagInfo::builder(AuthorizationStatus::Accepted)
+            .expiry_date(expiry_date)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            info.status_at(expiry_date - chrono::Duration::seconds(1)),
+            AuthorizationStatus::Accepted
+        );
+        assert_eq!(info.status_at(expiry_date), AuthorizationStatus::Expired);
     }
 }
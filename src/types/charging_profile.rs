@@ -1,18 +1,24 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
 
 use super::enumerations::{
     ChargingProfileKindType, ChargingProfilePurposeType, ChargingRateUnitType, RecurrencyKindType,
 };
+use crate::error::OcppError;
+use crate::messages::Validate;
 
 /// A ChargingProfile consists of a ChargingSchedule, describing
 /// the amount of power or current that can be delivered
 /// per time interval.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ChargingProfile {
     /// Unique identifier for this profile.
     charging_profile_id: usize,
     /// Only valid if ChargingProfilePurpose is set
     /// to TxProfile, the transactionId MAY be used to
     /// match the profile to a specific transaction_id.
+    #[serde(skip_serializing_if = "Option::is_none")]
     transaction_id: Option<usize>,
     /// Value determinig level in hierarchy stack of profiles.
     /// Higher values have precedence over lower values.
@@ -20,27 +26,283 @@ pub struct ChargingProfile {
     stack_level: usize,
     charging_profile_purpose: ChargingProfilePurposeType,
     /// Indicates the kind of schedule.
-    charging_profile_king: ChargingProfileKindType,
+    charging_profile_kind: ChargingProfileKindType,
     /// Indicates the start point of a recurrence.
+    #[serde(skip_serializing_if = "Option::is_none")]
     recurrency_kind: Option<RecurrencyKindType>,
+    /// Point in time at which the profile starts to be valid.
+    /// If absent, the profile is valid as soon as it is received
+    /// by the Charge Point. Not to be used when ChargingProfilePurpose
+    /// is TxProfile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    valid_from: Option<DateTime<Utc>>,
     /// Point in time at which the profile stops to be valid.
     /// If absent, the profile is valid until it is replaced
     /// by another profile. Not to be used when ChargingProfilePurpose
     /// is TxProfile.
-    valid_from: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    valid_to: Option<DateTime<Utc>>,
     /// Contains limits for the available power or current over time.
     charging_schedule: ChargingSchedule,
 }
 
+impl ChargingProfile {
+    pub fn builder(
+        charging_profile_id: usize,
+        stack_level: usize,
+        charging_profile_purpose: ChargingProfilePurposeType,
+        charging_profile_kind: ChargingProfileKindType,
+        charging_schedule: ChargingSchedule,
+    ) -> ChargingProfileBuilder {
+        ChargingProfileBuilder {
+            inner: ChargingProfile {
+                charging_profile_id,
+                stack_level,
+                charging_profile_purpose,
+                charging_profile_kind,
+                charging_schedule,
+                transaction_id: None,
+                recurrency_kind: None,
+                valid_from: None,
+                valid_to: None,
+            },
+        }
+    }
+
+    pub fn charging_profile_id(&self) -> usize {
+        self.charging_profile_id
+    }
+
+    pub fn transaction_id(&self) -> Option<usize> {
+        self.transaction_id
+    }
+
+    pub fn stack_level(&self) -> usize {
+        self.stack_level
+    }
+
+    pub fn charging_profile_purpose(&self) -> ChargingProfilePurposeType {
+        self.charging_profile_purpose
+    }
+
+    pub fn charging_profile_kind(&self) -> ChargingProfileKindType {
+        self.charging_profile_kind
+    }
+
+    pub fn recurrency_kind(&self) -> Option<RecurrencyKindType> {
+        self.recurrency_kind
+    }
+
+    pub fn valid_from(&self) -> Option<DateTime<Utc>> {
+        self.valid_from
+    }
+
+    pub fn valid_to(&self) -> Option<DateTime<Utc>> {
+        self.valid_to
+    }
+
+    pub fn charging_schedule(&self) -> &ChargingSchedule {
+        &self.charging_schedule
+    }
+
+    /// Resolves the schedule into the absolute periods that fall between
+    /// `from` and `to`, clipped to that window and to the validity of
+    /// the profile.
+    ///
+    /// The schedule starts at:
+    ///
+    /// * startSchedule for an Absolute profile;
+    /// * `transaction_start` for a Relative profile, or `from` when no
+    ///   transaction is running;
+    /// * startSchedule for a Recurring profile, repeated every day or
+    ///   week in UTC, so that daylight saving time does not shift the
+    ///   recurrence. An occurrence ends when the next one starts.
+    pub fn absolute_periods(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        transaction_start: Option<DateTime<Utc>>,
+    ) -> Vec<AbsolutePeriod> {
+        let from = self.valid_from.map_or(from, |valid| valid.max(from));
+        let to = self.valid_to.map_or(to, |valid| valid.min(to));
+        if from >= to {
+            return Vec::new();
+        }
+
+        let schedule = &self.charging_schedule;
+        let start_schedule = schedule.start_schedule;
+
+        let mut starts = Vec::new();
+        let mut recurrence = None;
+        match (self.charging_profile_kind, start_schedule) {
+            (ChargingProfileKindType::Relative, _) => {
+                starts.push(transaction_start.unwrap_or(from))
+            }
+            (ChargingProfileKindType::Absolute, Some(start)) => starts.push(start),
+            (ChargingProfileKindType::Recurring, Some(start)) => {
+                let period = match self.recurrency_kind {
+                    Some(RecurrencyKindType::Weekly) => Duration::weeks(1),
+                    _ => Duration::days(1),
+                };
+                let mut occurrence = if from > start {
+                    (from - start).num_seconds() / period.num_seconds()
+                } else {
+                    0
+                };
+                loop {
+                    let begin = i32::try_from(occurrence)
+                        .ok()
+                        .and_then(|occurrence| period.checked_mul(occurrence))
+                        .and_then(|offset| start.checked_add_signed(offset));
+                    let Some(begin) = begin.filter(|begin| *begin < to) else {
+                        break;
+                    };
+                    starts.push(begin);
+                    occurrence += 1;
+                }
+                recurrence = Some(period);
+            }
+            // Rejected by the validation of the profile.
+            (_, None) => return Vec::new(),
+        }
+
+        let mut periods = Vec::new();
+        for begin in starts {
+            let end = [
+                schedule.duration.map(|duration| after(begin, duration)),
+                recurrence.map(|period| {
+                    begin
+                        .checked_add_signed(period)
+                        .unwrap_or(DateTime::<Utc>::MAX_UTC)
+                }),
+            ]
+            .into_iter()
+            .flatten()
+            .fold(to, DateTime::min);
+
+            let schedule_periods = &schedule.charging_schedule_period;
+            for (index, period) in schedule_periods.iter().enumerate() {
+                let period_end = schedule_periods
+                    .get(index + 1)
+                    .map_or(end, |next| after(begin, next.start_period))
+                    .min(end);
+                let period_start = after(begin, period.start_period).max(from);
+                if period_start < period_end {
+                    periods.push(AbsolutePeriod {
+                        start: period_start,
+                        end: period_end,
+                        limit: period.limit,
+                        number_phases: period.number_phases,
+                    });
+                }
+            }
+        }
+
+        periods
+    }
+
+    /// Limit imposed by the profile at `instant`, or `None` if the
+    /// profile does not apply at that time.
+    ///
+    /// See [`ChargingProfile::absolute_periods`] for how the schedule
+    /// is placed in time.
+    pub fn limit_at(
+        &self,
+        instant: DateTime<Utc>,
+        transaction_start: Option<DateTime<Utc>>,
+    ) -> Option<f64> {
+        let next = instant
+            .checked_add_signed(Duration::nanoseconds(1))
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+        self.absolute_periods(instant, next, transaction_start)
+            .first()
+            .map(|period| period.limit)
+    }
+}
+
+/// Builder for ChargingProfile, created by [`ChargingProfile::builder`].
+#[derive(Debug, Clone)]
+pub struct ChargingProfileBuilder {
+    inner: ChargingProfile,
+}
+
+impl ChargingProfileBuilder {
+    pub fn transaction_id(mut self, transaction_id: usize) -> ChargingProfileBuilder {
+        self.inner.transaction_id = Some(transaction_id);
+        self
+    }
+
+    pub fn recurrency_kind(
+        mut self,
+        recurrency_kind: RecurrencyKindType,
+    ) -> ChargingProfileBuilder {
+        self.inner.recurrency_kind = Some(recurrency_kind);
+        self
+    }
+
+    pub fn valid_from(mut self, valid_from: DateTime<Utc>) -> ChargingProfileBuilder {
+        self.inner.valid_from = Some(valid_from);
+        self
+    }
+
+    pub fn valid_to(mut self, valid_to: DateTime<Utc>) -> ChargingProfileBuilder {
+        self.inner.valid_to = Some(valid_to);
+        self
+    }
+
+    /// Finishes the ChargingProfile, checking its constraints.
+    pub fn build(self) -> Result<ChargingProfile, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for ChargingProfile {
+    fn validate(&self) -> Result<(), OcppError> {
+        if self.transaction_id.is_some()
+            && self.charging_profile_purpose != ChargingProfilePurposeType::TxProfile
+        {
+            return Err(OcppError::PropertyConstraintViolation(String::from(
+                "transactionId is only allowed in a TxProfile",
+            )));
+        }
+
+        let recurring = self.charging_profile_kind == ChargingProfileKindType::Recurring;
+        if recurring != self.recurrency_kind.is_some() {
+            return Err(OcppError::OccurenceConstraintViolation(String::from(
+                "recurrencyKind must be present exactly when chargingProfileKind is Recurring",
+            )));
+        }
+
+        // Only Relative profiles start with the transaction, the others
+        // need a fixed starting point to give the same limits whatever
+        // the window they are resolved in.
+        if self.charging_profile_kind != ChargingProfileKindType::Relative
+            && self.charging_schedule.start_schedule.is_none()
+        {
+            return Err(OcppError::OccurenceConstraintViolation(String::from(
+                "startSchedule is required unless chargingProfileKind is Relative",
+            )));
+        }
+
+        self.charging_schedule.validate()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ChargingSchedule {
     /// Duration of the charging schedule in seconds.
     /// If the duration is left empty, the last period
     /// will continue indefinitely or unitl end of the
     /// transaciton in case startSchedule is absent.
+    #[serde(skip_serializing_if = "Option::is_none")]
     duration: Option<usize>,
     /// Starting point of an absolude scheduel.
     /// If absent the schedule will be relative to
     /// start of charging.
+    #[serde(skip_serializing_if = "Option::is_none")]
     start_schedule: Option<DateTime<Utc>>,
     /// The unit of measure Limit is expressed in.
     charging_rate_unit: ChargingRateUnitType,
@@ -53,9 +315,109 @@ pub struct ChargingSchedule {
     /// algorithm to optimize the power allocation for in the case
     /// a charging process is inefficient at lower charging rates.
     /// Accepts at most one digit fraction.
+    #[serde(skip_serializing_if = "Option::is_none")]
     min_charging_rate: Option<f64>,
 }
 
+impl ChargingSchedule {
+    pub fn builder(
+        charging_rate_unit: ChargingRateUnitType,
+        charging_schedule_period: Vec<ChargingSchedulePeriod>,
+    ) -> ChargingScheduleBuilder {
+        ChargingScheduleBuilder {
+            inner: ChargingSchedule {
+                charging_rate_unit,
+                charging_schedule_period,
+                duration: None,
+                start_schedule: None,
+                min_charging_rate: None,
+            },
+        }
+    }
+
+    pub fn duration(&self) -> Option<usize> {
+        self.duration
+    }
+
+    pub fn start_schedule(&self) -> Option<DateTime<Utc>> {
+        self.start_schedule
+    }
+
+    pub fn charging_rate_unit(&self) -> ChargingRateUnitType {
+        self.charging_rate_unit
+    }
+
+    pub fn charging_schedule_period(&self) -> &[ChargingSchedulePeriod] {
+        &self.charging_schedule_period
+    }
+
+    pub fn min_charging_rate(&self) -> Option<f64> {
+        self.min_charging_rate
+    }
+}
+
+/// Builder for ChargingSchedule, created by [`ChargingSchedule::builder`].
+#[derive(Debug, Clone)]
+pub struct ChargingScheduleBuilder {
+    inner: ChargingSchedule,
+}
+
+impl ChargingScheduleBuilder {
+    pub fn duration(mut self, duration: usize) -> ChargingScheduleBuilder {
+        self.inner.duration = Some(duration);
+        self
+    }
+
+    pub fn start_schedule(mut self, start_schedule: DateTime<Utc>) -> ChargingScheduleBuilder {
+        self.inner.start_schedule = Some(start_schedule);
+        self
+    }
+
+    pub fn min_charging_rate(mut self, min_charging_rate: f64) -> ChargingScheduleBuilder {
+        self.inner.min_charging_rate = Some(min_charging_rate);
+        self
+    }
+
+    /// Finishes the ChargingSchedule, checking its constraints.
+    pub fn build(self) -> Result<ChargingSchedule, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for ChargingSchedule {
+    fn validate(&self) -> Result<(), OcppError> {
+        match self.charging_schedule_period.first() {
+            None => {
+                return Err(OcppError::OccurenceConstraintViolation(String::from(
+                    "chargingSchedulePeriod must contain at least one element",
+                )))
+            }
+            Some(period) if period.start_period != 0 => {
+                return Err(OcppError::PropertyConstraintViolation(String::from(
+                    "the first chargingSchedulePeriod must start at 0",
+                )))
+            }
+            _ => {}
+        }
+
+        let ascending = self
+            .charging_schedule_period
+            .windows(2)
+            .all(|pair| pair[0].start_period < pair[1].start_period);
+        if !ascending {
+            return Err(OcppError::PropertyConstraintViolation(String::from(
+                "chargingSchedulePeriod must be ordered by increasing startPeriod",
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ChargingSchedulePeriod {
     /// Start of the period, in seconds from the
     /// start of schedule. The value of StartPeriod
@@ -68,5 +430,379 @@ pub struct ChargingSchedulePeriod {
     /// The number of phases that can be used for charging.
     /// If a number of phases is needed, numberPhases = 3
     /// will be assumed unless another number is given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    number_phases: Option<usize>,
+}
+
+impl ChargingSchedulePeriod {
+    pub fn builder(start_period: usize, limit: f64) -> ChargingSchedulePeriodBuilder {
+        ChargingSchedulePeriodBuilder {
+            inner: ChargingSchedulePeriod {
+                start_period,
+                limit,
+                number_phases: None,
+            },
+        }
+    }
+
+    pub fn start_period(&self) -> usize {
+        self.start_period
+    }
+
+    pub fn limit(&self) -> f64 {
+        self.limit
+    }
+
+    pub fn number_phases(&self) -> Option<usize> {
+        self.number_phases
+    }
+}
+
+/// Builder for ChargingSchedulePeriod, created by [`ChargingSchedulePeriod::builder`].
+#[derive(Debug, Clone)]
+pub struct ChargingSchedulePeriodBuilder {
+    inner: ChargingSchedulePeriod,
+}
+
+impl ChargingSchedulePeriodBuilder {
+    pub fn number_phases(mut self, number_phases: usize) -> ChargingSchedulePeriodBuilder {
+        self.inner.number_phases = Some(number_phases);
+        self
+    }
+
+    /// Finishes the ChargingSchedulePeriod, checking its constraints.
+    pub fn build(self) -> Result<ChargingSchedulePeriod, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for ChargingSchedulePeriod {}
+
+/// Period of a charging schedule placed in absolute time, as resolved
+/// by [`ChargingProfile::absolute_periods`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AbsolutePeriod {
+    /// Start of the period, inclusive.
+    start: DateTime<Utc>,
+    /// End of the period, exclusive.
+    end: DateTime<Utc>,
+    /// Limit during the period, in the unit of the schedule.
+    limit: f64,
+    /// The number of phases that can be used for charging.
     number_phases: Option<usize>,
 }
+
+impl AbsolutePeriod {
+    pub fn start(&self) -> DateTime<Utc> {
+        self.start
+    }
+
+    pub fn end(&self) -> DateTime<Utc> {
+        self.end
+    }
+
+    pub fn limit(&self) -> f64 {
+        self.limit
+    }
+
+    pub fn number_phases(&self) -> Option<usize> {
+        self.number_phases
+    }
+
+    /// Whether `instant` falls within the period.
+    pub fn contains(&self, instant: DateTime<Utc>) -> bool {
+        self.start <= instant && instant < self.end
+    }
+}
+
+/// Instant `seconds` after `instant`, saturating at the latest
+/// representable one, so that schedules lasting for ages are treated
+/// as lasting forever.
+fn after(instant: DateTime<Utc>, seconds: usize) -> DateTime<Utc> {
+    i64::try_from(seconds)
+        .ok()
+        .and_then(Duration::try_seconds)
+        .and_then(|offset| instant.checked_add_signed(offset))
+        .unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, day, hour, 0, 0).unwrap()
+    }
+
+    fn schedule(periods: &[(usize, f64)]) -> ChargingScheduleBuilder {
+        let periods = periods
+            .iter()
+            .map(|(start, limit)| ChargingSchedulePeriod::builder(*start, *limit).build())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        ChargingSchedule::builder(ChargingRateUnitType::A, periods)
+    }
+
+    fn profile(
+        kind: ChargingProfileKindType,
+        schedule: ChargingScheduleBuilder,
+    ) -> ChargingProfileBuilder {
+        ChargingProfile::builder(
+            1,
+            0,
+            ChargingProfilePurposeType::TxDefaultProfile,
+            kind,
+            schedule.build().unwrap(),
+        )
+    }
+
+    fn spans(periods: &[AbsolutePeriod]) -> Vec<(DateTime<Utc>, DateTime<Utc>, f64)> {
+        periods
+            .iter()
+            .map(|period| (period.start(), period.end(), period.limit()))
+            .collect()
+    }
+
+    #[test]
+    fn test_absolute_profile() {
+        let profile = profile(
+            ChargingProfileKindType::Absolute,
+            schedule(&[(0, 10.0), (3600, 20.0)])
+                .start_schedule(at(4, 8))
+                .duration(3 * 3600),
+        )
+        .build()
+        .unwrap();
+
+        let periods = profile.absolute_periods(at(4, 0), at(5, 0), None);
+
+        assert_eq!(
+            spans(&periods),
+            vec![(at(4, 8), at(4, 9), 10.0), (at(4, 9), at(4, 11), 20.0)]
+        );
+    }
+
+    #[test]
+    fn test_periods_are_clipped_to_window() {
+        let profile = profile(
+            ChargingProfileKindType::Absolute,
+            schedule(&[(0, 10.0), (3600, 20.0)]).start_schedule(at(4, 8)),
+        )
+        .build()
+        .unwrap();
+
+        let periods = profile.absolute_periods(at(4, 8) + Duration::minutes(30), at(4, 10), None);
+
+        assert_eq!(
+            spans(&periods),
+            vec![
+                (at(4, 8) + Duration::minutes(30), at(4, 9), 10.0),
+                (at(4, 9), at(4, 10), 20.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validity_is_honored() {
+        let profile = profile(
+            ChargingProfileKindType::Absolute,
+            schedule(&[(0, 10.0)]).start_schedule(at(1, 0)),
+        )
+        .valid_from(at(4, 6))
+        .valid_to(at(4, 18))
+        .build()
+        .unwrap();
+
+        let periods = profile.absolute_periods(at(4, 0), at(5, 0), None);
+
+        assert_eq!(spans(&periods), vec![(at(4, 6), at(4, 18), 10.0)]);
+        assert_eq!(profile.limit_at(at(4, 5), None), None);
+        assert_eq!(profile.limit_at(at(4, 6), None), Some(10.0));
+        assert_eq!(profile.limit_at(at(4, 18), None), None);
+    }
+
+    #[test]
+    fn test_relative_profile_starts_with_transaction() {
+        let profile = profile(
+            ChargingProfileKindType::Relative,
+            schedule(&[(0, 10.0), (3600, 20.0)]).duration(2 * 3600),
+        )
+        .build()
+        .unwrap();
+
+        let periods = profile.absolute_periods(at(4, 0), at(5, 0), Some(at(4, 12)));
+
+        assert_eq!(
+            spans(&periods),
+            vec![(at(4, 12), at(4, 13), 10.0), (at(4, 13), at(4, 14), 20.0)]
+        );
+    }
+
+    #[test]
+    fn test_relative_profile_without_transaction_starts_at_window() {
+        let profile = profile(ChargingProfileKindType::Relative, schedule(&[(0, 10.0)]))
+            .build()
+            .unwrap();
+
+        let periods = profile.absolute_periods(at(4, 3), at(4, 4), None);
+
+        assert_eq!(spans(&periods), vec![(at(4, 3), at(4, 4), 10.0)]);
+    }
+
+    #[test]
+    fn test_daily_recurring_profile() {
+        let profile = profile(
+            ChargingProfileKindType::Recurring,
+            schedule(&[(0, 32.0), (18 * 3600, 16.0), (22 * 3600, 32.0)]).start_schedule(at(1, 0)),
+        )
+        .recurrency_kind(RecurrencyKindType::Daily)
+        .build()
+        .unwrap();
+
+        let periods = profile.absolute_periods(at(4, 12), at(5, 20), None);
+
+        assert_eq!(
+            spans(&periods),
+            vec![
+                (at(4, 12), at(4, 18), 32.0),
+                (at(4, 18), at(4, 22), 16.0),
+                (at(4, 22), at(5, 0), 32.0),
+                (at(5, 0), at(5, 18), 32.0),
+                (at(5, 18), at(5, 20), 16.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_weekly_recurring_profile() {
+        // Monday 4 March 2024, limited for the first 12 hours of each week.
+        let profile = profile(
+            ChargingProfileKindType::Recurring,
+            schedule(&[(0, 6.0)])
+                .start_schedule(at(4, 0))
+                .duration(12 * 3600),
+        )
+        .recurrency_kind(RecurrencyKindType::Weekly)
+        .build()
+        .unwrap();
+
+        assert_eq!(profile.limit_at(at(11, 6), None), Some(6.0));
+        assert_eq!(profile.limit_at(at(11, 12), None), None);
+        assert_eq!(profile.limit_at(at(12, 6), None), None);
+        assert_eq!(profile.limit_at(at(18, 11), None), Some(6.0));
+    }
+
+    #[test]
+    fn test_recurrence_ignores_daylight_saving_time() {
+        // Europe switches to summer time on 31 March 2024, the UTC
+        // recurrence is not affected.
+        let profile = profile(
+            ChargingProfileKindType::Recurring,
+            schedule(&[(0, 8.0), (3600, 16.0)]).start_schedule(at(1, 2)),
+        )
+        .recurrency_kind(RecurrencyKindType::Daily)
+        .build()
+        .unwrap();
+
+        assert_eq!(profile.limit_at(at(31, 2), None), Some(8.0));
+        assert_eq!(profile.limit_at(at(31, 3), None), Some(16.0));
+    }
+
+    #[test]
+    fn test_recurring_profile_before_start_schedule() {
+        let profile = profile(
+            ChargingProfileKindType::Recurring,
+            schedule(&[(0, 8.0)]).start_schedule(at(10, 0)),
+        )
+        .recurrency_kind(RecurrencyKindType::Daily)
+        .build()
+        .unwrap();
+
+        assert_eq!(profile.limit_at(at(9, 12), None), None);
+        assert_eq!(profile.limit_at(at(10, 12), None), Some(8.0));
+    }
+
+    #[test]
+    fn test_start_schedule_is_required() {
+        let absolute = profile(ChargingProfileKindType::Absolute, schedule(&[(0, 8.0)])).build();
+        let recurring = profile(ChargingProfileKindType::Recurring, schedule(&[(0, 8.0)]))
+            .recurrency_kind(RecurrencyKindType::Daily)
+            .build();
+
+        assert!(matches!(
+            absolute,
+            Err(OcppError::OccurenceConstraintViolation(_))
+        ));
+        assert!(matches!(
+            recurring,
+            Err(OcppError::OccurenceConstraintViolation(_))
+        ));
+    }
+
+    #[test]
+    fn test_overlapping_windows_agree() {
+        let profile = profile(
+            ChargingProfileKindType::Recurring,
+            schedule(&[(0, 32.0), (18 * 3600, 16.0)]).start_schedule(at(1, 0)),
+        )
+        .recurrency_kind(RecurrencyKindType::Daily)
+        .build()
+        .unwrap();
+
+        let day = profile.absolute_periods(at(4, 0), at(5, 0), None);
+        let evening = profile.absolute_periods(at(4, 17), at(4, 20), None);
+
+        assert_eq!(
+            spans(&evening),
+            vec![(at(4, 17), at(4, 18), 32.0), (at(4, 18), at(4, 20), 16.0)]
+        );
+        for instant in [at(4, 17), at(4, 18), at(4, 19)] {
+            let limit = |periods: &[AbsolutePeriod]| {
+                periods
+                    .iter()
+                    .find(|period| period.start() <= instant && instant < period.end())
+                    .map(AbsolutePeriod::limit)
+            };
+            assert_eq!(limit(&day), limit(&evening));
+        }
+    }
+
+    #[test]
+    fn test_huge_durations_last_forever() {
+        for duration in [100_000_000_000_000, 9_300_000_000_000_000, usize::MAX] {
+            let absolute = profile(
+                ChargingProfileKindType::Absolute,
+                schedule(&[(0, 10.0), (usize::MAX, 20.0)])
+                    .start_schedule(at(4, 8))
+                    .duration(duration),
+            )
+            .build()
+            .unwrap();
+            let recurring = profile(
+                ChargingProfileKindType::Recurring,
+                schedule(&[(0, 10.0)])
+                    .start_schedule(at(4, 8))
+                    .duration(duration),
+            )
+            .recurrency_kind(RecurrencyKindType::Weekly)
+            .build()
+            .unwrap();
+
+            assert_eq!(absolute.limit_at(at(5, 0), None), Some(10.0));
+            assert_eq!(recurring.limit_at(at(5, 0), None), Some(10.0));
+            assert_eq!(
+                spans(&recurring.absolute_periods(at(5, 0), at(19, 0), None)),
+                vec![
+                    (at(5, 0), at(11, 8), 10.0),
+                    (at(11, 8), at(18, 8), 10.0),
+                    (at(18, 8), at(19, 0), 10.0)
+                ]
+            );
+        }
+    }
+}
@@ -1,12 +1,65 @@
+use serde::{Deserialize, Serialize};
+
 use super::utils::{CiString500Type, CiString50Type};
+use crate::error::OcppError;
+use crate::messages::Validate;
 
 /// Contains information about a specific configuration key.
 /// It is returned in GetConfiguration.conf.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct KeyValue {
     key: CiString50Type,
     /// False if the value can be set with the
     /// ChangeConfiguration message
     readonly: bool,
     /// If key is known but not set, this field may be absent.
+    #[serde(skip_serializing_if = "Option::is_none")]
     value: Option<CiString500Type>,
 }
+
+impl KeyValue {
+    pub fn builder(key: CiString50Type, readonly: bool) -> KeyValueBuilder {
+        KeyValueBuilder {
+            inner: KeyValue {
+                key,
+                readonly,
+                value: None,
+            },
+        }
+    }
+
+    pub fn key(&self) -> &CiString50Type {
+        &self.key
+    }
+
+    pub fn readonly(&self) -> bool {
+        self.readonly
+    }
+
+    pub fn value(&self) -> Option<&CiString500Type> {
+        self.value.as_ref()
+    }
+}
+
+/// Builder for KeyValue, created by [`KeyValue::builder`].
+#[derive(Debug, Clone)]
+pub struct KeyValueBuilder {
+    inner: KeyValue,
+}
+
+impl KeyValueBuilder {
+    pub fn value(mut self, value: CiString500Type) -> KeyValueBuilder {
+        self.inner.value = Some(value);
+        self
+    }
+
+    /// Finishes the KeyValue, checking its constraints.
+    pub fn build(self) -> Result<KeyValue, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for KeyValue {}
@@ -1,571 +1,1246 @@
-/// Status in a response to an Authorize.req
-pub enum AuthorizationStatus {
-    /// Identifier is allowed for charging
-    Accepted,
-    /// Identifier has been blocked. Not allowed for charging.
-    Blocked,
-    /// Identifier has expired. Not allowed for charging.
-    Expired,
-    /// Identifier is unknown. Not allowed for charging
-    Invalid,
-    /// Identifier is already involved in another transaction and multiple
-    /// transactions are not allowed.
-    ConcurrentTx,
-}
-
-/// Status returned in response to ChangeAvailability.req
-pub enum AvailabilityStatus {
-    /// Request has been accepted and will be executed.
-    Accepted,
-    /// Request has not been accepted and will not be executed.
-    Rejected,
-    /// Request has been accepted and will be executed when transactions(s)
-    /// in progress have finished.
-    Scheduled,
-}
-
-/// Request availability change in ChangeAvailability.req
-pub enum AvailabilityType {
-    /// Charge point is not available for charging.
-    Inoperative,
-    /// Charge point is available for charging.
-    Operative,
-}
-
-/// Status in CancelReservation.conf
-pub enum CancelReservationStatus {
-    /// Reservation for the identifier has been cancelled.
-    Accepted,
-    /// Reservation could not be cancelled, because there is no reservation
-    /// active for the identifier.
-    Rejected,
-}
-
-/// Charge Point status reported in StatusNotification.req
-pub enum ChargePointErrorCode {
-    /// Failure to lock or unlock connector.
-    ConnectorLockFailure,
-    /// Communication failure with the vehicle, migh be Mode 3 or other
-    /// communication protocol problem. This is not a real error in the
-    /// sense that the Charge Point doesn't need to go to the faulted
-    /// state. Instead, it should go to the SuspendedEVSE state.
-    EVCommunicationError,
-    /// Ground fault circuit interrupter has been activated.
-    GroundFailure,
-    /// Temperature inside Charge Point is too high.
-    HighTemperature,
-    /// Error in internal hard- or software component.
-    InternalError,
-    /// The authorization information received from the Central System
-    /// is in conflict with the LocalAuthorizationList.
-    LocalListConflict,
-    /// No error to report.
-    NoError,
-    /// Other type of error. More information in vendorErrorCode.
-    OtherError,
-    /// Over current protection device has tripped.
-    OverCurrentFailure,
-    /// Voltage has risen above an acceptable level.
-    OverVoltage,
-    /// Failure to read power meter.
-    PowerMeterFailure,
-    /// Failure to control power switch.
-    PowerSwitchFailure,
-    /// Failure with idTag reader.
-    ReaderFailure,
-    /// Unable to perform a reset.
-    ResetFailure,
-    /// Voltage has dropped below an acceptable level.
-    UnderVoltage,
-    /// Wireless communication device reports a weak signal.
-    WeakSignal,
-}
-
-/// Status reported in StatusNotification.req. A status can be reported for the
-/// Charge Point main controller (connectorId = 0) or for a specific connector.
-/// Status for the Charge Point main controller is a subset of the enumeration:
-/// Available, Unavailable or Faulted.
-///
-/// Stated considered Operative are: Available, Preparing, Charging, SuspendedEVSE,
-/// SuspendedEV, Finishing, Reserved. States considered Inoperative are:
-/// Unavailable, Faulted.
-pub enum ChargePointStatus {
-    /// When a Connector becomes available for a new user. (Operative)
-    Available,
-    /// When a Connector becomes no longer available for a new user
-    /// but no charging session is active. Typically a Connector is
-    /// occupied when a user presents a tag, inserts a cable or a
-    /// vehicle occupies the parking bay. (Operative)
-    Preparing,
-    /// When the contactor of a Connector closes, allowing the
-    /// vehicle to charge. (Operative)
-    Charging,
-    /// When the contactor of a Connector opens upon request of the
-    /// EVSE, e.g. due to a smart charging restriction or as the result
-    /// of StartTransaction.conf indicating that charging is not allowed.
-    /// (Operative)
-    SuspendedEVSE,
-    /// When the EVSE is ready to deliver energy but contactor is open,
-    /// e.g. the EV is not ready. (Operative)
-    SuspendedEV,
-    /// When a charging session has stopped at a Connector, but the Connector
-    /// is not yet available for a new user, e.g. the cable has not been removed
-    /// or the vehicle has not left the parking bay. (Operative)
-    Finishing,
-    /// When a Connector becomes reserved as a result of a Reserve Now command.
-    /// (Operative)
-    Reserved,
-    /// When a Conenctor becomes unavailable as the result of a Change Availability
-    /// command or an event upon which the Charge Point transaitions to unavailable
-    /// at its discretion. Upon receipt of a Chage Availability command, the status
-    /// MAY be scheduled. When scheduled, the Status Nofitication shall be send when
-    /// the availability change becomes effective. (Inoperative)
-    Unavailable,
-    /// When a Charge Point or connector has reported an error and is not available
-    /// for energy delivery. (Inoperative)
-    Faulted,
-}
-
-pub enum ChargingProfileKindType {
-    /// Schedule periods are relative to a fixed point in time defined in the schedule.
-    Absolute,
-    /// The schedule restarts periodically at the first schedule period.
-    Recurring,
-    /// Schedule periods are relative to a situation-specific start point (such as the
-    /// start of a session) that is determined by the charge point.
-    Relative,
-}
-
-pub enum ChargingProfilePurposeType {
-    /// Configuration for the maximum power or current available for an entire Charge Point.
-    /// SetChargingProfile.req message.
-    ChargePointMaxProfile,
-    /// Default profile to be used for new transactions.
-    TxDefaultProfile,
-    /// Profile with constraints to be imposed by the Charge Point on the current transaction.
-    /// A profile with this purpose SHALL cease to be valid when the transaction terminates.
-    TxProfile,
-}
-
-/// Status returned in response to SetChargingProfile.req.
-pub enum ChargingProfileStatus {
-    /// Request has been accepted and will be executed.
-    Accepted,
-    /// Request has not been accepted and will not be executed.
-    Rejected,
-    /// Charge Point indicates that the request is not supported.
-    NotSupported,
-}
-
-/// Unit in which a charging schedule is defined, as used in: GetCompositeSchedule.req and
-/// ChargingSchedule.
-pub enum ChargingRateUnitType {
-    /// Watts (power).
-    W,
-    /// Amperes (current).
-    A,
-}
-
-/// Status returned in response to ClearCache.req.
-pub enum ClearCacheStatus {
-    /// Command has been executed.
-    Accepted,
-    /// Command has not been executed.
-    Rejected,
-}
-
-/// Status returned in response to ClearChargingProfile.req.
-pub enum ClearChargingProfileStatus {
-    /// Request has been accepted and will be executed.
-    Accepted,
-    /// No CHarging Profile(s) were found matching the request.
-    Unknown,
-}
-
-/// Status in ChangeConfiguration.conf.
-pub enum ConfigurationStatus {
-    /// Configuration key supported and setting has been changed.
-    Accepted,
-    /// Configuration key supported, but setting could not be changed.
-    Rejected,
-    /// Configuration key supported and setting has been changed,
-    /// bug change will be available after reboot (Charge Point
-    /// will not reboot itself).
-    RebootRequired,
-    /// Configuration key is not supported.
-    NotSupported,
-}
-
-/// Status in DataTransfer.conf.
-pub enum DataTransferStatus {
-    /// Message has been accepted and the contained request is accepted.
-    Accepted,
-    /// Message has been accepted but the contained request is rejected.
-    Rejected,
-    /// Message could not be interpreted due to unknown messageId string.
-    UnknownMessageId,
-    /// Message could not be interpreted due to unknown vendorId string.
-    UnknownVendorId,
-}
-
-/// Status in DiagnosticsStatusNotification.req.
-pub enum DiagnosticsStatus {
-    /// Charge Point is not performing diagnostics related tasks.
-    /// Status Idle SHALL only be used as in a DiagnosticsStatusNotification.req
-    /// that was triggered by a TriggerMessage.req.
-    Idle,
-    /// Diagnostics information has been uploaded.
-    Uploaded,
-    /// Uploading of diagnostics failed.
-    UploadFailed,
-    /// File is being uploaded.
-    Uploading,
-}
-
-/// Status of firmware download as reported in FirmwareStatusNotification.req.
-pub enum FirmwareStatus {
-    /// New firmware has been downloaded by Charge Point.
-    Downloaded,
-    /// Charge point failed to download firmware.
-    DownloadFailed,
-    /// Firmware is being downloaded.
-    Downloading,
-    /// Charge Point is not performing fimrware update related tasks.
-    /// Status Idle SHALL only be used as in a FirmwareStatusNotification.req
-    /// that was triggered by a TriggerMessage.req.
-    Idle,
-    /// Installation of new firmware has failed.
-    InstallationFailed,
-    /// Firmware is being installed.
-    Installing,
-    /// New firmware has successfully been installed in charge point.
-    Installed,
-}
-
-/// Status returned in response to GetCompositeSchedule.req.
-pub enum GetCompositeScheduleStatus {
-    /// Request has been accepted and will be executed.
-    Accepted,
-    /// Request has not been accepted and will not be executed.
-    Rejected,
-}
-
-/// Allowable values of the optional "location" field of a value element in SampledValue.
-pub enum Location {
-    /// Measurement inside body of Charge Point (e.g Temperature).
-    Body,
-    /// Measurement taken from cable between EV and Charge Point.
-    Cable,
-    /// Measurement taken by EV.
-    EV,
-    /// Measurement at network ("grid") inlet connection.
-    Inlet,
-    /// Measurement at a Connector. Default value.
-    Outlet,
-}
-
-/// Allowable values of the optional "measurand" field of a Value element,
-/// as used in MeterValues.req and StopTransaction.req messages.
-/// Default value of "measurand" is always "Energy.Active.Import.Register".
-pub enum Measurand {
-    /// Instantaneous current flow from EV.
-    CurrentExport,
-    /// Instantaneous current flow to EV.
-    CurrentImport,
-    /// Maximum current offered to EV.
-    CurrentOffered,
-    /// Energy exported by EV (Wh or kWh).
-    EnergyActiveExportRegister,
-    /// Enegy imported by EV (Wh or kWh).
-    EnergyActiveImportRegister,
-    /// Reactive energy exported by EV (varh or kvarh).
-    EnergyReactiveExportRegister,
-    /// Reactive energy imported by EV (varh or kvarh).
-    EnergyReactiveImportRegister,
-    /// Energy exported by EV (Wh or kWh).
-    EnergyActiveExportInterval,
-    /// Energy imported by EV (Wh or kWh).
-    EnergyActiveImportInterval,
-    /// Reactive energy exported by EV (varh or kvarh).
-    EnergyReactiveExportInterval,
-    /// Reactive energy imported by EV (varh or kvarh).
-    EnergyReactiveImportInterval,
-    /// Instantaneous reading of powerline frequency.
-    Frequency,
-    /// Instantaneous active power exported by EV (W or kW).
-    PowerActiveExport,
-    /// Instantaneous active power imported by EV (W or kW).
-    PowerActiveImport,
-    /// Instantaneous power factor of total energy flow.
-    PowerFactor,
-    /// Maximum power offered to EV.
-    PowerOffered,
-    /// Instantaneous reactive power exported by EV (var or kvar).
-    PowerReactiveExport,
-    /// Instantaneous reactive power imported by EV (var or kvar).
-    PowerReactiveImport,
-    /// Fan speed in RPM.
-    RPM,
-    /// State of charge of charging vehicle in percentage.
-    SoC,
-    /// Temperature reading inside Charge Point.
-    Temperature,
-    /// Instantaneous AC RMS supply voltage.
-    Voltage,
-}
-
-/// Type of request to be triggered in a TriggerMessage.req.
-pub enum MessageTrigger {
-    /// To trigger a BootNotification request.
-    BootNotification,
-    /// To trigger a DiagnosticsStatusNotification request.
-    DiagnosticsStatusNotification,
-    /// To trigger a FirmwareStatusNotification request.
-    FirmwareStatusNotification,
-    /// To trigger a Heartbeat request.
-    Heartbeat,
-    /// To trigger a MeterValues request.
-    MeterValues,
-    /// To trigger a StatusNotification request.
-    StatusNotification,
-}
-
-/// Phase as used in SampledValue. Phase specifies how a measured value
-/// is to be interpreted. Please note that not all values of Phase are
-/// applicable to all Measurands.
-pub enum Phase {
-    /// Measured on L1.
-    L1,
-    /// Measured on L2.
-    L2,
-    /// Measured on L3.
-    L3,
-    /// Measured on Neutral.
-    N,
-    /// Measured on L1 with respect to Neutral conductor.
-    L1N,
-    /// Measured on L2 with respect to Neutral conductor.
-    L2N,
-    /// Measured on L3 with respect to Neutral conductor.
-    L3N,
-    /// Measured between L1 and L2.
-    L1L2,
-    /// Measured between L2 and L3.
-    L2L3,
-    /// Measured between L3 and L1.
-    L3L1,
-}
-
-/// Values of the context field of a value in SampledValue.
-pub enum ReadingContext {
-    /// Value taken at start of interruption.
-    InterruptionBegin,
-    /// Value taken when resuming after interruption.
-    InterruptionEnd,
-    /// Value for any other situations.
-    Other,
-    /// Value taken at clock aligned interval.
-    SampleClock,
-    /// Value taken as periodic sample relative to start time of transaction.
-    SamplePeriodic,
-    /// Value taken at end of transaction.
-    TransactionBegin,
-    /// Value taken at start of transaction.
-    TransactionEnd,
-    /// Value taken in response to a TriggerMessage.req.
-    Trigger,
-}
-
-/// Reason for stopping a transaction in StopTransaction.req.
-pub enum Reason {
-    /// Emergency stop button was used.
-    EmergencyStop,
-    /// Disconnecting of cable, vehicle moved away from
-    /// inductive charge unit.
-    EVDisconnected,
-    /// A hard reset command was received.
-    HardReset,
-    /// Stopped locally on request of the user at the
-    /// Charge Point. This is reagular termination of a
-    /// transaction. Examples: presenting an RFID tag,
-    /// pressing a button to stop.
-    Local,
-    /// Any other reason.
-    Other,
-    /// Complete loss of power.
-    PowerLoss,
-    /// A locally initiated reset/reboot occurred (for
-    /// instance watchdog kicked in).
-    Reboot,
-    /// Stopped remotely on request of the user. This is a
-    /// regular termination of a transaction. Examples:
-    /// termination using a smartphone app, exceeding a
-    /// (non local) prepaid credit.
-    Remote,
-    /// A soft reset command was received.
-    SoftReset,
-    /// Central System sent an Unlock Connector command.
-    UnlockCommand,
-    /// The transaction was stopped because of the
-    /// authorization status in a StartTransaction.conf.
-    DeAuthorized,
-}
-
-pub enum RecurrencyKindType {
-    /// The schedule restarts at the beginning of the next day.
-    Daily,
-    /// The schedule restarts at the beginning of the next week (defined as
-    /// Monday morning).
-    Weekly,
-}
-
-/// Result of registration in response to BootNotification.req.
-pub enum RegistrationStatus {
-    /// Charge point is accepted by Central System.
-    Accepted,
-    /// Central System is not yet ready to accept the
-    /// Charge Point. Central System may send messages
-    /// to retrieve information or prepare the Charge
-    /// Point.
-    Pending,
-    /// Charge point is not accepted by Central System.
-    /// This may happen when the Charge Point id is not
-    /// known by Central System.
-    Rejected,
-}
-
-/// The result of a RemoteStartTransaction.req or RemoteStopTransaction.req request.
-pub enum RemoteStartStopStatus {
-    /// Command will be executed.
-    Accepted,
-    /// Command will not be executed.
-    Rejected,
-}
-
-/// Status in ReserveNow.conf.
-pub enum ReservationStatus {
-    /// Reservation has been made.
-    Accepted,
-    /// Reservation has not been made. All connectors or
-    /// the specified connector are in a faulted state.
-    Faulted,
-    /// Reservation has not been made. All connector or
-    /// the specified connector are occupied.
-    Occupied,
-    /// Reservation has not been made. Charge Point is
-    /// not configured to accept reservations.
-    Rejected,
-    /// Reservation has not been made, because
-    /// connectors or specified connector are in an
-    /// unavailable state.
-    Unavailable,
-}
-
-/// Result of Reset.req.
-pub enum ResetStatus {
-    /// Command will be executed.
-    Accepted,
-    /// Command will not be executed.
-    Rejected,
-}
-
-/// Type of reset requested by Reset.req.
-pub enum ResetType {
-    /// Full reboot of Charge Point software.
-    Hard,
-    /// Return to initial status, gracefully terminating any
-    /// transactions in progress.
-    Soft,
-}
-
-/// Status in TriggerMessage.conf.
-pub enum TriggerMessageStatus {
-    /// Requested notification will be sent.
-    Accepted,
-    /// Requested notification will not be sent.
-    Rejected,
-    /// Requested notification cannot be sent because it is
-    /// either not implemented or unknown.
-    NotImplemented,
-}
-
-/// Allowable values of the optional "unit" field of a Value element, as used in
-/// MeterValues.req and StopTransaction.req messages.
-/// Default value of "unit" is always "Wh".
-pub enum UnitOfMeasure {
-    /// Watt-hours (energy). Default.
-    WH,
-    /// kiloWatt-hours (energy).
-    KWH,
-    /// Var-hours (reactive energy).
-    VARH,
-    /// kilovar-hours (reactive energy).
-    KVARH,
-    /// Watts (power).
-    W,
-    /// kilowatts (power).
-    KW,
-    /// VoltAmpere (apparent power).
-    VA,
-    /// kiloVoltAmpere (apparent power).
-    KVA,
-    /// Vars (reactive power).
-    VAR,
-    /// kilovars (reactive power).
-    KVAR,
-    /// Amperes (current).
-    A,
-    /// Voltage (r.m.s. AC).
-    V,
-    /// Degrees (temperature).
-    Celsius,
-    /// Degrees (temperature).
-    Fahrenheit,
-    /// Degrees Kelvin (temperature).
-    K,
-    /// Percentage.
-    Percent,
-}
-
-/// Status in response to UnlockConnector.req.
-pub enum UnlockStatus {
-    /// Connector has successfully been unlocked.
-    Unlocked,
-    /// Failed to unlock the connector.
-    UnlockFailed,
-    /// Charge Point has no connector lock.
-    NotSupported,
-}
-
-/// Type of update for a SendLocalList.req
-pub enum UpdateStatus {
-    /// Local Authorization List successfully updated.
-    Accepted,
-    /// Failed to update the Local Authorization List.
-    Failed,
-    /// Update of Local Authorization List is not
-    /// supported by Charge Point.
-    NotSupported,
-    /// Version number in the request for a differential
-    /// update is less or equal then version number of
-    /// current list.
-    VersionMismatch,
-}
-
-/// Type of update for a SendLocalList.req
-pub enum UpdateType {
-    /// Indicates that the current Local Authorization List
-    /// must be updated with the values in this message.
-    Differential,
-    /// Indicates that the current Local Authorization List
-    /// must be replaced by the values in this message.
-    Full,
-}
-
-/// Format that specifies how the value element in SampledValue is to be interpreted.
-pub enum ValueFormat {
-    /// Data is to be interpreted as integer/decimal numeric data.
-    Raw,
-    /// Data is represented as a signed binary data block, encoded as hex data.
-    SignedData,
+use std::fmt;
+
+/// Error returned when a string does not match any value
+/// of an OCPP enumeration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEnumError {
+    /// Name of the enumeration that was being parsed.
+    enumeration: &'static str,
+    /// The value that could not be matched.
+    value: String,
+}
+
+impl ParseEnumError {
+    pub(crate) fn new(enumeration: &'static str, value: &str) -> ParseEnumError {
+        ParseEnumError {
+            enumeration,
+            value: value.to_string(),
+        }
+    }
+
+    /// Name of the enumeration that was being parsed.
+    pub fn enumeration(&self) -> &'static str {
+        self.enumeration
+    }
+
+    /// The value that could not be matched.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for ParseEnumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} value \"{}\"", self.enumeration, self.value)
+    }
+}
+
+impl std::error::Error for ParseEnumError {}
+
+/// Declares an OCPP enumeration together with the exact string used
+/// for each variant on the wire, deriving `Display`, `FromStr` and
+/// serde support from that single table.
+macro_rules! wire_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => $wire:literal,
+            )+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )+
+        }
+
+        impl $name {
+            /// The exact string used for this value in OCPP messages.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $wire,)+
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = $crate::types::enumerations::ParseEnumError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($wire => Ok($name::$variant),)+
+                    _ => Err($crate::types::enumerations::ParseEnumError::new(
+                        stringify!($name),
+                        s,
+                    )),
+                }
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+                value.parse().map_err(::serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+pub(crate) use wire_enum;
+
+wire_enum! {
+    /// Status in a response to an Authorize.req
+    pub enum AuthorizationStatus {
+        /// Identifier is allowed for charging
+        Accepted => "Accepted",
+        /// Identifier has been blocked. Not allowed for charging.
+        Blocked => "Blocked",
+        /// Identifier has expired. Not allowed for charging.
+        Expired => "Expired",
+        /// Identifier is unknown. Not allowed for charging
+        Invalid => "Invalid",
+        /// Identifier is already involved in another transaction and multiple
+        /// transactions are not allowed.
+        ConcurrentTx => "ConcurrentTx",
+    }
+}
+
+wire_enum! {
+    /// Status returned in response to ChangeAvailability.req
+    pub enum AvailabilityStatus {
+        /// Request has been accepted and will be executed.
+        Accepted => "Accepted",
+        /// Request has not been accepted and will not be executed.
+        Rejected => "Rejected",
+        /// Request has been accepted and will be executed when transactions(s)
+        /// in progress have finished.
+        Scheduled => "Scheduled",
+    }
+}
+
+wire_enum! {
+    /// Request availability change in ChangeAvailability.req
+    pub enum AvailabilityType {
+        /// Charge point is not available for charging.
+        Inoperative => "Inoperative",
+        /// Charge point is available for charging.
+        Operative => "Operative",
+    }
+}
+
+wire_enum! {
+    /// Status in CancelReservation.conf
+    pub enum CancelReservationStatus {
+        /// Reservation for the identifier has been cancelled.
+        Accepted => "Accepted",
+        /// Reservation could not be cancelled, because there is no reservation
+        /// active for the identifier.
+        Rejected => "Rejected",
+    }
+}
+
+wire_enum! {
+    /// Charge Point status reported in StatusNotification.req
+    pub enum ChargePointErrorCode {
+        /// Failure to lock or unlock connector.
+        ConnectorLockFailure => "ConnectorLockFailure",
+        /// Communication failure with the vehicle, migh be Mode 3 or other
+        /// communication protocol problem. This is not a real error in the
+        /// sense that the Charge Point doesn't need to go to the faulted
+        /// state. Instead, it should go to the SuspendedEVSE state.
+        EVCommunicationError => "EVCommunicationError",
+        /// Ground fault circuit interrupter has been activated.
+        GroundFailure => "GroundFailure",
+        /// Temperature inside Charge Point is too high.
+        HighTemperature => "HighTemperature",
+        /// Error in internal hard- or software component.
+        InternalError => "InternalError",
+        /// The authorization information received from the Central System
+        /// is in conflict with the LocalAuthorizationList.
+        LocalListConflict => "LocalListConflict",
+        /// No error to report.
+        NoError => "NoError",
+        /// Other type of error. More information in vendorErrorCode.
+        OtherError => "OtherError",
+        /// Over current protection device has tripped.
+        OverCurrentFailure => "OverCurrentFailure",
+        /// Voltage has risen above an acceptable level.
+        OverVoltage => "OverVoltage",
+        /// Failure to read power meter.
+        PowerMeterFailure => "PowerMeterFailure",
+        /// Failure to control power switch.
+        PowerSwitchFailure => "PowerSwitchFailure",
+        /// Failure with idTag reader.
+        ReaderFailure => "ReaderFailure",
+        /// Unable to perform a reset.
+        ResetFailure => "ResetFailure",
+        /// Voltage has dropped below an acceptable level.
+        UnderVoltage => "UnderVoltage",
+        /// Wireless communication device reports a weak signal.
+        WeakSignal => "WeakSignal",
+    }
+}
+
+wire_enum! {
+    /// Status reported in StatusNotification.req. A status can be reported for the
+    /// Charge Point main controller (connectorId = 0) or for a specific connector.
+    /// Status for the Charge Point main controller is a subset of the enumeration:
+    /// Available, Unavailable or Faulted.
+    ///
+    /// Stated considered Operative are: Available, Preparing, Charging, SuspendedEVSE,
+    /// SuspendedEV, Finishing, Reserved. States considered Inoperative are:
+    /// Unavailable, Faulted.
+    pub enum ChargePointStatus {
+        /// When a Connector becomes available for a new user. (Operative)
+        Available => "Available",
+        /// When a Connector becomes no longer available for a new user
+        /// but no charging session is active. Typically a Connector is
+        /// occupied when a user presents a tag, inserts a cable or a
+        /// vehicle occupies the parking bay. (Operative)
+        Preparing => "Preparing",
+        /// When the contactor of a Connector closes, allowing the
+        /// vehicle to charge. (Operative)
+        Charging => "Charging",
+        /// When the contactor of a Connector opens upon request of the
+        /// EVSE, e.g. due to a smart charging restriction or as the result
+        /// of StartTransaction.conf indicating that charging is not allowed.
+        /// (Operative)
+        SuspendedEVSE => "SuspendedEVSE",
+        /// When the EVSE is ready to deliver energy but contactor is open,
+        /// e.g. the EV is not ready. (Operative)
+        SuspendedEV => "SuspendedEV",
+        /// When a charging session has stopped at a Connector, but the Connector
+        /// is not yet available for a new user, e.g. the cable has not been removed
+        /// or the vehicle has not left the parking bay. (Operative)
+        Finishing => "Finishing",
+        /// When a Connector becomes reserved as a result of a Reserve Now command.
+        /// (Operative)
+        Reserved => "Reserved",
+        /// When a Conenctor becomes unavailable as the result of a Change Availability
+        /// command or an event upon which the Charge Point transaitions to unavailable
+        /// at its discretion. Upon receipt of a Chage Availability command, the status
+        /// MAY be scheduled. When scheduled, the Status Nofitication shall be send when
+        /// the availability change becomes effective. (Inoperative)
+        Unavailable => "Unavailable",
+        /// When a Charge Point or connector has reported an error and is not available
+        /// for energy delivery. (Inoperative)
+        Faulted => "Faulted",
+    }
+}
+
+wire_enum! {
+    pub enum ChargingProfileKindType {
+        /// Schedule periods are relative to a fixed point in time defined in the schedule.
+        Absolute => "Absolute",
+        /// The schedule restarts periodically at the first schedule period.
+        Recurring => "Recurring",
+        /// Schedule periods are relative to a situation-specific start point (such as the
+        /// start of a session) that is determined by the charge point.
+        Relative => "Relative",
+    }
+}
+
+wire_enum! {
+    pub enum ChargingProfilePurposeType {
+        /// Configuration for the maximum power or current available for an entire Charge Point.
+        /// SetChargingProfile.req message.
+        ChargePointMaxProfile => "ChargePointMaxProfile",
+        /// Default profile to be used for new transactions.
+        TxDefaultProfile => "TxDefaultProfile",
+        /// Profile with constraints to be imposed by the Charge Point on the current transaction.
+        /// A profile with this purpose SHALL cease to be valid when the transaction terminates.
+        TxProfile => "TxProfile",
+    }
+}
+
+wire_enum! {
+    /// Status returned in response to SetChargingProfile.req.
+    pub enum ChargingProfileStatus {
+        /// Request has been accepted and will be executed.
+        Accepted => "Accepted",
+        /// Request has not been accepted and will not be executed.
+        Rejected => "Rejected",
+        /// Charge Point indicates that the request is not supported.
+        NotSupported => "NotSupported",
+    }
+}
+
+wire_enum! {
+    /// Unit in which a charging schedule is defined, as used in: GetCompositeSchedule.req and
+    /// ChargingSchedule.
+    pub enum ChargingRateUnitType {
+        /// Watts (power).
+        W => "W",
+        /// Amperes (current).
+        A => "A",
+    }
+}
+
+wire_enum! {
+    /// Status returned in response to ClearCache.req.
+    pub enum ClearCacheStatus {
+        /// Command has been executed.
+        Accepted => "Accepted",
+        /// Command has not been executed.
+        Rejected => "Rejected",
+    }
+}
+
+wire_enum! {
+    /// Status returned in response to ClearChargingProfile.req.
+    pub enum ClearChargingProfileStatus {
+        /// Request has been accepted and will be executed.
+        Accepted => "Accepted",
+        /// No CHarging Profile(s) were found matching the request.
+        Unknown => "Unknown",
+    }
+}
+
+wire_enum! {
+    /// Status in ChangeConfiguration.conf.
+    pub enum ConfigurationStatus {
+        /// Configuration key supported and setting has been changed.
+        Accepted => "Accepted",
+        /// Configuration key supported, but setting could not be changed.
+        Rejected => "Rejected",
+        /// Configuration key supported and setting has been changed,
+        /// bug change will be available after reboot (Charge Point
+        /// will not reboot itself).
+        RebootRequired => "RebootRequired",
+        /// Configuration key is not supported.
+        NotSupported => "NotSupported",
+    }
+}
+
+wire_enum! {
+    /// Status in DataTransfer.conf.
+    pub enum DataTransferStatus {
+        /// Message has been accepted and the contained request is accepted.
+        Accepted => "Accepted",
+        /// Message has been accepted but the contained request is rejected.
+        Rejected => "Rejected",
+        /// Message could not be interpreted due to unknown messageId string.
+        UnknownMessageId => "UnknownMessageId",
+        /// Message could not be interpreted due to unknown vendorId string.
+        UnknownVendorId => "UnknownVendorId",
+    }
+}
+
+wire_enum! {
+    /// Status in DiagnosticsStatusNotification.req.
+    pub enum DiagnosticsStatus {
+        /// Charge Point is not performing diagnostics related tasks.
+        /// Status Idle SHALL only be used as in a DiagnosticsStatusNotification.req
+        /// that was triggered by a TriggerMessage.req.
+        Idle => "Idle",
+        /// Diagnostics information has been uploaded.
+        Uploaded => "Uploaded",
+        /// Uploading of diagnostics failed.
+        UploadFailed => "UploadFailed",
+        /// File is being uploaded.
+        Uploading => "Uploading",
+    }
+}
+
+wire_enum! {
+    /// Status of firmware download as reported in FirmwareStatusNotification.req.
+    pub enum FirmwareStatus {
+        /// New firmware has been downloaded by Charge Point.
+        Downloaded => "Downloaded",
+        /// Charge point failed to download firmware.
+        DownloadFailed => "DownloadFailed",
+        /// Firmware is being downloaded.
+        Downloading => "Downloading",
+        /// Charge Point is not performing fimrware update related tasks.
+        /// Status Idle SHALL only be used as in a FirmwareStatusNotification.req
+        /// that was triggered by a TriggerMessage.req.
+        Idle => "Idle",
+        /// Installation of new firmware has failed.
+        InstallationFailed => "InstallationFailed",
+        /// Firmware is being installed.
+        Installing => "Installing",
+        /// New firmware has successfully been installed in charge point.
+        Installed => "Installed",
+    }
+}
+
+wire_enum! {
+    /// Status returned in response to GetCompositeSchedule.req.
+    pub enum GetCompositeScheduleStatus {
+        /// Request has been accepted and will be executed.
+        Accepted => "Accepted",
+        /// Request has not been accepted and will not be executed.
+        Rejected => "Rejected",
+    }
+}
+
+wire_enum! {
+    /// Allowable values of the optional "location" field of a value element in SampledValue.
+    pub enum Location {
+        /// Measurement inside body of Charge Point (e.g Temperature).
+        Body => "Body",
+        /// Measurement taken from cable between EV and Charge Point.
+        Cable => "Cable",
+        /// Measurement taken by EV.
+        EV => "EV",
+        /// Measurement at network ("grid") inlet connection.
+        Inlet => "Inlet",
+        /// Measurement at a Connector. Default value.
+        Outlet => "Outlet",
+    }
+}
+
+wire_enum! {
+    /// Allowable values of the optional "measurand" field of a Value element,
+    /// as used in MeterValues.req and StopTransaction.req messages.
+    /// Default value of "measurand" is always "Energy.Active.Import.Register".
+    pub enum Measurand {
+        /// Instantaneous current flow from EV.
+        CurrentExport => "Current.Export",
+        /// Instantaneous current flow to EV.
+        CurrentImport => "Current.Import",
+        /// Maximum current offered to EV.
+        CurrentOffered => "Current.Offered",
+        /// Energy exported by EV (Wh or kWh).
+        EnergyActiveExportRegister => "Energy.Active.Export.Register",
+        /// Enegy imported by EV (Wh or kWh).
+        EnergyActiveImportRegister => "Energy.Active.Import.Register",
+        /// Reactive energy exported by EV (varh or kvarh).
+        EnergyReactiveExportRegister => "Energy.Reactive.Export.Register",
+        /// Reactive energy imported by EV (varh or kvarh).
+        EnergyReactiveImportRegister => "Energy.Reactive.Import.Register",
+        /// Energy exported by EV (Wh or kWh).
+        EnergyActiveExportInterval => "Energy.Active.Export.Interval",
+        /// Energy imported by EV (Wh or kWh).
+        EnergyActiveImportInterval => "Energy.Active.Import.Interval",
+        /// Reactive energy exported by EV (varh or kvarh).
+        EnergyReactiveExportInterval => "Energy.Reactive.Export.Interval",
+        /// Reactive energy imported by EV (varh or kvarh).
+        EnergyReactiveImportInterval => "Energy.Reactive.Import.Interval",
+        /// Instantaneous reading of powerline frequency.
+        Frequency => "Frequency",
+        /// Instantaneous active power exported by EV (W or kW).
+        PowerActiveExport => "Power.Active.Export",
+        /// Instantaneous active power imported by EV (W or kW).
+        PowerActiveImport => "Power.Active.Import",
+        /// Instantaneous power factor of total energy flow.
+        PowerFactor => "Power.Factor",
+        /// Maximum power offered to EV.
+        PowerOffered => "Power.Offered",
+        /// Instantaneous reactive power exported by EV (var or kvar).
+        PowerReactiveExport => "Power.Reactive.Export",
+        /// Instantaneous reactive power imported by EV (var or kvar).
+        PowerReactiveImport => "Power.Reactive.Import",
+        /// Fan speed in RPM.
+        RPM => "RPM",
+        /// State of charge of charging vehicle in percentage.
+        SoC => "SoC",
+        /// Temperature reading inside Charge Point.
+        Temperature => "Temperature",
+        /// Instantaneous AC RMS supply voltage.
+        Voltage => "Voltage",
+    }
+}
+
+wire_enum! {
+    /// Type of request to be triggered in a TriggerMessage.req.
+    pub enum MessageTrigger {
+        /// To trigger a BootNotification request.
+        BootNotification => "BootNotification",
+        /// To trigger a DiagnosticsStatusNotification request.
+        DiagnosticsStatusNotification => "DiagnosticsStatusNotification",
+        /// To trigger a FirmwareStatusNotification request.
+        FirmwareStatusNotification => "FirmwareStatusNotification",
+        /// To trigger a Heartbeat request.
+        Heartbeat => "Heartbeat",
+        /// To trigger a MeterValues request.
+        MeterValues => "MeterValues",
+        /// To trigger a StatusNotification request.
+        StatusNotification => "StatusNotification",
+    }
+}
+
+wire_enum! {
+    /// Phase as used in SampledValue. Phase specifies how a measured value
+    /// is to be interpreted. Please note that not all values of Phase are
+    /// applicable to all Measurands.
+    pub enum Phase {
+        /// Measured on L1.
+        L1 => "L1",
+        /// Measured on L2.
+        L2 => "L2",
+        /// Measured on L3.
+        L3 => "L3",
+        /// Measured on Neutral.
+        N => "N",
+        /// Measured on L1 with respect to Neutral conductor.
+        L1N => "L1-N",
+        /// Measured on L2 with respect to Neutral conductor.
+        L2N => "L2-N",
+        /// Measured on L3 with respect to Neutral conductor.
+        L3N => "L3-N",
+        /// Measured between L1 and L2.
+        L1L2 => "L1-L2",
+        /// Measured between L2 and L3.
+        L2L3 => "L2-L3",
+        /// Measured between L3 and L1.
+        L3L1 => "L3-L1",
+    }
+}
+
+wire_enum! {
+    /// Values of the context field of a value in SampledValue.
+    pub enum ReadingContext {
+        /// Value taken at start of interruption.
+        InterruptionBegin => "Interruption.Begin",
+        /// Value taken when resuming after interruption.
+        InterruptionEnd => "Interruption.End",
+        /// Value for any other situations.
+        Other => "Other",
+        /// Value taken at clock aligned interval.
+        SampleClock => "Sample.Clock",
+        /// Value taken as periodic sample relative to start time of transaction.
+        SamplePeriodic => "Sample.Periodic",
+        /// Value taken at start of transaction.
+        TransactionBegin => "Transaction.Begin",
+        /// Value taken at end of transaction.
+        TransactionEnd => "Transaction.End",
+        /// Value taken in response to a TriggerMessage.req.
+        Trigger => "Trigger",
+    }
+}
+
+wire_enum! {
+    /// Reason for stopping a transaction in StopTransaction.req.
+    pub enum Reason {
+        /// Emergency stop button was used.
+        EmergencyStop => "EmergencyStop",
+        /// Disconnecting of cable, vehicle moved away from
+        /// inductive charge unit.
+        EVDisconnected => "EVDisconnected",
+        /// A hard reset command was received.
+        HardReset => "HardReset",
+        /// Stopped locally on request of the user at the
+        /// Charge Point. This is reagular termination of a
+        /// transaction. Examples: presenting an RFID tag,
+        /// pressing a button to stop.
+        Local => "Local",
+        /// Any other reason.
+        Other => "Other",
+        /// Complete loss of power.
+        PowerLoss => "PowerLoss",
+        /// A locally initiated reset/reboot occurred (for
+        /// instance watchdog kicked in).
+        Reboot => "Reboot",
+        /// Stopped remotely on request of the user. This is a
+        /// regular termination of a transaction. Examples:
+        /// termination using a smartphone app, exceeding a
+        /// (non local) prepaid credit.
+        Remote => "Remote",
+        /// A soft reset command was received.
+        SoftReset => "SoftReset",
+        /// Central System sent an Unlock Connector command.
+        UnlockCommand => "UnlockCommand",
+        /// The transaction was stopped because of the
+        /// authorization status in a StartTransaction.conf.
+        DeAuthorized => "DeAuthorized",
+    }
+}
+
+wire_enum! {
+    pub enum RecurrencyKindType {
+        /// The schedule restarts at the beginning of the next day.
+        Daily => "Daily",
+        /// The schedule restarts at the beginning of the next week (defined as
+        /// Monday morning).
+        Weekly => "Weekly",
+    }
+}
+
+wire_enum! {
+    /// Result of registration in response to BootNotification.req.
+    pub enum RegistrationStatus {
+        /// Charge point is accepted by Central System.
+        Accepted => "Accepted",
+        /// Central System is not yet ready to accept the
+        /// Charge Point. Central System may send messages
+        /// to retrieve information or prepare the Charge
+        /// Point.
+        Pending => "Pending",
+        /// Charge point is not accepted by Central System.
+        /// This may happen when the Charge Point id is not
+        /// known by Central System.
+        Rejected => "Rejected",
+    }
+}
+
+wire_enum! {
+    /// The result of a RemoteStartTransaction.req or RemoteStopTransaction.req request.
+    pub enum RemoteStartStopStatus {
+        /// Command will be executed.
+        Accepted => "Accepted",
+        /// Command will not be executed.
+        Rejected => "Rejected",
+    }
+}
+
+wire_enum! {
+    /// Status in ReserveNow.conf.
+    pub enum ReservationStatus {
+        /// Reservation has been made.
+        Accepted => "Accepted",
+        /// Reservation has not been made. All connectors or
+        /// the specified connector are in a faulted state.
+        Faulted => "Faulted",
+        /// Reservation has not been made. All connector or
+        /// the specified connector are occupied.
+        Occupied => "Occupied",
+        /// Reservation has not been made. Charge Point is
+        /// not configured to accept reservations.
+        Rejected => "Rejected",
+        /// Reservation has not been made, because
+        /// connectors or specified connector are in an
+        /// unavailable state.
+        Unavailable => "Unavailable",
+    }
+}
+
+wire_enum! {
+    /// Result of Reset.req.
+    pub enum ResetStatus {
+        /// Command will be executed.
+        Accepted => "Accepted",
+        /// Command will not be executed.
+        Rejected => "Rejected",
+    }
+}
+
+wire_enum! {
+    /// Type of reset requested by Reset.req.
+    pub enum ResetType {
+        /// Full reboot of Charge Point software.
+        Hard => "Hard",
+        /// Return to initial status, gracefully terminating any
+        /// transactions in progress.
+        Soft => "Soft",
+    }
+}
+
+wire_enum! {
+    /// Status in TriggerMessage.conf.
+    pub enum TriggerMessageStatus {
+        /// Requested notification will be sent.
+        Accepted => "Accepted",
+        /// Requested notification will not be sent.
+        Rejected => "Rejected",
+        /// Requested notification cannot be sent because it is
+        /// either not implemented or unknown.
+        NotImplemented => "NotImplemented",
+    }
+}
+
+wire_enum! {
+    /// Allowable values of the optional "unit" field of a Value element, as used in
+    /// MeterValues.req and StopTransaction.req messages.
+    /// Default value of "unit" is always "Wh".
+    pub enum UnitOfMeasure {
+        /// Watt-hours (energy). Default.
+        WH => "Wh",
+        /// kiloWatt-hours (energy).
+        KWH => "kWh",
+        /// Var-hours (reactive energy).
+        VARH => "varh",
+        /// kilovar-hours (reactive energy).
+        KVARH => "kvarh",
+        /// Watts (power).
+        W => "W",
+        /// kilowatts (power).
+        KW => "kW",
+        /// VoltAmpere (apparent power).
+        VA => "VA",
+        /// kiloVoltAmpere (apparent power).
+        KVA => "kVA",
+        /// Vars (reactive power).
+        VAR => "var",
+        /// kilovars (reactive power).
+        KVAR => "kvar",
+        /// Amperes (current).
+        A => "A",
+        /// Voltage (r.m.s. AC).
+        V => "V",
+        /// Degrees (temperature).
+        Celsius => "Celsius",
+        /// Degrees (temperature).
+        Fahrenheit => "Fahrenheit",
+        /// Degrees Kelvin (temperature).
+        K => "K",
+        /// Percentage.
+        Percent => "Percent",
+    }
+}
+
+wire_enum! {
+    /// Status in response to UnlockConnector.req.
+    pub enum UnlockStatus {
+        /// Connector has successfully been unlocked.
+        Unlocked => "Unlocked",
+        /// Failed to unlock the connector.
+        UnlockFailed => "UnlockFailed",
+        /// Charge Point has no connector lock.
+        NotSupported => "NotSupported",
+    }
+}
+
+wire_enum! {
+    /// Type of update for a SendLocalList.req
+    pub enum UpdateStatus {
+        /// Local Authorization List successfully updated.
+        Accepted => "Accepted",
+        /// Failed to update the Local Authorization List.
+        Failed => "Failed",
+        /// Update of Local Authorization List is not
+        /// supported by Charge Point.
+        NotSupported => "NotSupported",
+        /// Version number in the request for a differential
+        /// update is less or equal then version number of
+        /// current list.
+        VersionMismatch => "VersionMismatch",
+    }
+}
+
+wire_enum! {
+    /// Type of update for a SendLocalList.req
+    pub enum UpdateType {
+        /// Indicates that the current Local Authorization List
+        /// must be updated with the values in this message.
+        Differential => "Differential",
+        /// Indicates that the current Local Authorization List
+        /// must be replaced by the values in this message.
+        Full => "Full",
+    }
+}
+
+wire_enum! {
+    /// Format that specifies how the value element in SampledValue is to be interpreted.
+    pub enum ValueFormat {
+        /// Data is to be interpreted as integer/decimal numeric data.
+        Raw => "Raw",
+        /// Data is represented as a signed binary data block, encoded as hex data.
+        SignedData => "SignedData",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+    use std::fmt::Debug;
+    use std::str::FromStr;
+
+    fn assert_wire_values<T>(cases: &[(T, &str)])
+    where
+        T: Debug
+            + PartialEq
+            + fmt::Display
+            + FromStr<Err = ParseEnumError>
+            + Serialize
+            + DeserializeOwned,
+    {
+        for (variant, wire) in cases {
+            assert_eq!(variant.to_string(), *wire);
+            assert_eq!(wire.parse::<T>().as_ref(), Ok(variant));
+
+            let json = serde_json::to_string(variant).unwrap();
+            assert_eq!(json, format!("\"{}\"", wire));
+            assert_eq!(&serde_json::from_str::<T>(&json).unwrap(), variant);
+        }
+    }
+
+    #[test]
+    fn test_unknown_value_is_rejected() {
+        let err = "Energy.Active.Import".parse::<Measurand>().unwrap_err();
+
+        assert_eq!(err.enumeration(), "Measurand");
+        assert_eq!(err.value(), "Energy.Active.Import");
+        assert_eq!(
+            err.to_string(),
+            "unknown Measurand value \"Energy.Active.Import\""
+        );
+    }
+
+    #[test]
+    fn test_rust_identifier_is_not_a_wire_value() {
+        assert!("EnergyActiveImportRegister".parse::<Measurand>().is_err());
+        assert!("L1N".parse::<Phase>().is_err());
+        assert!("KWH".parse::<UnitOfMeasure>().is_err());
+    }
+
+    #[test]
+    fn test_wire_values_are_case_sensitive() {
+        assert!("accepted".parse::<AuthorizationStatus>().is_err());
+    }
+
+    #[test]
+    fn test_deserialize_unknown_value_reports_error() {
+        let err = serde_json::from_str::<ReadingContext>("\"Sample.Hourly\"").unwrap_err();
+
+        assert!(err
+            .to_string()
+            .contains("unknown ReadingContext value \"Sample.Hourly\""));
+    }
+
+    #[test]
+    fn test_authorization_status_wire_values() {
+        assert_wire_values(&[
+            (AuthorizationStatus::Accepted, "Accepted"),
+            (AuthorizationStatus::Blocked, "Blocked"),
+            (AuthorizationStatus::Expired, "Expired"),
+            (AuthorizationStatus::Invalid, "Invalid"),
+            (AuthorizationStatus::ConcurrentTx, "ConcurrentTx"),
+        ]);
+    }
+
+    #[test]
+    fn test_availability_status_wire_values() {
+        assert_wire_values(&[
+            (AvailabilityStatus::Accepted, "Accepted"),
+            (AvailabilityStatus::Rejected, "Rejected"),
+            (AvailabilityStatus::Scheduled, "Scheduled"),
+        ]);
+    }
+
+    #[test]
+    fn test_availability_type_wire_values() {
+        assert_wire_values(&[
+            (AvailabilityType::Inoperative, "Inoperative"),
+            (AvailabilityType::Operative, "Operative"),
+        ]);
+    }
+
+    #[test]
+    fn test_cancel_reservation_status_wire_values() {
+        assert_wire_values(&[
+            (CancelReservationStatus::Accepted, "Accepted"),
+            (CancelReservationStatus::Rejected, "Rejected"),
+        ]);
+    }
+
+    #[test]
+    fn test_charge_point_error_code_wire_values() {
+        assert_wire_values(&[
+            (
+                ChargePointErrorCode::ConnectorLockFailure,
+                "ConnectorLockFailure",
+            ),
+            (
+                ChargePointErrorCode::EVCommunicationError,
+                "EVCommunicationError",
+            ),
+            (ChargePointErrorCode::GroundFailure, "GroundFailure"),
+            (ChargePointErrorCode::HighTemperature, "HighTemperature"),
+            (ChargePointErrorCode::InternalError, "InternalError"),
+            (ChargePointErrorCode::LocalListConflict, "LocalListConflict"),
+            (ChargePointErrorCode::NoError, "NoError"),
+            (ChargePointErrorCode::OtherError, "OtherError"),
+            (
+                ChargePointErrorCode::OverCurrentFailure,
+                "OverCurrentFailure",
+            ),
+            (ChargePointErrorCode::OverVoltage, "OverVoltage"),
+            (ChargePointErrorCode::PowerMeterFailure, "PowerMeterFailure"),
+            (
+                ChargePointErrorCode::PowerSwitchFailure,
+                "PowerSwitchFailure",
+            ),
+            (ChargePointErrorCode::ReaderFailure, "ReaderFailure"),
+            (ChargePointErrorCode::ResetFailure, "ResetFailure"),
+            (ChargePointErrorCode::UnderVoltage, "UnderVoltage"),
+            (ChargePointErrorCode::WeakSignal, "WeakSignal"),
+        ]);
+    }
+
+    #[test]
+    fn test_charge_point_status_wire_values() {
+        assert_wire_values(&[
+            (ChargePointStatus::Available, "Available"),
+            (ChargePointStatus::Preparing, "Preparing"),
+            (ChargePointStatus::Charging, "Charging"),
+            (ChargePointStatus::SuspendedEVSE, "SuspendedEVSE"),
+            (ChargePointStatus::SuspendedEV, "SuspendedEV"),
+            (ChargePointStatus::Finishing, "Finishing"),
+            (ChargePointStatus::Reserved, "Reserved"),
+            (ChargePointStatus::Unavailable, "Unavailable"),
+            (ChargePointStatus::Faulted, "Faulted"),
+        ]);
+    }
+
+    #[test]
+    fn test_charging_profile_kind_type_wire_values() {
+        assert_wire_values(&[
+            (ChargingProfileKindType::Absolute, "Absolute"),
+            (ChargingProfileKindType::Recurring, "Recurring"),
+            (ChargingProfileKindType::Relative, "Relative"),
+        ]);
+    }
+
+    #[test]
+    fn test_charging_profile_purpose_type_wire_values() {
+        assert_wire_values(&[
+            (
+                ChargingProfilePurposeType::ChargePointMaxProfile,
+                "ChargePointMaxProfile",
+            ),
+            (
+                ChargingProfilePurposeType::TxDefaultProfile,
+                "TxDefaultProfile",
+            ),
+            (ChargingProfilePurposeType::TxProfile, "TxProfile"),
+        ]);
+    }
+
+    #[test]
+    fn test_charging_profile_status_wire_values() {
+        assert_wire_values(&[
+            (ChargingProfileStatus::Accepted, "Accepted"),
+            (ChargingProfileStatus::Rejected, "Rejected"),
+            (ChargingProfileStatus::NotSupported, "NotSupported"),
+        ]);
+    }
+
+    #[test]
+    fn test_charging_rate_unit_type_wire_values() {
+        assert_wire_values(&[
+            (ChargingRateUnitType::W, "W"),
+            (ChargingRateUnitType::A, "A"),
+        ]);
+    }
+
+    #[test]
+    fn test_clear_cache_status_wire_values() {
+        assert_wire_values(&[
+            (ClearCacheStatus::Accepted, "Accepted"),
+            (ClearCacheStatus::Rejected, "Rejected"),
+        ]);
+    }
+
+    #[test]
+    fn test_clear_charging_profile_status_wire_values() {
+        assert_wire_values(&[
+            (ClearChargingProfileStatus::Accepted, "Accepted"),
+            (ClearChargingProfileStatus::Unknown, "Unknown"),
+        ]);
+    }
+
+    #[test]
+    fn test_configuration_status_wire_values() {
+        assert_wire_values(&[
+            (ConfigurationStatus::Accepted, "Accepted"),
+            (ConfigurationStatus::Rejected, "Rejected"),
+            (ConfigurationStatus::RebootRequired, "RebootRequired"),
+            (ConfigurationStatus::NotSupported, "NotSupported"),
+        ]);
+    }
+
+    #[test]
+    fn test_data_transfer_status_wire_values() {
+        assert_wire_values(&[
+            (DataTransferStatus::Accepted, "Accepted"),
+            (DataTransferStatus::Rejected, "Rejected"),
+            (DataTransferStatus::UnknownMessageId, "UnknownMessageId"),
+            (DataTransferStatus::UnknownVendorId, "UnknownVendorId"),
+        ]);
+    }
+
+    #[test]
+    fn test_diagnostics_status_wire_values() {
+        assert_wire_values(&[
+            (DiagnosticsStatus::Idle, "Idle"),
+            (DiagnosticsStatus::Uploaded, "Uploaded"),
+            (DiagnosticsStatus::UploadFailed, "UploadFailed"),
+            (DiagnosticsStatus::Uploading, "Uploading"),
+        ]);
+    }
+
+    #[test]
+    fn test_firmware_status_wire_values() {
+        assert_wire_values(&[
+            (FirmwareStatus::Downloaded, "Downloaded"),
+            (FirmwareStatus::DownloadFailed, "DownloadFailed"),
+            (FirmwareStatus::Downloading, "Downloading"),
+            (FirmwareStatus::Idle, "Idle"),
+            (FirmwareStatus::InstallationFailed, "InstallationFailed"),
+            (FirmwareStatus::Installing, "Installing"),
+            (FirmwareStatus::Installed, "Installed"),
+        ]);
+    }
+
+    #[test]
+    fn test_get_composite_schedule_status_wire_values() {
+        assert_wire_values(&[
+            (GetCompositeScheduleStatus::Accepted, "Accepted"),
+            (GetCompositeScheduleStatus::Rejected, "Rejected"),
+        ]);
+    }
+
+    #[test]
+    fn test_location_wire_values() {
+        assert_wire_values(&[
+            (Location::Body, "Body"),
+            (Location::Cable, "Cable"),
+            (Location::EV, "EV"),
+            (Location::Inlet, "Inlet"),
+            (Location::Outlet, "Outlet"),
+        ]);
+    }
+
+    #[test]
+    fn test_measurand_wire_values() {
+        assert_wire_values(&[
+            (Measurand::CurrentExport, "Current.Export"),
+            (Measurand::CurrentImport, "Current.Import"),
+            (Measurand::CurrentOffered, "Current.Offered"),
+            (
+                Measurand::EnergyActiveExportRegister,
+                "Energy.Active.Export.Register",
+            ),
+            (
+                Measurand::EnergyActiveImportRegister,
+                "Energy.Active.Import.Register",
+            ),
+            (
+                Measurand::EnergyReactiveExportRegister,
+                "Energy.Reactive.Export.Register",
+            ),
+            (
+                Measurand::EnergyReactiveImportRegister,
+                "Energy.Reactive.Import.Register",
+            ),
+            (
+                Measurand::EnergyActiveExportInterval,
+                "Energy.Active.Export.Interval",
+            ),
+            (
+                Measurand::EnergyActiveImportInterval,
+                "Energy.Active.Import.Interval",
+            ),
+            (
+                Measurand::EnergyReactiveExportInterval,
+                "Energy.Reactive.Export.Interval",
+            ),
+            (
+                Measurand::EnergyReactiveImportInterval,
+                "Energy.Reactive.Import.Interval",
+            ),
+            (Measurand::Frequency, "Frequency"),
+            (Measurand::PowerActiveExport, "Power.Active.Export"),
+            (Measurand::PowerActiveImport, "Power.Active.Import"),
+            (Measurand::PowerFactor, "Power.Factor"),
+            (Measurand::PowerOffered, "Power.Offered"),
+            (Measurand::PowerReactiveExport, "Power.Reactive.Export"),
+            (Measurand::PowerReactiveImport, "Power.Reactive.Import"),
+            (Measurand::RPM, "RPM"),
+            (Measurand::SoC, "SoC"),
+            (Measurand::Temperature, "Temperature"),
+            (Measurand::Voltage, "Voltage"),
+        ]);
+    }
+
+    #[test]
+    fn test_message_trigger_wire_values() {
+        assert_wire_values(&[
+            (MessageTrigger::BootNotification, "BootNotification"),
+            (
+                MessageTrigger::DiagnosticsStatusNotification,
+                "DiagnosticsStatusNotification",
+            ),
+            (
+                MessageTrigger::FirmwareStatusNotification,
+                "FirmwareStatusNotification",
+            ),
+            (MessageTrigger::Heartbeat, "Heartbeat"),
+            (MessageTrigger::MeterValues, "MeterValues"),
+            (MessageTrigger::StatusNotification, "StatusNotification"),
+        ]);
+    }
+
+    #[test]
+    fn test_phase_wire_values() {
+        assert_wire_values(&[
+            (Phase::L1, "L1"),
+            (Phase::L2, "L2"),
+            (Phase::L3, "L3"),
+            (Phase::N, "N"),
+            (Phase::L1N, "L1-N"),
+            (Phase::L2N, "L2-N"),
+            (Phase::L3N, "L3-N"),
+            (Phase::L1L2, "L1-L2"),
+            (Phase::L2L3, "L2-L3"),
+            (Phase::L3L1, "L3-L1"),
+        ]);
+    }
+
+    #[test]
+    fn test_reading_context_wire_values() {
+        assert_wire_values(&[
+            (ReadingContext::InterruptionBegin, "Interruption.Begin"),
+            (ReadingContext::InterruptionEnd, "Interruption.End"),
+            (ReadingContext::Other, "Other"),
+            (ReadingContext::SampleClock, "Sample.Clock"),
+            (ReadingContext::SamplePeriodic, "Sample.Periodic"),
+            (ReadingContext::TransactionBegin, "Transaction.Begin"),
+            (ReadingContext::TransactionEnd, "Transaction.End"),
+            (ReadingContext::Trigger, "Trigger"),
+        ]);
+    }
+
+    #[test]
+    fn test_reason_wire_values() {
+        assert_wire_values(&[
+            (Reason::EmergencyStop, "EmergencyStop"),
+            (Reason::EVDisconnected, "EVDisconnected"),
+            (Reason::HardReset, "HardReset"),
+            (Reason::Local, "Local"),
+            (Reason::Other, "Other"),
+            (Reason::PowerLoss, "PowerLoss"),
+            (Reason::Reboot, "Reboot"),
+            (Reason::Remote, "Remote"),
+            (Reason::SoftReset, "SoftReset"),
+            (Reason::UnlockCommand, "UnlockCommand"),
+            (Reason::DeAuthorized, "DeAuthorized"),
+        ]);
+    }
+
+    #[test]
+    fn test_recurrency_kind_type_wire_values() {
+        assert_wire_values(&[
+            (RecurrencyKindType::Daily, "Daily"),
+            (RecurrencyKindType::Weekly, "Weekly"),
+        ]);
+    }
+
+    #[test]
+    fn test_registration_status_wire_values() {
+        assert_wire_values(&[
+            (RegistrationStatus::Accepted, "Accepted"),
+            (RegistrationStatus::Pending, "Pending"),
+            (RegistrationStatus::Rejected, "Rejected"),
+        ]);
+    }
+
+    #[test]
+    fn test_remote_start_stop_status_wire_values() {
+        assert_wire_values(&[
+            (RemoteStartStopStatus::Accepted, "Accepted"),
+            (RemoteStartStopStatus::Rejected, "Rejected"),
+        ]);
+    }
+
+    #[test]
+    fn test_reservation_status_wire_values() {
+        assert_wire_values(&[
+            (ReservationStatus::Accepted, "Accepted"),
+            (ReservationStatus::Faulted, "Faulted"),
+            (ReservationStatus::Occupied, "Occupied"),
+            (ReservationStatus::Rejected, "Rejected"),
+            (ReservationStatus::Unavailable, "Unavailable"),
+        ]);
+    }
+
+    #[test]
+    fn test_reset_status_wire_values() {
+        assert_wire_values(&[
+            (ResetStatus::Accepted, "Accepted"),
+            (ResetStatus::Rejected, "Rejected"),
+        ]);
+    }
+
+    #[test]
+    fn test_reset_type_wire_values() {
+        assert_wire_values(&[(ResetType::Hard, "Hard"), (ResetType::Soft, "Soft")]);
+    }
+
+    #[test]
+    fn test_trigger_message_status_wire_values() {
+        assert_wire_values(&[
+            (TriggerMessageStatus::Accepted, "Accepted"),
+            (TriggerMessageStatus::Rejected, "Rejected"),
+            (TriggerMessageStatus::NotImplemented, "NotImplemented"),
+        ]);
+    }
+
+    #[test]
+    fn test_unit_of_measure_wire_values() {
+        assert_wire_values(&[
+            (UnitOfMeasure::WH, "Wh"),
+            (UnitOfMeasure::KWH, "kWh"),
+            (UnitOfMeasure::VARH, "varh"),
+            (UnitOfMeasure::KVARH, "kvarh"),
+            (UnitOfMeasure::W, "W"),
+            (UnitOfMeasure::KW, "kW"),
+            (UnitOfMeasure::VA, "VA"),
+            (UnitOfMeasure::KVA, "kVA"),
+            (UnitOfMeasure::VAR, "var"),
+            (UnitOfMeasure::KVAR, "kvar"),
+            (UnitOfMeasure::A, "A"),
+            (UnitOfMeasure::V, "V"),
+            (UnitOfMeasure::Celsius, "Celsius"),
+            (UnitOfMeasure::Fahrenheit, "Fahrenheit"),
+            (UnitOfMeasure::K, "K"),
+            (UnitOfMeasure::Percent, "Percent"),
+        ]);
+    }
+
+    #[test]
+    fn test_unlock_status_wire_values() {
+        assert_wire_values(&[
+            (UnlockStatus::Unlocked, "Unlocked"),
+            (UnlockStatus::UnlockFailed, "UnlockFailed"),
+            (UnlockStatus::NotSupported, "NotSupported"),
+        ]);
+    }
+
+    #[test]
+    fn test_update_status_wire_values() {
+        assert_wire_values(&[
+            (UpdateStatus::Accepted, "Accepted"),
+            (UpdateStatus::Failed, "Failed"),
+            (UpdateStatus::NotSupported, "NotSupported"),
+            (UpdateStatus::VersionMismatch, "VersionMismatch"),
+        ]);
+    }
+
+    #[test]
+    fn test_update_type_wire_values() {
+        assert_wire_values(&[
+            (UpdateType::Differential, "Differential"),
+            (UpdateType::Full, "Full"),
+        ]);
+    }
+
+    #[test]
+    fn test_value_format_wire_values() {
+        assert_wire_values(&[
+            (ValueFormat::Raw, "Raw"),
+            (ValueFormat::SignedData, "SignedData"),
+        ]);
+    }
 }
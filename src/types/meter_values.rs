@@ -1,7 +1,12 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
 use super::enumerations::{Location, Measurand, Phase, ReadingContext, UnitOfMeasure, ValueFormat};
+use crate::error::OcppError;
+use crate::messages::Validate;
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MeterValues {
     /// Timestamp for measured value(s).
     timestamp: DateTime<Utc>,
@@ -9,6 +14,32 @@ pub struct MeterValues {
     sampled_value: Vec<SampledValue>,
 }
 
+impl MeterValues {
+    /// Builds the PDU, checking its constraints.
+    pub fn new(
+        timestamp: DateTime<Utc>,
+        sampled_value: Vec<SampledValue>,
+    ) -> Result<MeterValues, OcppError> {
+        let pdu = MeterValues {
+            timestamp,
+            sampled_value,
+        };
+        pdu.validate()?;
+
+        Ok(pdu)
+    }
+
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    pub fn sampled_value(&self) -> &[SampledValue] {
+        &self.sampled_value
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct SampledValue {
     /// Value as a "Raw" (decimal) number or "SigedData".
     /// Field Type is "string" to allow for digitally signed data readings.
@@ -17,12 +48,15 @@ pub struct SampledValue {
     value: String,
     /// Type of detail value: start, end or sample.
     /// Default = "Sample.Periodic".
+    #[serde(skip_serializing_if = "Option::is_none")]
     context: Option<ReadingContext>,
     /// Raw or signed data.
     /// Default = "Raw".
+    #[serde(skip_serializing_if = "Option::is_none")]
     format: Option<ValueFormat>,
     /// Type of measurement.
     /// Default = "Energy.Active.Import.Register".
+    #[serde(skip_serializing_if = "Option::is_none")]
     measurand: Option<Measurand>,
     /// Indicates how the measured value is to be interpreted.
     /// For instance between L1 and neutral (L1-N).
@@ -30,11 +64,133 @@ pub struct SampledValue {
     /// to all Measurands.
     /// When phase is absent, the measured value is interpreted
     /// as an overall value.
+    #[serde(skip_serializing_if = "Option::is_none")]
     phase: Option<Phase>,
     /// Location of measurement.
     /// Default = "Outlet".
+    #[serde(skip_serializing_if = "Option::is_none")]
     location: Option<Location>,
     /// Unit of the value.
     /// Default = "Wh" if the (default) measurand is an "Energy" type.
+    #[serde(skip_serializing_if = "Option::is_none")]
     unit: Option<UnitOfMeasure>,
 }
+
+impl SampledValue {
+    pub fn builder(value: String) -> SampledValueBuilder {
+        SampledValueBuilder {
+            inner: SampledValue {
+                value,
+                context: None,
+                format: None,
+                measurand: None,
+                phase: None,
+                location: None,
+                unit: None,
+            },
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn context(&self) -> Option<ReadingContext> {
+        self.context
+    }
+
+    pub fn format(&self) -> Option<ValueFormat> {
+        self.format
+    }
+
+    pub fn measurand(&self) -> Option<Measurand> {
+        self.measurand
+    }
+
+    pub fn phase(&self) -> Option<Phase> {
+        self.phase
+    }
+
+    pub fn location(&self) -> Option<Location> {
+        self.location
+    }
+
+    pub fn unit(&self) -> Option<UnitOfMeasure> {
+        self.unit
+    }
+}
+
+/// Builder for SampledValue, created by [`SampledValue::builder`].
+#[derive(Debug, Clone)]
+pub struct SampledValueBuilder {
+    inner: SampledValue,
+}
+
+impl SampledValueBuilder {
+    pub fn context(mut self, context: ReadingContext) -> SampledValueBuilder {
+        self.inner.context = Some(context);
+        self
+    }
+
+    pub fn format(mut self, format: ValueFormat) -> SampledValueBuilder {
+        self.inner.format = Some(format);
+        self
+    }
+
+    pub fn measurand(mut self, measurand: Measurand) -> SampledValueBuilder {
+        self.inner.measurand = Some(measurand);
+        self
+    }
+
+    pub fn phase(mut self, phase: Phase) -> SampledValueBuilder {
+        self.inner.phase = Some(phase);
+        self
+    }
+
+    pub fn location(mut self, location: Location) -> SampledValueBuilder {
+        self.inner.location = Some(location);
+        self
+    }
+
+    pub fn unit(mut self, unit: UnitOfMeasure) -> SampledValueBuilder {
+        self.inner.unit = Some(unit);
+        self
+    }
+
+    /// Finishes the SampledValue, checking its constraints.
+    pub fn build(self) -> Result<SampledValue, OcppError> {
+        self.inner.validate()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl Validate for MeterValues {
+    fn validate(&self) -> Result<(), OcppError> {
+        if self.sampled_value.is_empty() {
+            return Err(OcppError::OccurenceConstraintViolation(String::from(
+                "sampledValue must contain at least one element",
+            )));
+        }
+
+        for sampled_value in &self.sampled_value {
+            sampled_value.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Validate for SampledValue {
+    fn validate(&self) -> Result<(), OcppError> {
+        let raw = !matches!(self.format, Some(ValueFormat::SignedData));
+        if raw && self.value.parse::<f64>().is_err() {
+            return Err(OcppError::PropertyConstraintViolation(format!(
+                "raw sampled value \"{}\" is not a decimal number",
+                self.value
+            )));
+        }
+
+        Ok(())
+    }
+}
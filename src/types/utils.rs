@@ -1,104 +1,338 @@
-/// Generic used case insensitive string of 20 characters.
-pub struct CiString20Type {
-    cistring20: String,
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::OcppError;
+
+/// Error returned when a string does not fit a case insensitive
+/// string type of the specification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CiStringError {
+    /// The string is longer than the maximum length of the type.
+    TooLong { max: usize, length: usize },
+    /// The string contains a character that is not printable ASCII.
+    InvalidCharacter(char),
 }
 
-impl CiString20Type {
-    fn new(cistring20: String) -> CiString20Type {
-        if cistring20.len() > 20 {
-            panic!("Max length of 20 characters");
+impl fmt::Display for CiStringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CiStringError::TooLong { max, length } => write!(
+                f,
+                "string of {} characters exceeds maximum length of {}",
+                length, max
+            ),
+            CiStringError::InvalidCharacter(c) => {
+                write!(f, "character {:?} is not printable ASCII", c)
+            }
         }
+    }
+}
 
-        CiString20Type { cistring20 }
+impl std::error::Error for CiStringError {}
+
+impl From<CiStringError> for OcppError {
+    fn from(err: CiStringError) -> OcppError {
+        OcppError::PropertyConstraintViolation(err.to_string())
     }
 }
 
-impl PartialEq for CiString20Type {
-    fn eq(&self, other: &Self) -> bool {
-        self.cistring20.to_lowercase() == other.cistring20.to_lowercase()
+/// Checks that `value` has at most `max` characters, all of them
+/// printable ASCII as required for case insensitive strings.
+fn validate(value: &str, max: usize) -> Result<(), CiStringError> {
+    if let Some(c) = value.chars().find(|c| !(' '..='~').contains(c)) {
+        return Err(CiStringError::InvalidCharacter(c));
+    }
+
+    if value.len() > max {
+        return Err(CiStringError::TooLong {
+            max,
+            length: value.len(),
+        });
     }
+
+    Ok(())
 }
 
-/// Generic used case insensitive string of 25 characters.
-pub struct CiString25Type {
-    cistring25: String,
+/// Case insensitive string of at most `N` printable ASCII characters.
+///
+/// Comparison, ordering and hashing ignore ASCII case, while the
+/// original spelling is kept for serialization.
+#[derive(Debug, Clone)]
+pub struct CiString<const N: usize> {
+    value: String,
 }
 
-impl CiString25Type {
-    fn new(cistring25: String) -> CiString25Type {
-        if cistring25.len() > 25 {
-            panic!("Max length of 25 characters");
-        }
+/// Generic used case insensitive string of 20 characters.
+pub type CiString20Type = CiString<20>;
+
+/// Generic used case insensitive string of 25 characters.
+pub type CiString25Type = CiString<25>;
+
+/// Generic used case insensitive string of 50 characters.
+pub type CiString50Type = CiString<50>;
+
+/// Generic used case insensitive string of 255 characters.
+pub type CiString255Type = CiString<255>;
+
+/// Generic used case insensitive string of 500 characters.
+pub type CiString500Type = CiString<500>;
+
+impl<const N: usize> CiString<N> {
+    /// Maximum number of characters of the string.
+    pub const MAX_LENGTH: usize = N;
 
-        CiString25Type { cistring25 }
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// Converts into a string type of at least the same length.
+    /// Using a shorter target type fails to compile.
+    pub fn widen<const M: usize>(self) -> CiString<M> {
+        const { assert!(N <= M, "widening to a shorter CiString") };
+
+        CiString { value: self.value }
+    }
+
+    /// Converts into a string type of any length, failing if the
+    /// string does not fit.
+    pub fn narrow<const M: usize>(self) -> Result<CiString<M>, CiStringError> {
+        CiString::try_from(self.value)
     }
 }
 
-impl PartialEq for CiString25Type {
-    fn eq(&self, other: &Self) -> bool {
-        self.cistring25.to_lowercase() == other.cistring25.to_lowercase()
+impl<const N: usize> TryFrom<String> for CiString<N> {
+    type Error = CiStringError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        validate(&value, N)?;
+
+        Ok(CiString { value })
     }
 }
 
-/// Generic used case insensitive string of 50 characters.
-pub struct CiString50Type {
-    cistring50: String,
+impl<const N: usize> TryFrom<&str> for CiString<N> {
+    type Error = CiStringError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        CiString::try_from(value.to_string())
+    }
 }
 
-impl CiString50Type {
-    fn new(cistring50: String) -> CiString50Type {
-        if cistring50.len() > 50 {
-            panic!("Max length of 50 characters");
-        }
+impl<const N: usize> FromStr for CiString<N> {
+    type Err = CiStringError;
 
-        CiString50Type { cistring50 }
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CiString::try_from(s)
     }
 }
 
-impl PartialEq for CiString50Type {
-    fn eq(&self, other: &Self) -> bool {
-        self.cistring50.to_lowercase() == other.cistring50.to_lowercase()
+impl<const N: usize> From<CiString<N>> for String {
+    fn from(value: CiString<N>) -> String {
+        value.value
     }
 }
 
-/// Generic used case insensitive string of 255 characters.
-pub struct CiString255Type {
-    cistring255: String,
+impl<const N: usize> AsRef<str> for CiString<N> {
+    fn as_ref(&self) -> &str {
+        &self.value
+    }
 }
 
-impl CiString255Type {
-    fn new(cistring255: String) -> CiString255Type {
-        if cistring255.len() > 255 {
-            panic!("Max length of 255 characters");
-        }
+macro_rules! widen {
+    ($($from:literal => $($to:literal),+;)+) => {
+        $($(
+            impl From<CiString<$from>> for CiString<$to> {
+                fn from(value: CiString<$from>) -> CiString<$to> {
+                    value.widen()
+                }
+            }
+        )+)+
+    };
+}
 
-        CiString255Type { cistring255 }
+widen! {
+    20 => 25, 50, 255, 500;
+    25 => 50, 255, 500;
+    50 => 255, 500;
+    255 => 500;
+}
+
+impl<const N: usize> fmt::Display for CiString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.value)
     }
 }
 
-impl PartialEq for CiString255Type {
+impl<const N: usize> PartialEq for CiString<N> {
     fn eq(&self, other: &Self) -> bool {
-        self.cistring255.to_lowercase() == other.cistring255.to_lowercase()
+        self.value.eq_ignore_ascii_case(&other.value)
     }
 }
 
-/// Generic used case insensitive string of 500 characters.
-pub struct CiString500Type {
-    cistring500: String,
+impl<const N: usize> Eq for CiString<N> {}
+
+impl<const N: usize> PartialOrd for CiString<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
-impl CiString500Type {
-    fn new(cistring500: String) -> CiString500Type {
-        if cistring500.len() > 500 {
-            panic!("Max length of 500 characters");
+impl<const N: usize> Ord for CiString<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let lhs = self.value.bytes().map(|b| b.to_ascii_lowercase());
+        let rhs = other.value.bytes().map(|b| b.to_ascii_lowercase());
+
+        lhs.cmp(rhs)
+    }
+}
+
+impl<const N: usize> Hash for CiString<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for byte in self.value.bytes() {
+            state.write_u8(byte.to_ascii_lowercase());
         }
+        state.write_u8(0xff);
+    }
+}
 
-        CiString500Type { cistring500 }
+impl<const N: usize> Serialize for CiString<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.value)
     }
 }
 
-impl PartialEq for CiString500Type {
-    fn eq(&self, other: &Self) -> bool {
-        self.cistring500.to_lowercase() == other.cistring500.to_lowercase()
+impl<'de, const N: usize> Deserialize<'de> for CiString<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        CiString::try_from(value).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_with_max_length() {
+        let value = "a".repeat(20);
+
+        assert_eq!(
+            CiString20Type::try_from(value.as_str()).unwrap().as_str(),
+            value
+        );
+    }
+
+    #[test]
+    fn test_with_too_long_string() {
+        let value = "a".repeat(26);
+
+        assert_eq!(
+            CiString25Type::try_from(value),
+            Err(CiStringError::TooLong {
+                max: 25,
+                length: 26
+            })
+        );
+    }
+
+    #[test]
+    fn test_with_non_printable_character() {
+        assert_eq!(
+            CiString50Type::try_from("line\nbreak"),
+            Err(CiStringError::InvalidCharacter('\n'))
+        );
+    }
+
+    #[test]
+    fn test_with_non_ascii_character() {
+        assert_eq!(
+            CiString50Type::try_from("São Paulo"),
+            Err(CiStringError::InvalidCharacter('ã'))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let value = CiString255Type::try_from("VendorX").unwrap();
+
+        assert_eq!(value.to_string(), "VendorX");
+    }
+
+    #[test]
+    fn test_hash_is_case_insensitive() {
+        let mut set = HashSet::new();
+        set.insert(CiString20Type::try_from("abcd-01234").unwrap());
+
+        assert!(set.contains(&CiString20Type::try_from("ABCD-01234").unwrap()));
+        assert!(!set.contains(&CiString20Type::try_from("efgh-01234").unwrap()));
+    }
+
+    #[test]
+    fn test_deserialize_too_long_string() {
+        let json = format!("\"{}\"", "a".repeat(21));
+
+        let err = serde_json::from_str::<CiString20Type>(&json).unwrap_err();
+
+        assert!(err.to_string().contains("exceeds maximum length of 20"));
+    }
+
+    #[test]
+    fn test_ordering_is_case_insensitive() {
+        let a = CiString20Type::try_from("abc").unwrap();
+        let b = CiString20Type::try_from("ABD").unwrap();
+
+        assert!(a < b);
+        assert_eq!(
+            a.cmp(&CiString20Type::try_from("ABC").unwrap()),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_widen() {
+        let value = CiString20Type::try_from("VendorX").unwrap();
+
+        let widened: CiString500Type = value.into();
+
+        assert_eq!(widened.as_str(), "VendorX");
+    }
+
+    #[test]
+    fn test_narrow() {
+        let fits = CiString50Type::try_from("a".repeat(20)).unwrap();
+        let too_long = CiString50Type::try_from("a".repeat(21)).unwrap();
+
+        assert!(fits.narrow::<20>().is_ok());
+        assert_eq!(
+            too_long.narrow::<20>(),
+            Err(CiStringError::TooLong {
+                max: 20,
+                length: 21
+            })
+        );
+    }
+
+    #[test]
+    fn test_other_lengths() {
+        type CiString36 = CiString<36>;
+
+        assert!(CiString36::try_from("a".repeat(36)).is_ok());
+        assert!(CiString36::try_from("a".repeat(37)).is_err());
+    }
+
+    #[test]
+    fn test_into_ocpp_error() {
+        let err = CiString20Type::try_from("a".repeat(21)).unwrap_err();
+
+        assert!(matches!(
+            OcppError::from(err),
+            OcppError::PropertyConstraintViolation(_)
+        ));
     }
 }